@@ -0,0 +1,85 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::absolute_monarchy::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use states::Kingdom;
+
+pub const SEED_KINGDOM: &[u8] = b"kingdom";
+pub const SEED_MONARCH: &[u8] = b"monarch";
+pub const SEED_SUBJECT: &[u8] = b"subject";
+pub const SEED_INVITE: &[u8] = b"kingdom_invite";
+pub const SEED_DECREE: &[u8] = b"decree";
+pub const SEED_DIVISION: &[u8] = b"division";
+pub const SEED_ECONOMY: &[u8] = b"economy";
+pub const SEED_NOBLE: &[u8] = b"noble";
+pub const SEED_POLICY: &[u8] = b"policy";
+pub const SEED_PRIVILEGES: &[u8] = b"privileges";
+pub const SEED_TREASURY: &[u8] = b"treasury";
+pub const SEED_WAR: &[u8] = b"war";
+pub const SEED_DECISION: &[u8] = b"decision";
+
+pub fn find_kingdom_address(monarch: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_KINGDOM, monarch.as_ref()], &ID)
+}
+
+pub fn find_monarch_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_MONARCH, kingdom.as_ref()], &ID)
+}
+
+/// Subject created when the kingdom has `total_subjects` subjects
+pub fn find_subject_address(kingdom: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_SUBJECT, kingdom.as_ref(), &total_subjects.to_le_bytes()], &ID)
+}
+
+pub fn find_invite_address(kingdom: &Pubkey, creator: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, kingdom.as_ref(), creator.as_ref(), &total_subjects.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn find_decree_address(monarch: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_DECREE, monarch.as_ref()], &ID)
+}
+
+pub fn find_division_address(monarch: &Pubkey, name: &str) -> (Pubkey, u8) {
+    find_address(&[SEED_DIVISION, monarch.as_ref(), name.as_bytes()], &ID)
+}
+
+pub fn find_economy_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_ECONOMY, kingdom.as_ref()], &ID)
+}
+
+pub fn find_noble_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_NOBLE, kingdom.as_ref()], &ID)
+}
+
+pub fn find_policy_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_POLICY, kingdom.as_ref()], &ID)
+}
+
+pub fn find_privileges_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_PRIVILEGES, kingdom.as_ref()], &ID)
+}
+
+pub fn find_treasury_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_TREASURY, kingdom.as_ref()], &ID)
+}
+
+pub fn find_war_address(kingdom: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_WAR, kingdom.as_ref()], &ID)
+}
+
+/// Decision record `make_decision` writes for the router
+pub fn find_decision_address(monarch: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_DECISION, monarch.as_ref()], &ID)
+}
+
+pub fn find_nft_mint_address(kingdom: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Kingdom::NFT_PREFIX_SEED, kingdom.as_ref(), symbol.as_bytes()], &ID)
+}
+
+pub fn find_spl_mint_address(kingdom: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Kingdom::SPL_PREFIX_SEED, kingdom.as_ref(), symbol.as_bytes()], &ID)
+}
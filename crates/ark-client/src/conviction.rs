@@ -0,0 +1,60 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::conviction::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use states::Governance;
+
+pub const SEED_PROPOSAL: &[u8] = b"proposal";
+pub const SEED_STAKE: &[u8] = b"stake";
+pub const SEED_NFT_STAKE: &[u8] = b"nft_stake";
+pub const SEED_STAKE_ACCOUNT: &[u8] = b"stake_account";
+pub const SEED_VOTE: &[u8] = b"vote";
+pub const SEED_INVITE: &[u8] = b"invite";
+pub const SEED_DECISION: &[u8] = b"decision";
+
+/// Proposal created when the governance has `total_proposals` proposals
+pub fn find_proposal_address(governance: &Pubkey, total_proposals: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_PROPOSAL, governance.as_ref(), &total_proposals.to_le_bytes()], &ID)
+}
+
+/// Decision record `make_decision` writes for the router
+pub fn find_decision_address(governance: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_DECISION, governance.as_ref()], &ID)
+}
+
+pub fn find_stake_address(proposal: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_STAKE, proposal.as_ref(), user.as_ref()], &ID)
+}
+
+pub fn find_nft_stake_address(proposal: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_NFT_STAKE, proposal.as_ref(), user.as_ref()], &ID)
+}
+
+/// Stake account created by `create_stake_account`
+pub fn find_user_stake_address(user: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_STAKE, user.as_ref()], &ID)
+}
+
+pub fn find_stake_account_address(governance: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_STAKE_ACCOUNT, governance.as_ref(), member.as_ref()], &ID)
+}
+
+pub fn find_vote_address(governance: &Pubkey, proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_VOTE, governance.as_ref(), proposal.as_ref(), voter.as_ref()], &ID)
+}
+
+pub fn find_invite_address(governance: &Pubkey, creator: &Pubkey, total_members: u64) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, governance.as_ref(), creator.as_ref(), &total_members.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn find_nft_mint_address(governance: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Governance::NFT_PREFIX_SEED, governance.as_ref(), symbol.as_bytes()], &ID)
+}
+
+pub fn find_spl_mint_address(governance: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Governance::SPL_PREFIX_SEED, governance.as_ref(), symbol.as_bytes()], &ID)
+}
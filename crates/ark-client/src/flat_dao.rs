@@ -0,0 +1,39 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::flat_dao::{accounts, instruction, states, ID};
+
+use crate::find_address;
+
+pub const SEED_ANALYTICS: &[u8] = b"analytics";
+pub const SEED_AUTH: &[u8] = b"auth";
+pub const SEED_DAO: &[u8] = b"dao";
+pub const SEED_VAULT: &[u8] = b"vault";
+pub const SEED_USER_MEMBERSHIP: &[u8] = b"user_membership";
+pub const SEED_INVITE: &[u8] = b"invite";
+
+pub fn find_analytics_address() -> (Pubkey, u8) {
+    find_address(&[SEED_ANALYTICS], &ID)
+}
+
+pub fn find_auth_address(analytics: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_AUTH, analytics.as_ref()], &ID)
+}
+
+pub fn find_dao_address(creator: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_DAO, creator.as_ref(), mint.as_ref()], &ID)
+}
+
+pub fn find_vault_address(creator: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_VAULT, creator.as_ref(), mint.as_ref()], &ID)
+}
+
+pub fn find_user_membership_address(dao: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_USER_MEMBERSHIP, dao.as_ref(), member.as_ref()], &ID)
+}
+
+pub fn find_invite_address(dao: &Pubkey, creator: &Pubkey, total_members: u32) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, dao.as_ref(), creator.as_ref(), &total_members.to_le_bytes()],
+        &ID,
+    )
+}
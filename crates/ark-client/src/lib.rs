@@ -0,0 +1,68 @@
+//! Typed client for every Ark program.
+//!
+//! Each program module re-exports the program's Anchor-generated `accounts` (instruction
+//! account lists) and `instruction` (instruction data) types, and adds PDA finders built
+//! from the program's seeds. Instructions are assembled with [`build_instruction`] and
+//! fetched accounts are read with [`decode_account`]:
+//!
+//! ```ignore
+//! use ark_client::{absolute_monarchy, build_instruction};
+//!
+//! let (kingdom, _) = absolute_monarchy::find_kingdom_address(&monarch);
+//! let ix = build_instruction(
+//!     absolute_monarchy::ID,
+//!     absolute_monarchy::accounts::UseInvite { /* ... */ },
+//!     absolute_monarchy::instruction::UseInvite { /* ... */ },
+//! );
+//! ```
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+
+pub mod absolute_monarchy;
+pub mod conviction;
+pub mod flat_dao;
+pub mod military_junta;
+pub mod polycentric;
+pub mod reclaim;
+pub mod sociocracy;
+pub mod sortition;
+pub mod standard;
+pub mod the_ark_program;
+
+/// Build an instruction for `program_id` from Anchor account and instruction data types
+pub fn build_instruction(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    build_instruction_with_remaining_accounts(program_id, accounts, data, vec![])
+}
+
+/// Like [`build_instruction`], appending `remaining_accounts` after the named accounts
+pub fn build_instruction_with_remaining_accounts(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id,
+        accounts: metas,
+        data: data.data(),
+    }
+}
+
+/// Decode a fetched account, checking its discriminator
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+fn find_address(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, program_id)
+}
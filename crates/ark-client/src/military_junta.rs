@@ -0,0 +1,58 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::military_junta::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use states::Junta;
+
+pub const SEED_CITIZEN: &[u8] = b"citizen";
+pub const SEED_OFFICER: &[u8] = b"officer";
+pub const SEED_REBEL: &[u8] = b"rebel";
+pub const SEED_SUPPORTER: &[u8] = b"supporter";
+pub const SEED_DECREE: &[u8] = b"decree";
+pub const SEED_INVITE: &[u8] = b"junta_invite";
+
+pub fn find_junta_address(name: &str) -> (Pubkey, u8) {
+    find_address(&[Junta::PREFIX_SEED, name.as_bytes()], &ID)
+}
+
+/// Citizen created when the junta has `total_subjects` subjects
+pub fn find_citizen_address(junta: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_CITIZEN, junta.as_ref(), &total_subjects.to_le_bytes()], &ID)
+}
+
+/// Citizen keyed by its member, as created by `init_citizen`
+pub fn find_member_citizen_address(junta: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_CITIZEN, junta.as_ref(), member.as_ref()], &ID)
+}
+
+pub fn find_officer_address(junta: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_OFFICER, junta.as_ref(), &total_subjects.to_le_bytes()], &ID)
+}
+
+pub fn find_rebel_address(junta: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_REBEL, junta.as_ref(), &total_subjects.to_le_bytes()], &ID)
+}
+
+pub fn find_supporter_address(junta: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_SUPPORTER, junta.as_ref(), &total_subjects.to_le_bytes()], &ID)
+}
+
+pub fn find_decree_address(junta: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_DECREE, junta.as_ref(), &total_subjects.to_le_bytes()], &ID)
+}
+
+pub fn find_invite_address(junta: &Pubkey, creator: &Pubkey, total_subjects: u64) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, junta.as_ref(), creator.as_ref(), &total_subjects.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn find_nft_mint_address(junta: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Junta::NFT_PREFIX_SEED, junta.as_ref(), symbol.as_bytes()], &ID)
+}
+
+pub fn find_spl_mint_address(junta: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Junta::SPL_PREFIX_SEED, junta.as_ref(), symbol.as_bytes()], &ID)
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::polycentric::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use states::GovernancePool;
+
+pub const SEED_GOVERNANCE_POOL: &[u8] = b"governance_pool";
+pub const SEED_CITIZEN: &[u8] = b"citizen";
+pub const SEED_ASSEMBLY: &[u8] = b"assembly";
+pub const SEED_POLICY_AREA: &[u8] = b"policy_area";
+pub const SEED_PROPOSAL: &[u8] = b"proposal";
+pub const SEED_TASK: &[u8] = b"task";
+pub const SEED_VOTE: &[u8] = b"vote";
+pub const SEED_ANALYTICS: &[u8] = b"analytics";
+pub const SEED_INVITE: &[u8] = b"governance_invite";
+
+pub fn find_governance_pool_address(admin: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_GOVERNANCE_POOL, admin.as_ref()], &ID)
+}
+
+pub fn find_citizen_address(governance_pool: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_CITIZEN, governance_pool.as_ref(), member.as_ref()], &ID)
+}
+
+/// Assembly created when the pool has `assembly_count` assemblies
+pub fn find_assembly_address(governance_pool: &Pubkey, assembly_count: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_ASSEMBLY, governance_pool.as_ref(), &assembly_count.to_le_bytes()], &ID)
+}
+
+/// Policy area created when the pool has `policy_area_count` policy areas
+pub fn find_policy_area_address(governance_pool: &Pubkey, policy_area_count: u64) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_POLICY_AREA, governance_pool.as_ref(), &policy_area_count.to_le_bytes()],
+        &ID,
+    )
+}
+
+/// Proposal created when the policy area has `proposal_count` proposals
+pub fn find_proposal_address(policy_area: &Pubkey, proposal_count: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_PROPOSAL, policy_area.as_ref(), &proposal_count.to_le_bytes()], &ID)
+}
+
+pub fn find_task_address(governance_pool: &Pubkey, task_id: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_TASK, governance_pool.as_ref(), &task_id.to_le_bytes()], &ID)
+}
+
+pub fn find_vote_address(proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_VOTE, proposal.as_ref(), voter.as_ref()], &ID)
+}
+
+pub fn find_analytics_address() -> (Pubkey, u8) {
+    find_address(&[SEED_ANALYTICS], &ID)
+}
+
+pub fn find_invite_address(
+    governance_pool: &Pubkey,
+    creator: &Pubkey,
+    total_participants: u64,
+) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, governance_pool.as_ref(), creator.as_ref(), &total_participants.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn find_nft_mint_address(governance_pool: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[GovernancePool::NFT_PREFIX_SEED, governance_pool.as_ref(), symbol.as_bytes()], &ID)
+}
+
+pub fn find_spl_mint_address(governance_pool: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[GovernancePool::SPL_PREFIX_SEED, governance_pool.as_ref(), symbol.as_bytes()], &ID)
+}
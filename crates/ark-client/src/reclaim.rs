@@ -0,0 +1,2 @@
+pub use ::reclaim::{accounts, constants, instruction, state, ID};
+pub use reclaim_sdk::pda::*;
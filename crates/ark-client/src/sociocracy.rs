@@ -0,0 +1,44 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::sociocracy::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use states::Circle;
+
+pub const SEED_CIRCLE: &[u8] = b"circle";
+pub const SEED_MEMBER: &[u8] = b"member";
+pub const SEED_CIRCLE_MEMBER: &[u8] = b"circle_member";
+pub const SEED_INVITE: &[u8] = b"circle_invite";
+pub const SEED_PROPOSAL: &[u8] = b"proposal";
+
+pub fn find_circle_address(name: &str) -> (Pubkey, u8) {
+    find_address(&[SEED_CIRCLE, name.as_bytes()], &ID)
+}
+
+pub fn find_member_address(member: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_MEMBER, member.as_ref()], &ID)
+}
+
+pub fn find_circle_member_address(circle: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_CIRCLE_MEMBER, circle.as_ref(), member.as_ref()], &ID)
+}
+
+/// Invite created when the circle has `member_count` members
+pub fn find_invite_address(circle: &Pubkey, creator: &Pubkey, member_count: u64) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, circle.as_ref(), creator.as_ref(), &member_count.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn find_proposal_address(circle: &Pubkey, description: &str) -> (Pubkey, u8) {
+    find_address(&[SEED_PROPOSAL, circle.as_ref(), description.as_bytes()], &ID)
+}
+
+pub fn find_nft_mint_address(circle: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Circle::NFT_PREFIX_SEED, circle.as_ref(), symbol.as_bytes()], &ID)
+}
+
+pub fn find_spl_mint_address(circle: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[Circle::SPL_PREFIX_SEED, circle.as_ref(), symbol.as_bytes()], &ID)
+}
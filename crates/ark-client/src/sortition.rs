@@ -0,0 +1,61 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::sortition::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use states::{CitizenIndex, GovernancePool};
+
+pub const SEED_GOVERNANCE_POOL: &[u8] = b"governance_pool";
+pub const SEED_CITIZEN: &[u8] = b"citizen";
+pub const SEED_CITIZEN_INDEX: &[u8] = b"citizen_index";
+pub const SEED_PAGINATION: &[u8] = b"pagination";
+pub const SEED_ASSEMBLY: &[u8] = b"assembly";
+pub const SEED_PROPOSAL: &[u8] = b"proposal";
+pub const SEED_INVITE: &[u8] = b"governance_invite";
+
+pub fn find_governance_pool_address(admin: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_GOVERNANCE_POOL, admin.as_ref()], &ID)
+}
+
+pub fn find_citizen_address(governance_pool: &Pubkey, citizen: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_CITIZEN, governance_pool.as_ref(), citizen.as_ref()], &ID)
+}
+
+pub fn find_citizen_index_address(governance_pool: &Pubkey, index: u32) -> (Pubkey, u8) {
+    find_address(&[SEED_CITIZEN_INDEX, governance_pool.as_ref(), &index.to_le_bytes()], &ID)
+}
+
+/// Citizen index page the next citizen is registered in
+pub fn find_current_citizen_index_address(governance_pool: &Pubkey, total_citizens: u32) -> (Pubkey, u8) {
+    find_citizen_index_address(
+        governance_pool,
+        total_citizens / CitizenIndex::MAX_CITIZENS_PER_INDEX as u32,
+    )
+}
+
+pub fn find_pagination_address(governance_pool: &Pubkey, total_citizens: u32) -> (Pubkey, u8) {
+    find_address(&[SEED_PAGINATION, governance_pool.as_ref(), &total_citizens.to_le_bytes()], &ID)
+}
+
+pub fn find_assembly_address(governance_pool: &Pubkey, total_citizens: u32) -> (Pubkey, u8) {
+    find_address(&[SEED_ASSEMBLY, governance_pool.as_ref(), &total_citizens.to_le_bytes()], &ID)
+}
+
+pub fn find_proposal_address(assembly: &Pubkey, description: &str) -> (Pubkey, u8) {
+    find_address(&[SEED_PROPOSAL, assembly.as_ref(), description.as_bytes()], &ID)
+}
+
+pub fn find_invite_address(governance_pool: &Pubkey, creator: &Pubkey, total_citizens: u32) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_INVITE, governance_pool.as_ref(), creator.as_ref(), &total_citizens.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn find_nft_mint_address(governance_pool: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[GovernancePool::NFT_PREFIX_SEED, governance_pool.as_ref(), symbol.as_bytes()], &ID)
+}
+
+pub fn find_spl_mint_address(governance_pool: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    find_address(&[GovernancePool::SPL_PREFIX_SEED, governance_pool.as_ref(), symbol.as_bytes()], &ID)
+}
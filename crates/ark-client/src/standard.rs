@@ -0,0 +1,83 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::standard::contexts::reclaim::{
+    find_verification_address, SEED_VERIFICATION, SEED_VERIFICATION_INDEX,
+};
+pub use ::standard::states::router::{find_executor_address, SEED_EXECUTOR};
+pub use ::standard::{accounts, instruction, states, ID};
+
+use crate::find_address;
+use ::standard::oracle::OracleConfig;
+use states::*;
+
+pub fn find_verification_index_address(user: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_VERIFICATION_INDEX, user.as_ref()], &ID)
+}
+
+pub fn find_escrow_address(sender: &Pubkey, escrow_id: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_ESCROW, sender.as_ref(), &escrow_id.to_le_bytes()], &ID)
+}
+
+pub fn find_escrow_vault_address(escrow: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_ESCROW_VAULT, escrow.as_ref()], &ID)
+}
+
+pub fn find_stream_address(authority: &Pubkey, stream_id: u64) -> (Pubkey, u8) {
+    find_address(&[SEED_STREAM, authority.as_ref(), &stream_id.to_le_bytes()], &ID)
+}
+
+pub fn find_stream_vault_address(stream: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_STREAM_VAULT, stream.as_ref()], &ID)
+}
+
+pub fn find_pool_address(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_fee: u64,
+    oracle: &Option<OracleConfig>,
+) -> (Pubkey, u8) {
+    find_address(
+        &[SEED_POOL, token_a_mint.as_ref(), token_b_mint.as_ref(), &swap_fee.to_le_bytes(), &oracle_seed(oracle)],
+        &ID,
+    )
+}
+
+pub fn find_pool_reserve_address(pool: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_POOL_RESERVE, pool.as_ref(), mint.as_ref()], &ID)
+}
+
+pub fn find_lp_mint_address(pool: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_LP_MINT, pool.as_ref()], &ID)
+}
+
+pub fn find_locked_lp_address(pool: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_LOCKED_LP, pool.as_ref()], &ID)
+}
+
+pub fn find_market_authority_address(market: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_MARKET_AUTHORITY, market.as_ref()], &ID)
+}
+
+pub fn find_bids_address(market: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_BIDS, market.as_ref()], &ID)
+}
+
+pub fn find_asks_address(market: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_ASKS, market.as_ref()], &ID)
+}
+
+pub fn find_event_queue_address(market: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_EVENT_QUEUE, market.as_ref()], &ID)
+}
+
+pub fn find_base_vault_address(market: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_BASE_VAULT, market.as_ref()], &ID)
+}
+
+pub fn find_quote_vault_address(market: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_QUOTE_VAULT, market.as_ref()], &ID)
+}
+
+pub fn find_open_orders_address(market: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    find_address(&[SEED_OPEN_ORDERS, market.as_ref(), owner.as_ref()], &ID)
+}
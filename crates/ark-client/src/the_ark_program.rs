@@ -0,0 +1,11 @@
+use anchor_lang::prelude::Pubkey;
+
+pub use ::the_ark_program::{accounts, instruction, state, ID};
+
+use crate::find_address;
+
+pub const SEED_TREASURY: &[u8] = b"treasury";
+
+pub fn find_treasury_address(owner: &Pubkey, name: &str) -> (Pubkey, u8) {
+    find_address(&[SEED_TREASURY, owner.as_ref(), name.as_bytes()], &ID)
+}
@@ -0,0 +1,25 @@
+const SECONDS_PER_WEEK: i64 = 7 * 24 * 60 * 60;
+
+/// Doublings of the lock period that still raise the conviction multiplier
+const MAX_DOUBLINGS: u32 = 6;
+
+/// Seconds a stake stays locked for a lock period of `lock_period` weeks
+pub fn calculate_lock_duration(lock_period: u8) -> i64 {
+    lock_period as i64 * SECONDS_PER_WEEK
+}
+
+/// Conviction multiplier for a lock period of `lock_period` weeks.
+///
+/// Starts at 1 without a lock and gains one for every doubling of the lock period, from a
+/// week up to 32 weeks, so it ranges from 1 to 7.
+pub fn calculate_conviction_multiplier(lock_period: u8) -> u8 {
+    let doublings = (0..MAX_DOUBLINGS)
+        .take_while(|doubling| lock_period as u32 >= 1 << doubling)
+        .count();
+    1 + doublings as u8
+}
+
+/// Voting power of `amount` staked tokens at `conviction_multiplier`, `None` on overflow
+pub fn calculate_voting_power(amount: u64, conviction_multiplier: u8) -> Option<u64> {
+    amount.checked_mul(conviction_multiplier as u64)
+}
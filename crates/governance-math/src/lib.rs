@@ -0,0 +1,21 @@
+//! Voting math shared by the Ark programs.
+//!
+//! Everything here is pure integer arithmetic: sums and products are checked and returned
+//! as `Option`, ratios are compared by cross-multiplying in `u128`, so results are exact
+//! and identical on every validator. Programs map a `None` to their own overflow error:
+//!
+//! ```
+//! use governance_math::{calculate_voting_power, meets_threshold, tally};
+//!
+//! let power = calculate_voting_power(1_000, 3).unwrap();
+//! let (approve, total) = tally([(power, true), (2_000, false)]).unwrap();
+//! assert!(meets_threshold(approve, total, 60));
+//! ```
+
+pub mod conviction;
+pub mod sortition;
+pub mod threshold;
+
+pub use conviction::*;
+pub use sortition::*;
+pub use threshold::*;
@@ -0,0 +1,43 @@
+/// Selection probabilities are expressed out of this scale
+pub const SELECTION_SCALE: u64 = 10_000;
+
+// Share of the probability each demographic contributes, summing to `SELECTION_SCALE`
+const REGION_WEIGHT: u64 = 4_000;
+const AGE_GROUP_WEIGHT: u64 = 3_000;
+const OTHER_DEMOGRAPHIC_WEIGHT: u64 = 3_000;
+
+/// Seats a demographic group gets in the assembly, and how many are already filled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GroupSeats {
+    pub quota: u32,
+    pub filled: u32,
+}
+
+impl GroupSeats {
+    pub fn is_full(&self) -> bool {
+        self.filled >= self.quota
+    }
+
+    /// `weight` scaled by the share of the group's seats still open
+    fn open_share(&self, weight: u64) -> u64 {
+        if self.is_full() {
+            return 0;
+        }
+        (self.quota - self.filled) as u64 * weight / self.quota as u64
+    }
+}
+
+/// Probability, out of [`SELECTION_SCALE`], of drawing a citizen from these groups.
+///
+/// Each group weighs in with the share of its seats still open. A citizen whose region,
+/// age group or other demographic is full, or has no seats, can't be drawn at all, so the
+/// selection never overfills a quota.
+pub fn calculate_selection_probability(region: GroupSeats, age_group: GroupSeats, other_demographic: GroupSeats) -> u64 {
+    if region.is_full() || age_group.is_full() || other_demographic.is_full() {
+        return 0;
+    }
+
+    region.open_share(REGION_WEIGHT)
+        + age_group.open_share(AGE_GROUP_WEIGHT)
+        + other_demographic.open_share(OTHER_DEMOGRAPHIC_WEIGHT)
+}
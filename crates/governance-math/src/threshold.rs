@@ -0,0 +1,25 @@
+/// Sum `(voting_power, approve)` votes into the approving and the total power.
+///
+/// `None` if the total overflows.
+pub fn tally(votes: impl IntoIterator<Item = (u64, bool)>) -> Option<(u64, u64)> {
+    votes.into_iter().try_fold((0u64, 0u64), |(approve, total), (power, approves)| {
+        let total = total.checked_add(power)?;
+        // Never above the checked total, so it can't overflow
+        let approve = if approves { approve + power } else { approve };
+        Some((approve, total))
+    })
+}
+
+/// Whether `approve` out of `total` voting power is at least `threshold_percent` percent.
+///
+/// Nothing is approved without votes.
+pub fn meets_threshold(approve: u64, total: u64, threshold_percent: u64) -> bool {
+    total > 0 && approve as u128 * 100 >= threshold_percent as u128 * total as u128
+}
+
+/// Whether `approve` out of `total` voting power is strictly above `threshold_percent` percent.
+///
+/// Nothing is approved without votes.
+pub fn exceeds_threshold(approve: u64, total: u64, threshold_percent: u64) -> bool {
+    total > 0 && approve as u128 * 100 > threshold_percent as u128 * total as u128
+}
@@ -0,0 +1,158 @@
+//! Invariants of the voting math over arbitrary inputs
+
+use governance_math::*;
+use proptest::prelude::*;
+
+fn group_seats() -> impl Strategy<Value = GroupSeats> {
+    (0..50u32, 0..60u32).prop_map(|(quota, filled)| GroupSeats { quota, filled })
+}
+
+fn open_group_seats() -> impl Strategy<Value = GroupSeats> {
+    (1..50u32).prop_flat_map(|quota| (Just(quota), 0..quota)).prop_map(|(quota, filled)| GroupSeats { quota, filled })
+}
+
+proptest! {
+    #[test]
+    fn tally_is_exact_or_reports_overflow(votes in prop::collection::vec((any::<u64>(), any::<bool>()), 0..20)) {
+        let total: u128 = votes.iter().map(|&(power, _)| power as u128).sum();
+        let approve: u128 = votes.iter().filter(|&&(_, approves)| approves).map(|&(power, _)| power as u128).sum();
+
+        match tally(votes.iter().copied()) {
+            Some(tallied) => prop_assert_eq!((tallied.0 as u128, tallied.1 as u128), (approve, total)),
+            None => prop_assert!(total > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn tally_ignores_vote_order(votes in prop::collection::vec((0..u64::MAX / 32, any::<bool>()), 0..20)) {
+        let reversed: Vec<_> = votes.iter().rev().copied().collect();
+        prop_assert_eq!(tally(votes), tally(reversed));
+    }
+
+    #[test]
+    fn thresholds_never_approve_without_votes(threshold in any::<u64>()) {
+        prop_assert!(!meets_threshold(0, 0, threshold));
+        prop_assert!(!exceeds_threshold(0, 0, threshold));
+    }
+
+    #[test]
+    fn thresholds_match_the_exact_percentage(total in 1..10_000u64, approve_share in 0..=100u64, threshold in 0..=100u64) {
+        // With 100 votes per percent, the approving percentage is a whole number
+        let approve = total * approve_share;
+        let total = total * 100;
+
+        prop_assert_eq!(meets_threshold(approve, total, threshold), approve_share >= threshold);
+        prop_assert_eq!(exceeds_threshold(approve, total, threshold), approve_share > threshold);
+    }
+
+    #[test]
+    fn exceeding_a_threshold_meets_it(approve in any::<u64>(), extra in any::<u64>(), threshold in any::<u64>()) {
+        let total = approve.saturating_add(extra);
+        if exceeds_threshold(approve, total, threshold) {
+            prop_assert!(meets_threshold(approve, total, threshold));
+        }
+    }
+
+    #[test]
+    fn more_approval_never_loses_a_vote(approve in any::<u64>(), more in any::<u64>(), extra in any::<u64>(), threshold in any::<u64>()) {
+        let more_approve = approve.saturating_add(more);
+        let total = more_approve.saturating_add(extra);
+
+        if meets_threshold(approve, total, threshold) {
+            prop_assert!(meets_threshold(more_approve, total, threshold));
+        }
+        if exceeds_threshold(approve, total, threshold) {
+            prop_assert!(exceeds_threshold(more_approve, total, threshold));
+        }
+    }
+
+    #[test]
+    fn lower_thresholds_are_easier_to_meet(approve in any::<u64>(), extra in any::<u64>(), threshold in any::<u64>(), lower in any::<u64>()) {
+        let total = approve.saturating_add(extra);
+        let lower = lower.min(threshold);
+
+        if meets_threshold(approve, total, threshold) {
+            prop_assert!(meets_threshold(approve, total, lower));
+        }
+    }
+
+    #[test]
+    fn unanimous_votes_meet_any_percentage(total in 1..u64::MAX, threshold in 0..=100u64) {
+        prop_assert!(meets_threshold(total, total, threshold));
+    }
+
+    #[test]
+    fn voting_power_is_exact_or_reports_overflow(amount in any::<u64>(), multiplier in any::<u8>()) {
+        let exact = amount as u128 * multiplier as u128;
+
+        match calculate_voting_power(amount, multiplier) {
+            Some(power) => prop_assert_eq!(power as u128, exact),
+            None => prop_assert!(exact > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn voting_power_grows_with_stake_and_conviction(amount in 0..u64::MAX / 16, more in 0..u64::MAX / 16, lock_period in any::<u8>(), longer in any::<u8>()) {
+        let multiplier = calculate_conviction_multiplier(lock_period);
+        let power = calculate_voting_power(amount, multiplier).unwrap();
+
+        prop_assert!(calculate_voting_power(amount + more, multiplier).unwrap() >= power);
+        let longer_multiplier = calculate_conviction_multiplier(lock_period.saturating_add(longer));
+        prop_assert!(calculate_voting_power(amount, longer_multiplier).unwrap() >= power);
+    }
+
+    #[test]
+    fn conviction_multiplier_is_bounded_and_monotonic(lock_period in any::<u8>(), longer in any::<u8>()) {
+        let multiplier = calculate_conviction_multiplier(lock_period);
+
+        prop_assert!((1..=7).contains(&multiplier));
+        prop_assert!(calculate_conviction_multiplier(lock_period.saturating_add(longer)) >= multiplier);
+    }
+
+    #[test]
+    fn lock_duration_grows_with_the_lock_period(lock_period in any::<u8>(), longer in any::<u8>()) {
+        let duration = calculate_lock_duration(lock_period);
+
+        prop_assert!(duration >= 0);
+        prop_assert!(calculate_lock_duration(lock_period.saturating_add(longer)) >= duration);
+    }
+
+    #[test]
+    fn selection_probability_stays_on_scale(region in group_seats(), age_group in group_seats(), other in group_seats()) {
+        prop_assert!(calculate_selection_probability(region, age_group, other) <= SELECTION_SCALE);
+    }
+
+    #[test]
+    fn full_groups_are_never_drawn(full in group_seats(), open in open_group_seats(), position in 0..3usize) {
+        let full = GroupSeats { filled: full.quota.max(full.filled), ..full };
+        let mut groups = [open; 3];
+        groups[position] = full;
+
+        prop_assert_eq!(calculate_selection_probability(groups[0], groups[1], groups[2]), 0);
+    }
+
+    #[test]
+    fn open_groups_can_always_be_drawn(region in open_group_seats(), age_group in open_group_seats(), other in open_group_seats()) {
+        prop_assert!(calculate_selection_probability(region, age_group, other) > 0);
+    }
+
+    #[test]
+    fn filling_a_seat_never_raises_the_probability(region in open_group_seats(), age_group in open_group_seats(), other in open_group_seats()) {
+        let probability = calculate_selection_probability(region, age_group, other);
+        let filled = GroupSeats { filled: region.filled + 1, ..region };
+
+        prop_assert!(calculate_selection_probability(filled, age_group, other) <= probability);
+    }
+}
+
+#[test]
+fn empty_groups_are_drawn_with_certainty() {
+    let empty = GroupSeats { quota: 3, filled: 0 };
+    assert_eq!(calculate_selection_probability(empty, empty, empty), SELECTION_SCALE);
+}
+
+#[test]
+fn conviction_doubles_up_to_32_weeks() {
+    let multipliers: Vec<_> = [0, 1, 2, 4, 8, 16, 32, 64, 255].map(calculate_conviction_multiplier).into();
+    assert_eq!(multipliers, vec![1, 2, 3, 4, 5, 6, 7, 7, 7]);
+}
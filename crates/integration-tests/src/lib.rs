@@ -0,0 +1,295 @@
+//! In-process test harness for the Ark programs.
+//!
+//! [`program_test`] loads every program into a `solana-program-test` bank as a native
+//! processor, alongside the SPL programs the bank ships with. [`Harness`] wraps the started
+//! bank with helpers to send transactions, read and seed Anchor accounts, mint SPL tokens
+//! and move the clock forward for voting periods:
+//!
+//! ```ignore
+//! let mut harness = Harness::start().await;
+//! harness.process(&[instruction], &[&signer]).await.unwrap();
+//! harness.warp_seconds(VOTING_PERIOD).await;
+//! ```
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::solana_program::{program_pack::Pack, system_instruction};
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account as SolanaAccount;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub use ark_client::{build_instruction, build_instruction_with_remaining_accounts, decode_account};
+
+/// Lamports given to every keypair created with [`Harness::funded_keypair`]
+pub const DEFAULT_FUNDING: u64 = 10_000_000_000;
+
+// Anchor's `entry` ties the account slice and its infos to a single lifetime, which the
+// native processor signature can't express, so each program gets a small leaking shim.
+macro_rules! anchor_processor {
+    ($program:ident) => {{
+        fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+            let accounts = Box::leak(Box::new(accounts.to_vec()));
+            $program::entry(program_id, accounts, data)
+        }
+        processor!(process_instruction)
+    }};
+}
+
+/// A bank with every Ark program and a no-op token metadata program
+pub fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+
+    program_test.add_program("absolute_monarchy", absolute_monarchy::ID, anchor_processor!(absolute_monarchy));
+    program_test.add_program("conviction", conviction::ID, anchor_processor!(conviction));
+    program_test.add_program("flat_dao", flat_dao::ID, anchor_processor!(flat_dao));
+    program_test.add_program("military_junta", military_junta::ID, anchor_processor!(military_junta));
+    program_test.add_program("polycentric", polycentric::ID, anchor_processor!(polycentric));
+    program_test.add_program("reclaim", reclaim::ID, anchor_processor!(reclaim));
+    program_test.add_program("sociocracy", sociocracy::ID, anchor_processor!(sociocracy));
+    program_test.add_program("sortition", sortition::ID, anchor_processor!(sortition));
+    program_test.add_program("standard", standard::ID, anchor_processor!(standard));
+    program_test.add_program("the_ark_program", the_ark_program::ID, anchor_processor!(the_ark_program));
+
+    // Some instructions require the Metaplex program account without calling it
+    program_test.add_program(
+        "mpl_token_metadata",
+        anchor_spl::metadata::mpl_token_metadata::ID,
+        processor!(process_noop),
+    );
+
+    program_test
+}
+
+fn process_noop(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+/// The custom program error a failed transaction returned, if any
+pub fn custom_error(error: BanksClientError) -> Option<u32> {
+    match error.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
+
+/// Assert that `result` failed with the Anchor error `expected`
+pub fn assert_error(result: std::result::Result<(), BanksClientError>, expected: impl Into<u32>) {
+    let expected = expected.into();
+    let error = result.expect_err("transaction should have failed");
+    assert_eq!(custom_error(error), Some(expected), "expected custom error {expected}");
+}
+
+/// A started bank and the helpers the flow tests share
+pub struct Harness {
+    pub context: ProgramTestContext,
+}
+
+impl Harness {
+    pub async fn start() -> Self {
+        Self {
+            context: program_test().start_with_context().await,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    /// Send `instructions` in one transaction paid by the harness payer
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), BanksClientError> {
+        let transaction = self.transaction(instructions, signers).await;
+        self.context.banks_client.process_transaction(transaction).await
+    }
+
+    /// Simulate `instructions` and return the data the transaction returned, empty if none
+    pub async fn simulate_return_data(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Vec<u8> {
+        let transaction = self.transaction(instructions, signers).await;
+        let simulation = self.context.banks_client.simulate_transaction(transaction).await.unwrap();
+        simulation.result.expect("simulation did not run").unwrap();
+
+        simulation.simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| return_data.data)
+            .unwrap_or_default()
+    }
+
+    async fn transaction(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Transaction {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await.unwrap();
+
+        let mut all_signers = vec![&self.context.payer];
+        for signer in signers {
+            if !all_signers.iter().any(|s| s.pubkey() == signer.pubkey()) {
+                all_signers.push(signer);
+            }
+        }
+
+        Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        )
+    }
+
+    /// A new keypair holding [`DEFAULT_FUNDING`] lamports
+    pub async fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        self.transfer_lamports(&keypair.pubkey(), DEFAULT_FUNDING).await;
+        keypair
+    }
+
+    pub async fn transfer_lamports(&mut self, to: &Pubkey, lamports: u64) {
+        let instruction = system_instruction::transfer(&self.payer(), to, lamports);
+        self.process(&[instruction], &[]).await.unwrap();
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    /// Fetch and decode an Anchor account, panicking if it is missing
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.context.banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {address} not found"));
+        decode_account(&account.data).unwrap()
+    }
+
+    pub async fn account_exists(&mut self, address: &Pubkey) -> bool {
+        self.context.banks_client.get_account(*address).await.unwrap().is_some()
+    }
+
+    /// Write `state` to `address` as an account of `space` bytes owned by its program.
+    ///
+    /// Used for state the programs can't create themselves and to set up edge cases,
+    /// such as a nearly overthrown junta.
+    pub fn set_account<T: AccountSerialize + Owner>(&mut self, address: &Pubkey, state: &T, space: usize) {
+        let mut data = Vec::with_capacity(space);
+        state.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "account data does not fit in {space} bytes");
+        data.resize(space, 0);
+
+        self.set_raw_account(address, T::owner(), data);
+    }
+
+    /// Rewrite an existing Anchor account through `update`, keeping its size
+    pub async fn update_account<T>(&mut self, address: &Pubkey, update: impl FnOnce(&mut T))
+    where
+        T: AccountSerialize + AccountDeserialize + Owner,
+    {
+        let space = self.context.banks_client.get_account(*address).await.unwrap().unwrap().data.len();
+        let mut state = self.account::<T>(address).await;
+        update(&mut state);
+        self.set_account(address, &state, space);
+    }
+
+    /// Write `data` to `address` as a rent exempt account owned by `owner`
+    pub fn set_raw_account(&mut self, address: &Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let account = SolanaAccount {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.context.set_account(address, &account.into());
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context.banks_client.get_sysvar().await.unwrap()
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        self.clock().await.unix_timestamp
+    }
+
+    /// Move to the next slot and advance the clock by `seconds`.
+    ///
+    /// The new slot also gives a fresh blockhash, so a transaction that failed before the
+    /// warp can be resent unchanged.
+    pub async fn warp_seconds(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        self.context.warp_to_slot(clock.slot + 1).unwrap();
+
+        clock.slot += 1;
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+
+    /// Create an SPL token mint with `authority` as mint authority
+    pub async fn create_mint(&mut self, authority: &Pubkey, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let instructions = [
+            system_instruction::create_account(
+                &self.payer(),
+                &mint.pubkey(),
+                Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::ID, &mint.pubkey(), authority, None, decimals)
+                .unwrap(),
+        ];
+        self.process(&instructions, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    /// Create an SPL token account for `mint` owned by `owner`, which may be a PDA
+    pub async fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let account = Keypair::new();
+        let instructions = [
+            system_instruction::create_account(
+                &self.payer(),
+                &account.pubkey(),
+                Rent::default().minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::ID, &account.pubkey(), mint, owner).unwrap(),
+        ];
+        self.process(&instructions, &[&account]).await.unwrap();
+        account.pubkey()
+    }
+
+    /// Create the associated token account of `owner` for `mint`
+    pub async fn create_associated_token_account(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+
+        let instruction = spl_associated_token_account::instruction::create_associated_token_account(
+            &self.payer(),
+            owner,
+            mint,
+            &spl_token::ID,
+        );
+        self.process(&[instruction], &[]).await.unwrap();
+        get_associated_token_address(owner, mint)
+    }
+
+    pub async fn mint_to(&mut self, mint: &Pubkey, account: &Pubkey, authority: &Keypair, amount: u64) {
+        let instruction = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            mint,
+            account,
+            &authority.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+        self.process(&[instruction], &[authority]).await.unwrap();
+    }
+
+    pub async fn token_balance(&mut self, account: &Pubkey) -> u64 {
+        let account = self.context.banks_client.get_account(*account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+}
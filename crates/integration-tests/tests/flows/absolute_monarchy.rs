@@ -0,0 +1,389 @@
+use absolute_monarchy::contexts::TaxType;
+use absolute_monarchy::error::AbsoluteMonarchyError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_spl::associated_token::get_associated_token_address;
+use ark_client::absolute_monarchy::{
+    accounts, find_decree_address, find_economy_address, find_invite_address, find_monarch_address,
+    find_subject_address, instruction, states, ID,
+};
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::contexts::reclaim::{find_verification_address, provider_hash};
+use standard::errors::RouterError;
+use standard::ReclaimRequirement;
+use states::{Decree, DecreeType, EconomicPolicyType, Kingdom, KingdomInvite, Monarch, Subject};
+
+const INVITE_DAYS: u64 = 7;
+
+pub(crate) struct Realm {
+    pub(crate) kingdom: Pubkey,
+    pub(crate) monarch: Pubkey,
+    pub(crate) king: Keypair,
+    pub(crate) noble: Keypair,
+    mint: Pubkey,
+    mint_authority: Keypair,
+}
+
+/// A kingdom with its monarch, crowned to `king`, and one noble.
+///
+/// The kingdom PDA is seeded with the monarch and the monarch PDA with the kingdom, so
+/// `initialize_absolute_monarchy` can't derive either and both are written directly.
+pub(crate) async fn setup(harness: &mut Harness) -> Realm {
+    let king = harness.funded_keypair().await;
+    let noble = harness.funded_keypair().await;
+    let mint_authority = harness.funded_keypair().await;
+    let mint = harness.create_mint(&mint_authority.pubkey(), 0).await;
+    let kingdom = Pubkey::new_unique();
+    let (monarch, monarch_bump) = find_monarch_address(&kingdom);
+    let now = harness.now().await;
+
+    harness.set_account(
+        &kingdom,
+        &Kingdom {
+            name: "Avalon".to_string(),
+            description: String::new(),
+            creator: king.pubkey(),
+            monarch,
+            monarch_name: "Arthur".to_string(),
+            primary_token_mint: mint,
+            nft_mint: None,
+            spl_mint: Some(mint),
+            sbt_mint: None,
+            nft_symbol: String::new(),
+            spl_symbol: "AVL".to_string(),
+            symbol: "AVL".to_string(),
+            nft_minted: 0,
+            spl_minted: 0,
+            sbt_minted: 0,
+            royal_treasury: 0,
+            total_decrees: 0,
+            total_active_decrees: 0,
+            total_subjects: 0,
+            min_loyalty_amount: 0,
+            total_spl_token_supply: 0,
+            total_nft_token_supply: 0,
+            total_sbt_token_supply: 0,
+            established_at: now,
+            wars_declared: 0,
+            royal_judgments: 0,
+            economic_policies_set: 0,
+            pardons_granted: 0,
+            policies_implemented: 0,
+            divisions: vec![],
+            membership_tokens_threshold: 0,
+            officials_appointed: vec![],
+            nobles: vec![noble.pubkey()],
+            collection_price: 0,
+            reclaim_requirement: None,
+            bump: 0,
+        },
+        Kingdom::SPACE,
+    );
+    harness.set_account(
+        &monarch,
+        &Monarch {
+            authority: king.pubkey(),
+            name: "Arthur".to_string(),
+            divine_mandate: "Drew the sword from the stone".to_string(),
+            coronation_date: now,
+            abdication_date: None,
+            decrees_issued: 0,
+            wars_declared: 0,
+            royal_judgments: 0,
+            economic_policies_set: 0,
+            pardons_granted: 0,
+            policies_implemented: 0,
+            bump: monarch_bump,
+        },
+        Monarch::SPACE,
+    );
+
+    Realm { kingdom, monarch, king, noble, mint, mint_authority }
+}
+
+fn decree(realm: &Realm, authority: &Keypair) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::DecreeContext {
+            kingdom: realm.kingdom,
+            monarch: realm.monarch,
+            decree: find_decree_address(&realm.monarch).0,
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::Decree {
+            decree_text: "Every knight shall sit at a round table".to_string(),
+            decree_type: DecreeType::Law,
+        },
+    )
+}
+
+/// `authority` replacing the credential new subjects must hold
+fn set_reclaim_requirement(realm: &Realm, authority: &Keypair, reclaim_requirement: Option<ReclaimRequirement>) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::SetReclaimRequirement {
+            kingdom: realm.kingdom,
+            monarch: realm.monarch,
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SetReclaimRequirement { reclaim_requirement },
+    )
+}
+
+/// An invite from `creator` for the kingdom's next subject
+async fn create_invite(harness: &mut Harness, realm: &Realm, creator: &Keypair) -> (Instruction, Pubkey) {
+    let total_subjects = harness.account::<Kingdom>(&realm.kingdom).await.total_subjects;
+    let invite = find_invite_address(&realm.kingdom, &creator.pubkey(), total_subjects).0;
+
+    let create_invite = build_instruction(
+        ID,
+        accounts::CreateKingdomInvite {
+            kingdom: realm.kingdom,
+            invite,
+            creator: creator.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CreateKingdomInvite { expiration_days: INVITE_DAYS },
+    );
+    (create_invite, invite)
+}
+
+async fn use_invite(
+    harness: &mut Harness,
+    realm: &Realm,
+    invite: &Pubkey,
+    new_subject: &Keypair,
+    verification: Option<Pubkey>,
+) -> Instruction {
+    let total_subjects = harness.account::<Kingdom>(&realm.kingdom).await.total_subjects;
+
+    build_instruction(
+        ID,
+        accounts::UseKingdomInvite {
+            kingdom: realm.kingdom,
+            invite: *invite,
+            subject: find_subject_address(&realm.kingdom, total_subjects).0,
+            new_subject: new_subject.pubkey(),
+            verification,
+            member_token_account: get_associated_token_address(&new_subject.pubkey(), &realm.mint),
+            kingdom_mint: realm.mint,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::UseKingdomInvite {},
+    )
+}
+
+#[tokio::test]
+async fn monarch_issues_a_decree() {
+    let mut harness = Harness::start().await;
+    let realm = setup(&mut harness).await;
+
+    assert_error(
+        harness.process(&[decree(&realm, &realm.noble)], &[&realm.noble]).await,
+        AbsoluteMonarchyError::NotMonarch,
+    );
+    harness.process(&[decree(&realm, &realm.king)], &[&realm.king]).await.unwrap();
+
+    let decree = harness.account::<Decree>(&find_decree_address(&realm.monarch).0).await;
+    assert_eq!(decree.id, 1);
+    assert!(decree.is_active && decree.decree_type == DecreeType::Law);
+    assert_eq!(harness.account::<Monarch>(&realm.monarch).await.decrees_issued, 1);
+    let kingdom = harness.account::<Kingdom>(&realm.kingdom).await;
+    assert_eq!((kingdom.total_decrees, kingdom.total_active_decrees), (1, 1));
+}
+
+#[tokio::test]
+async fn invited_subject_joins_the_kingdom() {
+    let mut harness = Harness::start().await;
+    let realm = setup(&mut harness).await;
+
+    let (create_invite, invite) = create_invite(&mut harness, &realm, &realm.noble).await;
+    harness.process(&[create_invite], &[&realm.noble]).await.unwrap();
+
+    let new_subject = harness.funded_keypair().await;
+    let join = use_invite(&mut harness, &realm, &invite, &new_subject, None).await;
+    harness.process(&[join], &[&new_subject]).await.unwrap();
+
+    assert_eq!(harness.account::<Kingdom>(&realm.kingdom).await.total_subjects, 1);
+    let subject = harness.account::<Subject>(&find_subject_address(&realm.kingdom, 0).0).await;
+    assert_eq!(subject.key, new_subject.pubkey());
+    assert_eq!(subject.loyalty, 50);
+    let invite_state = harness.account::<KingdomInvite>(&invite).await;
+    assert!(invite_state.is_used);
+    assert_eq!(invite_state.used_by, Some(new_subject.pubkey()));
+
+    let latecomer = harness.funded_keypair().await;
+    let join = use_invite(&mut harness, &realm, &invite, &latecomer, None).await;
+    assert_error(harness.process(&[join], &[&latecomer]).await, AbsoluteMonarchyError::InviteAlreadyUsed);
+}
+
+#[tokio::test]
+async fn invites_expire_and_are_reserved_to_the_court() {
+    let mut harness = Harness::start().await;
+    let realm = setup(&mut harness).await;
+
+    let commoner = harness.funded_keypair().await;
+    let (create_invite_as_commoner, _) = create_invite(&mut harness, &realm, &commoner).await;
+    assert_error(
+        harness.process(&[create_invite_as_commoner], &[&commoner]).await,
+        AbsoluteMonarchyError::NotMonarch,
+    );
+
+    let (create_invite, invite) = create_invite(&mut harness, &realm, &realm.noble).await;
+    harness.process(&[create_invite], &[&realm.noble]).await.unwrap();
+    harness.warp_seconds(INVITE_DAYS as i64 * 24 * 60 * 60 + 1).await;
+
+    let new_subject = harness.funded_keypair().await;
+    let join = use_invite(&mut harness, &realm, &invite, &new_subject, None).await;
+    assert_error(harness.process(&[join], &[&new_subject]).await, AbsoluteMonarchyError::InviteExpired);
+}
+
+#[tokio::test]
+async fn subject_pays_tax_into_the_treasury() {
+    let mut harness = Harness::start().await;
+    let realm = setup(&mut harness).await;
+    let economic_policy = find_economy_address(&realm.kingdom).0;
+
+    let set_policy = build_instruction(
+        ID,
+        accounts::SetEconomicPolicy {
+            kingdom: realm.kingdom,
+            monarch: realm.monarch,
+            economic_policy,
+            authority: realm.king.pubkey(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::SetEconomicPolicy {
+            policy: EconomicPolicyType::Mercantilism,
+            income_tax_rate: 10,
+            property_tax_rate: 5,
+            trade_tax_rate: 20,
+            luxury_tax_rate: 40,
+        },
+    );
+    harness.process(&[set_policy], &[&realm.king]).await.unwrap();
+    assert_eq!(harness.account::<Kingdom>(&realm.kingdom).await.economic_policies_set, 1);
+
+    // `pay_tax` derives the subject at the kingdom's current subject count, one past the
+    // last subject an invite created, so the taxed subject is written there directly
+    let taxpayer = harness.funded_keypair().await;
+    let (subject, subject_bump) = find_subject_address(&realm.kingdom, 0);
+    let now = harness.now().await;
+    harness.set_account(
+        &subject,
+        &Subject {
+            key: taxpayer.pubkey(),
+            name: "Lancelot".to_string(),
+            role: "Citizen".to_string(),
+            nobility_title: None,
+            loyalty: 50,
+            wealth: 1_000,
+            is_convicted: false,
+            jurisdiction: "Kingdom".to_string(),
+            appointed_at: now,
+            bump: subject_bump,
+        },
+        Subject::SPACE,
+    );
+    let subject_tokens = harness.create_token_account(&realm.mint, &subject).await;
+    harness.mint_to(&realm.mint, &subject_tokens, &realm.mint_authority, 1_000).await;
+    let treasury_tokens = harness.create_token_account(&realm.mint, &realm.kingdom).await;
+
+    let pay_tax = build_instruction(
+        ID,
+        accounts::PayTax {
+            kingdom: realm.kingdom,
+            subject,
+            subject_token_account: subject_tokens,
+            treasury_token_account: treasury_tokens,
+            economic_policy,
+            authority: taxpayer.pubkey(),
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::PayTax { tax_type: TaxType::Trade, taxable_amount: 500 },
+    );
+    harness.process(&[pay_tax], &[&taxpayer]).await.unwrap();
+
+    assert_eq!(harness.token_balance(&treasury_tokens).await, 100);
+    assert_eq!(harness.token_balance(&subject_tokens).await, 900);
+    assert_eq!(harness.account::<Subject>(&subject).await.wealth, 900);
+}
+
+#[tokio::test]
+async fn gated_invite_needs_a_claim_verified_under_the_kingdom_dapp() {
+    let mut harness = Harness::start().await;
+    let realm = setup(&mut harness).await;
+    let reclaim = crate::reclaim::setup(&mut harness).await;
+
+    let create_key = Keypair::new();
+    let (create_dapp, dapp) = crate::reclaim::create_dapp(&reclaim, &create_key, &realm.king, crate::reclaim::MINIMUM_WITNESSES);
+    harness.process(&[create_dapp], &[&realm.king, &create_key]).await.unwrap();
+    let requirement = ReclaimRequirement {
+        provider_hash: provider_hash(crate::reclaim::PROVIDER),
+        epoch_config: reclaim.epoch_config,
+        dapp,
+    };
+    harness.process(&[set_reclaim_requirement(&realm, &realm.king, Some(requirement))], &[&realm.king]).await.unwrap();
+
+    let (create_invite, invite) = create_invite(&mut harness, &realm, &realm.noble).await;
+    harness.process(&[create_invite], &[&realm.noble]).await.unwrap();
+    let new_subject = harness.funded_keypair().await;
+    let verification = find_verification_address(&new_subject.pubkey(), crate::reclaim::PROVIDER).0;
+
+    // Verified outside the kingdom's dapp, the claim skipped its policy
+    let (claim_info, signed_claim) = crate::reclaim::attest_claim(&mut harness, &reclaim, &new_subject.pubkey()).await;
+    let verify = crate::reclaim::reclaim_verify(&reclaim, &new_subject, &claim_info, &signed_claim, None);
+    harness.process(&[verify], &[&new_subject]).await.unwrap();
+    let join = use_invite(&mut harness, &realm, &invite, &new_subject, Some(verification)).await;
+    assert_error(harness.process(&[join], &[&new_subject]).await, RouterError::InvalidReclaimVerification);
+
+    harness.warp_seconds(1).await;
+    let (claim_info, signed_claim) = crate::reclaim::attest_claim(&mut harness, &reclaim, &new_subject.pubkey()).await;
+    let verify = crate::reclaim::reclaim_verify(&reclaim, &new_subject, &claim_info, &signed_claim, Some(dapp));
+    harness.process(&[verify], &[&new_subject]).await.unwrap();
+    let join = use_invite(&mut harness, &realm, &invite, &new_subject, Some(verification)).await;
+    harness.process(&[join], &[&new_subject]).await.unwrap();
+
+    assert_eq!(harness.account::<Kingdom>(&realm.kingdom).await.total_subjects, 1);
+}
+
+#[tokio::test]
+async fn only_the_monarch_sets_the_reclaim_requirement_and_older_kingdoms_grow_to_hold_it() {
+    let mut harness = Harness::start().await;
+    let realm = setup(&mut harness).await;
+
+    // Kingdoms created before the requirement was appended are one `Option` short
+    let kingdom = harness.account::<Kingdom>(&realm.kingdom).await;
+    harness.set_account(&realm.kingdom, &kingdom, Kingdom::SPACE - 1 - ReclaimRequirement::SIZE);
+
+    let requirement = ReclaimRequirement {
+        provider_hash: provider_hash(crate::reclaim::PROVIDER),
+        epoch_config: Pubkey::new_unique(),
+        dapp: Pubkey::new_unique(),
+    };
+    assert_error(
+        harness.process(&[set_reclaim_requirement(&realm, &realm.noble, Some(requirement.clone()))], &[&realm.noble]).await,
+        AbsoluteMonarchyError::NotMonarch,
+    );
+    harness.process(&[set_reclaim_requirement(&realm, &realm.king, Some(requirement.clone()))], &[&realm.king]).await.unwrap();
+
+    let kingdom = harness.account::<Kingdom>(&realm.kingdom).await;
+    assert_eq!(kingdom.reclaim_requirement.map(|r| r.dapp), Some(requirement.dapp));
+    let grown = harness.context.banks_client.get_account(realm.kingdom).await.unwrap().unwrap();
+    assert_eq!(grown.data.len(), Kingdom::SPACE);
+
+    harness.warp_seconds(1).await;
+    harness.process(&[set_reclaim_requirement(&realm, &realm.king, None)], &[&realm.king]).await.unwrap();
+    assert!(harness.account::<Kingdom>(&realm.kingdom).await.reclaim_requirement.is_none());
+}
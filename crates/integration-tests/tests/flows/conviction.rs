@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use ark_client::conviction::{
+    accounts, find_proposal_address, find_stake_address, find_vote_address, instruction, states, ID,
+};
+use conviction::errors::ErrorCode;
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use states::{Governance, Proposal, ProposalStatus, ProposalType, StakeAccount};
+
+pub(crate) const VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+const EXECUTION_DELAY: i64 = 24 * 60 * 60;
+const APPROVAL_THRESHOLD: u64 = 50;
+const NEW_MIN_STAKE: u64 = 250;
+
+pub(crate) struct Conviction {
+    pub(crate) governance: Pubkey,
+    mint: Pubkey,
+    mint_authority: Keypair,
+    governance_ata: Pubkey,
+    pub(crate) proposal: Pubkey,
+}
+
+/// A governance voting with an SPL token, and an active proposal raising its minimum stake.
+async fn setup(harness: &mut Harness) -> Conviction {
+    setup_for(harness, Pubkey::new_unique(), [0; 32]).await
+}
+
+/// [`setup`] with the governance at `governance`, and a proposal that routes the batch of
+/// `instructions_hash` once it passes.
+///
+/// `new_governance` seeds the governance PDA with its own address, so the governance is
+/// written directly; `param_name`/`param_value` have no instruction setting them either.
+pub(crate) async fn setup_for(harness: &mut Harness, governance: Pubkey, instructions_hash: [u8; 32]) -> Conviction {
+    let mint_authority = harness.funded_keypair().await;
+    let mint = harness.create_mint(&mint_authority.pubkey(), 0).await;
+
+    harness.set_account(
+        &governance,
+        &Governance {
+            name: "Long Now".to_string(),
+            description: String::new(),
+            creator: mint_authority.pubkey(),
+            governance_token_mint: mint,
+            nft_mint: None,
+            spl_mint: Some(mint),
+            sbt_mint: None,
+            nft_minted: 0,
+            spl_minted: 0,
+            sbt_minted: 0,
+            nft_symbol: String::new(),
+            spl_symbol: "LONG".to_string(),
+            symbol: "LONG".to_string(),
+            approval_threshold: APPROVAL_THRESHOLD,
+            resources: 0,
+            total_proposals: 0,
+            total_active_proposals: 0,
+            total_members: 0,
+            min_stake_amount: 0,
+            collection_price: 0,
+            total_spl_token_supply: 0,
+            total_nft_token_supply: 0,
+            total_sbt_token_supply: 0,
+            reclaim_requirement: None,
+            bump: 0,
+        },
+        Governance::SPACE,
+    );
+    let governance_ata = harness.create_associated_token_account(&mint, &governance).await;
+
+    let creator = harness.funded_keypair().await;
+    let proposal = find_proposal_address(&governance, 0).0;
+    let new_proposal = build_instruction(
+        ID,
+        accounts::CreateProposal {
+            governance,
+            proposal,
+            creator: creator.pubkey(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::NewProposal {
+            description: "Raise the minimum stake".to_string(),
+            voting_period: VOTING_PERIOD,
+            execution_delay: EXECUTION_DELAY,
+            proposal_type: ProposalType::UpdateParameter,
+            instructions_hash,
+        },
+    );
+    harness.process(&[new_proposal], &[&creator]).await.unwrap();
+
+    harness.update_account::<Proposal>(&proposal, |proposal| {
+        proposal.param_name = Some("min_stake_amount".to_string());
+        proposal.param_value = Some(NEW_MIN_STAKE);
+    }).await;
+
+    Conviction { governance, mint, mint_authority, governance_ata, proposal }
+}
+
+/// A voter holding `amount` governance tokens, staked on the proposal for `lock_period` weeks
+pub(crate) async fn staked_voter(harness: &mut Harness, conviction: &Conviction, amount: u64, lock_period: u8) -> Keypair {
+    let voter = harness.funded_keypair().await;
+    let voter_ata = harness.create_associated_token_account(&conviction.mint, &voter.pubkey()).await;
+    harness.mint_to(&conviction.mint, &voter_ata, &conviction.mint_authority, amount).await;
+
+    let stake = build_instruction(
+        ID,
+        accounts::StakeOnProposal {
+            proposal: conviction.proposal,
+            stake: find_stake_address(&conviction.proposal, &voter.pubkey()).0,
+            mint: conviction.mint,
+            user: voter.pubkey(),
+            user_token_account: voter_ata,
+            proposal_account: conviction.governance_ata,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::StakeSpltokensOnProposal { amount, lock_period },
+    );
+    harness.process(&[stake], &[&voter]).await.unwrap();
+
+    voter
+}
+
+pub(crate) fn cast_vote(conviction: &Conviction, voter: &Keypair, vote: bool, voting_power: u64) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::CastVote {
+            voter: voter.pubkey(),
+            governance: conviction.governance,
+            proposal: conviction.proposal,
+            vote: find_vote_address(&conviction.governance, &conviction.proposal, &voter.pubkey()).0,
+            stake_account: find_stake_address(&conviction.proposal, &voter.pubkey()).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::CastVote { vote, voting_power },
+    )
+}
+
+/// `conclude_proposal` for the parameter update, which reads none of its token accounts
+async fn conclude_proposal(harness: &mut Harness, conviction: &Conviction, executor: &Keypair) -> Instruction {
+    let recipient = harness.create_token_account(&conviction.mint, &executor.pubkey()).await;
+
+    build_instruction(
+        ID,
+        accounts::EndAndExecuteProposal {
+            proposal: conviction.proposal,
+            governance: conviction.governance,
+            executor: executor.pubkey(),
+            treasury: conviction.governance_ata,
+            recipient,
+            spl_mint: conviction.mint,
+            nft_program: system_program::ID,
+            nft_mint: conviction.mint,
+            citizen_nft_ata: recipient,
+            sbt_program: system_program::ID,
+            sbt_mint: conviction.mint,
+            governance_ata: conviction.governance_ata,
+            citizen_sbt_ata: recipient,
+            metadata: Pubkey::new_unique(),
+            token_program: anchor_spl::token::ID,
+            token_2022program: anchor_spl::token_2022::ID,
+            token_metadata_program: anchor_spl::metadata::mpl_token_metadata::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ConcludeProposal {},
+    )
+}
+
+#[tokio::test]
+async fn conviction_vote_passes_and_executes_after_the_delay() {
+    let mut harness = Harness::start().await;
+    let conviction = setup(&mut harness).await;
+
+    // Four weeks of lock quadruple the supporter's stake, outweighing a larger unlocked one
+    let supporter = staked_voter(&mut harness, &conviction, 100, 4).await;
+    let opponent = staked_voter(&mut harness, &conviction, 300, 0).await;
+    let stake = harness.account::<StakeAccount>(&find_stake_address(&conviction.proposal, &supporter.pubkey()).0).await;
+    assert_eq!(stake.conviction_multiplier, 4);
+    assert_eq!(harness.token_balance(&conviction.governance_ata).await, 400);
+
+    harness.process(&[cast_vote(&conviction, &supporter, true, 400)], &[&supporter]).await.unwrap();
+    harness.process(&[cast_vote(&conviction, &opponent, false, 300)], &[&opponent]).await.unwrap();
+    let proposal = harness.account::<Proposal>(&conviction.proposal).await;
+    assert_eq!((proposal.for_votes, proposal.against_votes), (400, 300));
+
+    let executor = harness.funded_keypair().await;
+    let conclude = conclude_proposal(&mut harness, &conviction, &executor).await;
+    harness.warp_seconds(VOTING_PERIOD).await;
+    assert_error(harness.process(std::slice::from_ref(&conclude), &[&executor]).await, ErrorCode::VotingPeriodNotEnded);
+
+    harness.warp_seconds(EXECUTION_DELAY).await;
+    harness.process(&[conclude], &[&executor]).await.unwrap();
+
+    assert!(harness.account::<Proposal>(&conviction.proposal).await.status == ProposalStatus::Executed);
+    assert_eq!(harness.account::<Governance>(&conviction.governance).await.min_stake_amount, NEW_MIN_STAKE);
+}
+
+#[tokio::test]
+async fn proposal_without_a_majority_is_rejected() {
+    let mut harness = Harness::start().await;
+    let conviction = setup(&mut harness).await;
+
+    let supporter = staked_voter(&mut harness, &conviction, 100, 0).await;
+    let opponent = staked_voter(&mut harness, &conviction, 100, 0).await;
+    harness.process(&[cast_vote(&conviction, &supporter, true, 100)], &[&supporter]).await.unwrap();
+    harness.process(&[cast_vote(&conviction, &opponent, false, 100)], &[&opponent]).await.unwrap();
+
+    let executor = harness.funded_keypair().await;
+    let conclude = conclude_proposal(&mut harness, &conviction, &executor).await;
+    harness.warp_seconds(VOTING_PERIOD + EXECUTION_DELAY).await;
+    harness.process(&[conclude], &[&executor]).await.unwrap();
+
+    assert!(harness.account::<Proposal>(&conviction.proposal).await.status == ProposalStatus::Rejected);
+    assert_eq!(harness.account::<Governance>(&conviction.governance).await.min_stake_amount, 0);
+}
+
+#[tokio::test]
+async fn votes_are_bounded_by_stake_and_voting_period() {
+    let mut harness = Harness::start().await;
+    let conviction = setup(&mut harness).await;
+    let voter = staked_voter(&mut harness, &conviction, 100, 1).await;
+
+    // One week of lock doubles the stake
+    let result = harness.process(&[cast_vote(&conviction, &voter, true, 201)], &[&voter]).await;
+    assert_error(result, ErrorCode::ExceedsAvailableVotingPower);
+
+    harness.warp_seconds(VOTING_PERIOD + 1).await;
+    let result = harness.process(&[cast_vote(&conviction, &voter, true, 200)], &[&voter]).await;
+    assert_error(result, ErrorCode::VotingPeriodInactive);
+}
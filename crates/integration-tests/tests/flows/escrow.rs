@@ -0,0 +1,332 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::Discriminator;
+use ark_client::absolute_monarchy::{
+    accounts as monarchy_accounts, find_decision_address, instruction as monarchy_instruction, ID as MONARCHY_ID,
+};
+use ark_client::standard::{accounts, find_escrow_address, find_escrow_vault_address, instruction, ID};
+use integration_tests::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::errors::RouterError;
+use standard::states::escrow::{Escrow, EscrowCondition, MilestoneArgs, MilestoneStatus, DISPUTE_PERIOD};
+use standard::AllowedInstruction;
+use the_ark_program::InstructionContext;
+
+use crate::absolute_monarchy::Realm;
+use crate::router::Router;
+
+const ESCROW_ID: u64 = 1;
+const FULFILLED_AMOUNT: u64 = 400;
+const DISPUTED_AMOUNT: u64 = 600;
+const FUNDING: u64 = 10_000;
+const TERM: i64 = 24 * 60 * 60;
+
+struct Deal {
+    sender: Keypair,
+    recipient: Keypair,
+    // Signs the fulfillment of the second milestone
+    witness: Keypair,
+    escrow: Pubkey,
+    vault: Pubkey,
+    sender_tokens: Pubkey,
+    recipient_tokens: Pubkey,
+}
+
+/// An escrow of a milestone unlocked now and one needing the witness's signature,
+/// expiring after [`TERM`] and arbitrated by the kingdom registered in the router, if any
+async fn setup(harness: &mut Harness, arbiter: Option<(&Router, &Realm)>) -> Deal {
+    let witness = Keypair::new();
+    let condition = EscrowCondition::ArbiterSignature { arbiter: witness.pubkey() };
+    setup_with(harness, arbiter, witness, condition).await
+}
+
+/// [`setup`] with `condition` on the second milestone
+async fn setup_with(
+    harness: &mut Harness,
+    arbiter: Option<(&Router, &Realm)>,
+    witness: Keypair,
+    condition: EscrowCondition,
+) -> Deal {
+    let sender = harness.funded_keypair().await;
+    let recipient = harness.funded_keypair().await;
+    let mint_authority = harness.funded_keypair().await;
+    let mint = harness.create_mint(&mint_authority.pubkey(), 0).await;
+    let sender_tokens = harness.create_token_account(&mint, &sender.pubkey()).await;
+    let recipient_tokens = harness.create_token_account(&mint, &recipient.pubkey()).await;
+    harness.mint_to(&mint, &sender_tokens, &mint_authority, FUNDING).await;
+
+    let now = harness.now().await;
+    let (escrow, _) = find_escrow_address(&sender.pubkey(), ESCROW_ID);
+    let (vault, _) = find_escrow_vault_address(&escrow);
+    let create = build_instruction(
+        ID,
+        accounts::CreateEscrow {
+            escrow,
+            sender: sender.pubkey(),
+            recipient: recipient.pubkey(),
+            mint,
+            sender_token_account: sender_tokens,
+            escrow_token_account: vault,
+            router_state: arbiter.map(|(router, _)| router.state),
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::CreateConditionalEscrow {
+            escrow_id: ESCROW_ID,
+            milestones: vec![
+                MilestoneArgs {
+                    amount: FULFILLED_AMOUNT,
+                    condition: EscrowCondition::Timestamp { unlock_at: now },
+                    release_time: now,
+                },
+                MilestoneArgs {
+                    amount: DISPUTED_AMOUNT,
+                    condition,
+                    release_time: now,
+                },
+            ],
+            expiry_time: now + TERM,
+            arbiter_government_state: arbiter.map(|(_, realm)| realm.monarch),
+        },
+    );
+    harness.process(&[create], &[&sender]).await.unwrap();
+
+    Deal { sender, recipient, witness, escrow, vault, sender_tokens, recipient_tokens }
+}
+
+fn fulfill(deal: &Deal, milestone_index: u8) -> Instruction {
+    fulfill_with(deal, milestone_index, (milestone_index == 1).then(|| deal.witness.pubkey()), None)
+}
+
+/// Fulfill a milestone, signed by `arbiter` or reading `decision_account` if given
+fn fulfill_with(deal: &Deal, milestone_index: u8, arbiter: Option<Pubkey>, decision_account: Option<Pubkey>) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::FulfillCondition {
+            escrow: deal.escrow,
+            recipient: deal.recipient.pubkey(),
+            arbiter,
+            decision_account,
+            epoch_config: None,
+            epoch: None,
+            reclaim_group: None,
+            claim_nullifier: None,
+            reclaim_program: None,
+            system_program: None,
+        },
+        instruction::FulfillPaymentCondition { milestone_index, proof: None },
+    )
+}
+
+/// The monarch's decision on `request`, called directly rather than through a router
+fn decide(realm: &Realm, request: InstructionContext) -> Instruction {
+    build_instruction(
+        MONARCHY_ID,
+        monarchy_accounts::MakeDecision {
+            monarch: realm.monarch,
+            decision_account: find_decision_address(&realm.monarch).0,
+            authority: realm.king.pubkey(),
+            system_program: system_program::ID,
+        },
+        monarchy_instruction::MakeDecision { contexts: vec![request] },
+    )
+}
+
+/// Fulfill the witnessed milestone, signed by the recipient and the witness
+async fn fulfill_witnessed(harness: &mut Harness, deal: &Deal) -> std::result::Result<(), BanksClientError> {
+    harness.process(&[fulfill(deal, 1)], &[&deal.recipient, &deal.witness]).await
+}
+
+fn release(deal: &Deal, milestone_index: u8) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::ReleasePayment {
+            escrow: deal.escrow,
+            sender: deal.sender.pubkey(),
+            recipient: deal.recipient.pubkey(),
+            escrow_token_account: deal.vault,
+            recipient_token_account: deal.recipient_tokens,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ReleasePaymentForCondition { milestone_index },
+    )
+}
+
+fn refund(deal: &Deal) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::Refund {
+            escrow: deal.escrow,
+            sender: deal.sender.pubkey(),
+            escrow_token_account: deal.vault,
+            sender_token_account: deal.sender_tokens,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::RefundPayment {},
+    )
+}
+
+fn raise_dispute(deal: &Deal, party: &Pubkey) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::RaiseDispute { escrow: deal.escrow, party: *party },
+        instruction::RaiseEscrowDispute {},
+    )
+}
+
+fn resolve_dispute(deal: &Deal, router: &Router, executor: &Pubkey, recipient_share_bps: u64) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::ResolveDispute {
+            escrow: deal.escrow,
+            router_state: router.state,
+            executor: *executor,
+            sender: deal.sender.pubkey(),
+            escrow_token_account: deal.vault,
+            sender_token_account: deal.sender_tokens,
+            recipient_token_account: deal.recipient_tokens,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ResolveEscrowDispute { recipient_share_bps },
+    )
+}
+
+/// Register a new kingdom in `router`, allowed to resolve escrow disputes, with its executor
+async fn register_kingdom(harness: &mut Harness, router: &Router) -> (Realm, Pubkey) {
+    let allowlist = vec![AllowedInstruction {
+        program_id: ID,
+        discriminator: instruction::ResolveEscrowDispute::DISCRIMINATOR,
+        account_constraints: vec![],
+    }];
+    let realm = crate::absolute_monarchy::setup(harness).await;
+    let executor = crate::router::register_with_allowlist(harness, router, MONARCHY_ID, realm.monarch, allowlist).await;
+
+    (realm, executor)
+}
+
+/// A router whose kingdom may resolve escrow disputes, with the kingdom's executor
+async fn arbiter(harness: &mut Harness) -> (Router, Realm, Pubkey) {
+    let router = crate::router::setup(harness).await;
+    let (realm, executor) = register_kingdom(harness, &router).await;
+
+    (router, realm, executor)
+}
+
+#[tokio::test]
+async fn fulfilled_milestone_is_released_and_the_rest_refunded_after_expiry() {
+    let mut harness = Harness::start().await;
+    let deal = setup(&mut harness, None).await;
+
+    harness.process(&[fulfill(&deal, 0), release(&deal, 0)], &[&deal.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&deal.recipient_tokens).await, FULFILLED_AMOUNT);
+
+    assert_error(harness.process(&[refund(&deal)], &[&deal.sender]).await, RouterError::NotExpired);
+    harness.warp_seconds(TERM + 1).await;
+    assert_error(fulfill_witnessed(&mut harness, &deal).await, RouterError::Expired);
+
+    harness.process(&[refund(&deal)], &[&deal.sender]).await.unwrap();
+    assert_eq!(harness.token_balance(&deal.sender_tokens).await, FUNDING - FULFILLED_AMOUNT);
+    assert!(!harness.account_exists(&deal.escrow).await);
+}
+
+#[tokio::test]
+async fn arbiter_splits_only_the_pending_milestones() {
+    let mut harness = Harness::start().await;
+    let (router, realm, executor) = arbiter(&mut harness).await;
+    let deal = setup(&mut harness, Some((&router, &realm))).await;
+
+    harness.process(&[fulfill(&deal, 0)], &[&deal.recipient]).await.unwrap();
+    let raise = raise_dispute(&deal, &deal.recipient.pubkey());
+    harness.process(&[raise], &[&deal.recipient]).await.unwrap();
+    assert_error(fulfill_witnessed(&mut harness, &deal).await, RouterError::EscrowDisputed);
+
+    // Only the arbiter's executor can settle it
+    let forged = resolve_dispute(&deal, &router, &deal.recipient.pubkey(), 10_000);
+    assert_error(harness.process(&[forged], &[&deal.recipient]).await, RouterError::Unauthorized);
+
+    // Including the executor of any other kingdom of the same government program
+    let (usurper, usurper_executor) = register_kingdom(&mut harness, &router).await;
+    let forged = resolve_dispute(&deal, &router, &usurper_executor, 10_000);
+    let route = crate::router::royal_route(&router, &usurper, &usurper.king.pubkey(), vec![forged]);
+    assert_error(harness.process(&[route], &[&usurper.king]).await, RouterError::Unauthorized);
+
+    let resolve = resolve_dispute(&deal, &router, &executor, 2_500);
+    let route = crate::router::royal_route(&router, &realm, &realm.king.pubkey(), vec![resolve]);
+    harness.process(&[route], &[&realm.king]).await.unwrap();
+    assert_eq!(harness.token_balance(&deal.recipient_tokens).await, DISPUTED_AMOUNT / 4);
+    assert_eq!(
+        harness.token_balance(&deal.sender_tokens).await,
+        FUNDING - FULFILLED_AMOUNT - DISPUTED_AMOUNT / 4,
+    );
+
+    // The fulfilled milestone was not the arbiter's to split
+    let escrow = harness.account::<Escrow>(&deal.escrow).await;
+    assert_eq!(escrow.milestones[0].status, MilestoneStatus::Fulfilled);
+    harness.process(&[release(&deal, 0)], &[&deal.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&deal.recipient_tokens).await, FULFILLED_AMOUNT + DISPUTED_AMOUNT / 4);
+    assert!(!harness.account_exists(&deal.escrow).await);
+}
+
+#[tokio::test]
+async fn unresolved_dispute_lapses_and_the_escrow_resumes() {
+    let mut harness = Harness::start().await;
+    let (router, realm, executor) = arbiter(&mut harness).await;
+    let deal = setup(&mut harness, Some((&router, &realm))).await;
+
+    harness.process(&[fulfill(&deal, 0)], &[&deal.recipient]).await.unwrap();
+    let raise = raise_dispute(&deal, &deal.sender.pubkey());
+    harness.process(&[raise], &[&deal.sender]).await.unwrap();
+
+    // The dispute holds up the pending milestone, not the fulfilled one
+    assert_error(fulfill_witnessed(&mut harness, &deal).await, RouterError::EscrowDisputed);
+    harness.process(&[release(&deal, 0)], &[&deal.recipient]).await.unwrap();
+
+    // Past the escrow's expiry, the sender can neither refund nor dispute again
+    harness.warp_seconds(TERM + 1).await;
+    assert_error(harness.process(&[refund(&deal)], &[&deal.sender]).await, RouterError::EscrowDisputed);
+    let raise = raise_dispute(&deal, &deal.sender.pubkey());
+    assert_error(harness.process(&[raise], &[&deal.sender]).await, RouterError::EscrowDisputed);
+
+    harness.warp_seconds(DISPUTE_PERIOD - TERM).await;
+    let resolve = resolve_dispute(&deal, &router, &executor, 0);
+    let route = crate::router::royal_route(&router, &realm, &realm.king.pubkey(), vec![resolve]);
+    assert_error(harness.process(&[route], &[&realm.king]).await, RouterError::DisputeExpired);
+
+    // The recipient gets back the time the dispute kept the escrow frozen
+    assert_error(harness.process(&[refund(&deal)], &[&deal.sender]).await, RouterError::NotExpired);
+    fulfill_witnessed(&mut harness, &deal).await.unwrap();
+    harness.process(&[release(&deal, 1)], &[&deal.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&deal.recipient_tokens).await, FULFILLED_AMOUNT + DISPUTED_AMOUNT);
+    assert!(!harness.account_exists(&deal.escrow).await);
+}
+
+#[tokio::test]
+async fn government_decision_fulfills_only_the_milestone_it_approves() {
+    let mut harness = Harness::start().await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    let (decision, _) = find_decision_address(&realm.monarch);
+    let condition = EscrowCondition::GovernmentDecision { government_program: MONARCHY_ID, decision_account: decision };
+    let deal = setup_with(&mut harness, None, Keypair::new(), condition.clone()).await;
+    let other = setup_with(&mut harness, None, Keypair::new(), condition).await;
+    let deal_request = harness.account::<Escrow>(&deal.escrow).await.milestone_request(deal.escrow, 1);
+    let other_request = harness.account::<Escrow>(&other.escrow).await.milestone_request(other.escrow, 1);
+
+    // An approval of another escrow's milestone is no approval of this one
+    harness.process(&[decide(&realm, other_request)], &[&realm.king]).await.unwrap();
+    let result = harness.process(&[fulfill_with(&deal, 1, None, Some(decision))], &[&deal.recipient]).await;
+    assert_error(result, RouterError::ConditionNotMet);
+
+    harness.process(&[decide(&realm, deal_request)], &[&realm.king]).await.unwrap();
+    let result = harness.process(&[fulfill_with(&other, 1, None, Some(decision))], &[&other.recipient]).await;
+    assert_error(result, RouterError::ConditionNotMet);
+    harness.process(&[fulfill_with(&deal, 1, None, Some(decision)), release(&deal, 1)], &[&deal.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&deal.recipient_tokens).await, DISPUTED_AMOUNT);
+
+    // The approval is spent with the milestone
+    harness.warp_seconds(1).await;
+    let result = harness.process(&[fulfill_with(&deal, 1, None, Some(decision))], &[&deal.recipient]).await;
+    assert_error(result, RouterError::AlreadyFulfilled);
+}
@@ -0,0 +1,13 @@
+//! End-to-end flows against every program loaded in one in-process bank
+
+mod absolute_monarchy;
+mod conviction;
+mod escrow;
+mod market;
+mod military_junta;
+mod oracle;
+mod pool;
+mod reclaim;
+mod router;
+mod sortition;
+mod stream;
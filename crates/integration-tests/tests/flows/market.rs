@@ -0,0 +1,469 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use ark_client::standard::{
+    accounts, find_asks_address, find_base_vault_address, find_bids_address, find_event_queue_address,
+    find_market_authority_address, find_open_orders_address, find_quote_vault_address, instruction, ID,
+};
+use integration_tests::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::contexts::PlaceOrderArgs;
+use standard::errors::RouterError;
+use standard::oracle::OracleConfig;
+use standard::states::{
+    EventQueue, Market, MarketEvent, OpenOrders, OrderSide, OrderSlab, OrderType, SelfTradeBehavior, MAX_EVENTS,
+};
+use crate::oracle;
+
+const FUNDING: u64 = 1_000_000;
+
+struct Book {
+    market: Pubkey,
+    market_authority: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_vault: Pubkey,
+    quote_vault: Pubkey,
+    bids: Pubkey,
+    asks: Pubkey,
+    event_queue: Pubkey,
+    price_account: Option<Pubkey>,
+}
+
+struct Trader {
+    owner: Keypair,
+    open_orders: Pubkey,
+    base: Pubkey,
+    quote: Pubkey,
+}
+
+/// An oracle-free market trading in lots of `base_lot_size` at multiples of `tick_size`
+async fn setup(harness: &mut Harness, base_lot_size: u64, tick_size: u64) -> (Book, Keypair) {
+    let mint_authority = harness.funded_keypair().await;
+    let base_mint = harness.create_mint(&mint_authority.pubkey(), 0).await;
+    let quote_mint = harness.create_mint(&mint_authority.pubkey(), 0).await;
+    let book = initialize(harness, base_mint, quote_mint, base_lot_size, tick_size, None).await.unwrap();
+
+    (book, mint_authority)
+}
+
+async fn initialize(
+    harness: &mut Harness,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_lot_size: u64,
+    tick_size: u64,
+    oracle: Option<OracleConfig>,
+) -> std::result::Result<Book, BanksClientError> {
+
+    let market = Keypair::new();
+    let address = market.pubkey();
+    let book = Book {
+        market: address,
+        market_authority: find_market_authority_address(&address).0,
+        base_mint,
+        quote_mint,
+        base_vault: find_base_vault_address(&address).0,
+        quote_vault: find_quote_vault_address(&address).0,
+        bids: find_bids_address(&address).0,
+        asks: find_asks_address(&address).0,
+        event_queue: find_event_queue_address(&address).0,
+        price_account: oracle.as_ref().map(|oracle| oracle.price_account),
+    };
+
+    let authority = harness.funded_keypair().await;
+    let initialize = build_instruction(
+        ID,
+        accounts::InitializeMarket {
+            market: book.market,
+            market_authority: book.market_authority,
+            base_mint,
+            quote_mint,
+            base_vault: book.base_vault,
+            quote_vault: book.quote_vault,
+            bids: book.bids,
+            asks: book.asks,
+            event_queue: book.event_queue,
+            authority: authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeMarket { base_lot_size, tick_size, oracle },
+    );
+    harness.process(&[initialize], &[&authority, &market]).await?;
+
+    Ok(book)
+}
+
+/// A trader holding [`FUNDING`] of both tokens, with open orders on the market
+async fn trader(harness: &mut Harness, book: &Book, mint_authority: &Keypair) -> Trader {
+    let owner = harness.funded_keypair().await;
+    let base = harness.create_token_account(&book.base_mint, &owner.pubkey()).await;
+    let quote = harness.create_token_account(&book.quote_mint, &owner.pubkey()).await;
+    harness.mint_to(&book.base_mint, &base, mint_authority, FUNDING).await;
+    harness.mint_to(&book.quote_mint, &quote, mint_authority, FUNDING).await;
+
+    let (open_orders, _) = find_open_orders_address(&book.market, &owner.pubkey());
+    let create = build_instruction(
+        ID,
+        accounts::CreateOpenOrders {
+            market: book.market,
+            open_orders,
+            owner: owner.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CreateOpenOrders {},
+    );
+    harness.process(&[create], &[&owner]).await.unwrap();
+
+    Trader { owner, open_orders, base, quote }
+}
+
+fn place_order(book: &Book, trader: &Trader, args: PlaceOrderArgs) -> Instruction {
+    let (owner_token_account, vault) = match args.side {
+        OrderSide::Bid => (trader.quote, book.quote_vault),
+        OrderSide::Ask => (trader.base, book.base_vault),
+    };
+
+    build_instruction(
+        ID,
+        accounts::PlaceOrder {
+            market: book.market,
+            bids: book.bids,
+            asks: book.asks,
+            event_queue: book.event_queue,
+            open_orders: trader.open_orders,
+            owner: trader.owner.pubkey(),
+            owner_token_account,
+            vault,
+            price_account: book.price_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::PlaceOrder { args },
+    )
+}
+
+fn order(side: OrderSide, order_type: OrderType, amount: u64, price: u64) -> PlaceOrderArgs {
+    PlaceOrderArgs {
+        side,
+        order_type,
+        amount,
+        price,
+        self_trade_behavior: SelfTradeBehavior::DecrementTake,
+    }
+}
+
+async fn place(
+    harness: &mut Harness,
+    book: &Book,
+    trader: &Trader,
+    side: OrderSide,
+    order_type: OrderType,
+    amount: u64,
+    price: u64,
+) -> std::result::Result<(), BanksClientError> {
+    let place = place_order(book, trader, order(side, order_type, amount, price));
+    harness.process(&[place], &[&trader.owner]).await
+}
+
+fn cancel_order(book: &Book, trader: &Trader, side: OrderSide, order_id: u64) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::CancelOrder {
+            market: book.market,
+            bids: book.bids,
+            asks: book.asks,
+            open_orders: trader.open_orders,
+            owner: trader.owner.pubkey(),
+        },
+        instruction::CancelOrder { side, order_id },
+    )
+}
+
+fn process_orders(book: &Book, limit: u16) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::ProcessOrders {
+            market: book.market,
+            bids: book.bids,
+            asks: book.asks,
+            event_queue: book.event_queue,
+        },
+        instruction::ProcessOrders { limit },
+    )
+}
+
+fn consume_events(book: &Book, traders: &[&Trader], limit: u16) -> Instruction {
+    let mut consume = build_instruction(
+        ID,
+        accounts::ConsumeEvents { market: book.market, event_queue: book.event_queue },
+        instruction::ConsumeEvents { limit },
+    );
+    consume.accounts.extend(traders.iter().map(|trader| AccountMeta::new(trader.open_orders, false)));
+    consume
+}
+
+fn settle_funds(book: &Book, trader: &Trader) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::SettleFunds {
+            market: book.market,
+            market_authority: book.market_authority,
+            open_orders: trader.open_orders,
+            owner: trader.owner.pubkey(),
+            base_vault: book.base_vault,
+            quote_vault: book.quote_vault,
+            owner_base_account: trader.base,
+            owner_quote_account: trader.quote,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::SettleFunds {},
+    )
+}
+
+/// Settle `trader`'s free balances and return their base and quote holdings
+async fn settle(harness: &mut Harness, book: &Book, trader: &Trader) -> (u64, u64) {
+    harness.process(&[settle_funds(book, trader)], &[&trader.owner]).await.unwrap();
+    (harness.token_balance(&trader.base).await, harness.token_balance(&trader.quote).await)
+}
+
+/// Ids and remaining amounts of the resting orders on one side, best first
+async fn resting(harness: &mut Harness, slab: &Pubkey) -> Vec<(u64, u64)> {
+    let slab = harness.account::<OrderSlab>(slab).await;
+    slab.orders.iter().map(|order| (order.id, order.amount)).collect()
+}
+
+#[tokio::test]
+async fn orders_fill_by_price_then_time_and_settle_through_the_crank() {
+    let mut harness = Harness::start().await;
+    let (book, mint_authority) = setup(&mut harness, 1, 1).await;
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+    let bob = trader(&mut harness, &book, &mint_authority).await;
+    let carol = trader(&mut harness, &book, &mint_authority).await;
+    let dave = trader(&mut harness, &book, &mint_authority).await;
+
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 10, 101).await.unwrap();
+    place(&mut harness, &book, &bob, OrderSide::Ask, OrderType::Limit, 10, 100).await.unwrap();
+    place(&mut harness, &book, &carol, OrderSide::Ask, OrderType::Limit, 10, 100).await.unwrap();
+
+    // Bob's ask rested first at the best price, then Carol's
+    place(&mut harness, &book, &dave, OrderSide::Bid, OrderType::Limit, 15, 101).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.asks).await, vec![(3, 5), (1, 10)]);
+    assert_eq!(harness.account::<EventQueue>(&book.event_queue).await.events.len(), 2);
+
+    // A crank with nothing to do is not an error, one missing the users' open orders is
+    harness.process(&[consume_events(&book, &[], 0)], &[]).await.unwrap();
+    let partial = consume_events(&book, &[&dave], 2);
+    assert_error(harness.process(&[partial], &[]).await, RouterError::MissingOpenOrders);
+
+    harness.process(&[consume_events(&book, &[&bob, &carol, &dave], 2)], &[]).await.unwrap();
+    assert!(harness.account::<EventQueue>(&book.event_queue).await.events.is_empty());
+
+    // Dave paid the makers' price and got the rest of his escrow back
+    assert_eq!(settle(&mut harness, &book, &dave).await, (FUNDING + 15, FUNDING - 15 * 100));
+    assert_eq!(settle(&mut harness, &book, &bob).await, (FUNDING - 10, FUNDING + 10 * 100));
+    assert_eq!(settle(&mut harness, &book, &carol).await, (FUNDING - 10, FUNDING + 5 * 100));
+}
+
+#[tokio::test]
+async fn full_event_queue_does_not_block_placing_or_cancelling() {
+    let mut harness = Harness::start().await;
+    let (book, mint_authority) = setup(&mut harness, 1, 1).await;
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+    let bob = trader(&mut harness, &book, &mint_authority).await;
+
+    // Fill the queue with fills nobody has cranked yet
+    let backlog = MarketEvent::Fill {
+        maker: alice.owner.pubkey(),
+        taker: bob.owner.pubkey(),
+        maker_order_id: 0,
+        taker_order_id: 0,
+        taker_side: OrderSide::Bid,
+        price: 0,
+        taker_price: 0,
+        amount: 0,
+    };
+    harness
+        .update_account::<EventQueue>(&book.event_queue, |queue| queue.events = vec![backlog; MAX_EVENTS])
+        .await;
+
+    // Crossing orders rest instead of failing
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 10, 100).await.unwrap();
+    place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::Limit, 10, 100).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.asks).await, vec![(1, 10)]);
+    assert_eq!(resting(&mut harness, &book.bids).await, vec![(2, 10)]);
+
+    // Cancelling frees the funds right away
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 5, 120).await.unwrap();
+    harness.process(&[cancel_order(&book, &alice, OrderSide::Ask, 3)], &[&alice.owner]).await.unwrap();
+    let open_orders = harness.account::<OpenOrders>(&alice.open_orders).await;
+    assert_eq!((open_orders.base_free, open_orders.base_locked), (5, 10));
+
+    // Nor can anyone else cancel for her
+    let forged = cancel_order(&book, &bob, OrderSide::Ask, 1);
+    assert_error(harness.process(&[forged], &[&bob.owner]).await, RouterError::NotOrderOwner);
+
+    // Once cranked, the crossed orders are matched
+    let crank = consume_events(&book, &[&alice, &bob], MAX_EVENTS as u16);
+    harness.process(&[crank], &[]).await.unwrap();
+    harness.process(&[process_orders(&book, 4)], &[]).await.unwrap();
+    assert!(resting(&mut harness, &book.asks).await.is_empty());
+    harness.process(&[consume_events(&book, &[&alice, &bob], 1)], &[]).await.unwrap();
+
+    assert_eq!(settle(&mut harness, &book, &alice).await, (FUNDING - 10, FUNDING + 10 * 100));
+    assert_eq!(settle(&mut harness, &book, &bob).await, (FUNDING + 10, FUNDING - 10 * 100));
+    assert_eq!(harness.account::<Market>(&book.market).await.next_order_id, 4);
+}
+
+#[tokio::test]
+async fn orders_are_paid_in_the_mint_of_their_side() {
+    let mut harness = Harness::start().await;
+    let (book, mint_authority) = setup(&mut harness, 1, 1).await;
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+
+    // A bid escrows quote tokens, so paying it from the base account fails
+    let mut bid = place_order(&book, &alice, order(OrderSide::Bid, OrderType::Limit, 10, 100));
+    let payer = bid.accounts.iter_mut().find(|meta| meta.pubkey == alice.quote).unwrap();
+    payer.pubkey = alice.base;
+    assert_error(
+        harness.process(&[bid], &[&alice.owner]).await,
+        anchor_lang::error::ErrorCode::ConstraintTokenMint,
+    );
+
+    place(&mut harness, &book, &alice, OrderSide::Bid, OrderType::Limit, 10, 100).await.unwrap();
+    assert_eq!(harness.token_balance(&alice.quote).await, FUNDING - 10 * 100);
+    assert_eq!(harness.token_balance(&book.quote_vault).await, 10 * 100);
+    assert_eq!(harness.token_balance(&alice.base).await, FUNDING);
+}
+
+#[tokio::test]
+async fn order_types_decide_what_happens_to_the_unfilled_rest() {
+    let mut harness = Harness::start().await;
+    let (book, mint_authority) = setup(&mut harness, 1, 1).await;
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+    let bob = trader(&mut harness, &book, &mint_authority).await;
+    let carol = trader(&mut harness, &book, &mint_authority).await;
+
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 10, 100).await.unwrap();
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 10, 110).await.unwrap();
+
+    // Immediate-or-cancel takes what crosses and gives back the rest
+    place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::ImmediateOrCancel, 15, 100).await.unwrap();
+    assert!(resting(&mut harness, &book.bids).await.is_empty());
+    assert_eq!(harness.account::<OpenOrders>(&bob.open_orders).await.quote_free, 5 * 100);
+
+    // Fill-or-kill and post-only fail rather than fill partially or cross
+    let fill_or_kill = place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::FillOrKill, 20, 110).await;
+    assert_error(fill_or_kill, RouterError::FillOrKillNotFilled);
+    let post_only = place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::PostOnly, 5, 110).await;
+    assert_error(post_only, RouterError::PostOnlyWouldCross);
+    place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::PostOnly, 5, 105).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.asks).await, vec![(2, 10)]);
+    assert_eq!(resting(&mut harness, &book.bids).await, vec![(4, 5)]);
+
+    // A market ask needs no price and sells at the bid's
+    place(&mut harness, &book, &carol, OrderSide::Ask, OrderType::Market, 3, 0).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.bids).await, vec![(4, 2)]);
+
+    harness.process(&[consume_events(&book, &[&alice, &bob, &carol], 2)], &[]).await.unwrap();
+    assert_eq!(settle(&mut harness, &book, &alice).await, (FUNDING - 20, FUNDING + 10 * 100));
+    assert_eq!(settle(&mut harness, &book, &bob).await, (FUNDING + 13, FUNDING - 10 * 100 - 5 * 105));
+    assert_eq!(settle(&mut harness, &book, &carol).await, (FUNDING - 3, FUNDING + 3 * 105));
+}
+
+#[tokio::test]
+async fn self_trades_follow_the_order_policy() {
+    let mut harness = Harness::start().await;
+    let (book, mint_authority) = setup(&mut harness, 1, 1).await;
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+
+    place(&mut harness, &book, &alice, OrderSide::Bid, OrderType::Limit, 5, 100).await.unwrap();
+
+    let abort = PlaceOrderArgs {
+        self_trade_behavior: SelfTradeBehavior::AbortTransaction,
+        ..order(OrderSide::Ask, OrderType::Limit, 2, 100)
+    };
+    let abort = place_order(&book, &alice, abort);
+    assert_error(harness.process(&[abort], &[&alice.owner]).await, RouterError::SelfTrade);
+
+    // Decrementing shrinks both orders without trading
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 2, 100).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.bids).await, vec![(1, 3)]);
+    assert!(resting(&mut harness, &book.asks).await.is_empty());
+
+    // Cancelling the resting order lets the rest of the new one rest instead
+    let cancel_provide = PlaceOrderArgs {
+        self_trade_behavior: SelfTradeBehavior::CancelProvide,
+        ..order(OrderSide::Ask, OrderType::Limit, 4, 100)
+    };
+    let cancel_provide = place_order(&book, &alice, cancel_provide);
+    harness.process(&[cancel_provide], &[&alice.owner]).await.unwrap();
+    assert!(resting(&mut harness, &book.bids).await.is_empty());
+    assert_eq!(resting(&mut harness, &book.asks).await, vec![(3, 4)]);
+
+    assert!(harness.account::<EventQueue>(&book.event_queue).await.events.is_empty());
+    let open_orders = harness.account::<OpenOrders>(&alice.open_orders).await;
+    assert_eq!((open_orders.base_free, open_orders.base_locked), (2, 4));
+    assert_eq!((open_orders.quote_free, open_orders.quote_locked), (5 * 100, 0));
+}
+
+#[tokio::test]
+async fn orders_must_be_whole_lots_and_ticks() {
+    let mut harness = Harness::start().await;
+    let (book, mint_authority) = setup(&mut harness, 10, 5).await;
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+
+    for (amount, price) in [(15, 100), (10, 102)] {
+        let result = place(&mut harness, &book, &alice, OrderSide::Bid, OrderType::Limit, amount, price).await;
+        assert_error(result, RouterError::InvalidOrderSize);
+    }
+    place(&mut harness, &book, &alice, OrderSide::Bid, OrderType::Limit, 20, 105).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.bids).await, vec![(1, 20)]);
+}
+
+#[tokio::test]
+async fn oracle_guards_market_orders_against_stale_unsure_or_deviating_prices() {
+    let mut harness = Harness::start().await;
+    let mock = oracle::mock_price(&mut harness).await;
+    // A base token with 6 decimals priced at 100 quote tokens with 9, so 100_000 per unit
+    let mint_authority = harness.funded_keypair().await;
+    let base_mint = harness.create_mint(&mint_authority.pubkey(), 6).await;
+    let quote_mint = harness.create_mint(&mint_authority.pubkey(), 9).await;
+
+    let swapped = oracle::mock_oracle(&mock, 9, 6);
+    let mismatch = initialize(&mut harness, base_mint, quote_mint, 1, 1, Some(swapped)).await;
+    assert_error(mismatch.map(|_| ()), RouterError::OracleDecimalsMismatch);
+
+    let config = oracle::mock_oracle(&mock, 6, 9);
+    let book = initialize(&mut harness, base_mint, quote_mint, 1, 1, Some(config)).await.unwrap();
+    let alice = trader(&mut harness, &book, &mint_authority).await;
+    let bob = trader(&mut harness, &book, &mint_authority).await;
+
+    place(&mut harness, &book, &alice, OrderSide::Ask, OrderType::Limit, 10, 100_000).await.unwrap();
+    place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::Market, 2, 100_000).await.unwrap();
+    assert_eq!(resting(&mut harness, &book.asks).await, vec![(1, 8)]);
+
+    let mut unpriced = place_order(&book, &bob, order(OrderSide::Bid, OrderType::Market, 1, 100_000));
+    unpriced.accounts[8].pubkey = ID;
+    assert_error(harness.process(&[unpriced], &[&bob.owner]).await, RouterError::InvalidOracleAccount);
+
+    oracle::publish(&mut harness, &mock, oracle::PRICE, oracle::PRICE as u64 / 50).await;
+    let unsure = place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::Market, 1, 100_000).await;
+    assert_error(unsure, RouterError::OracleConfidenceTooWide);
+
+    oracle::publish(&mut harness, &mock, oracle::PRICE, 0).await;
+    harness.warp_seconds(oracle::MAX_AGE + 1).await;
+    let stale = place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::Market, 3, 100_000).await;
+    assert_error(stale, RouterError::StaleOraclePrice);
+
+    // Limit orders carry their own price and are not checked
+    place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::Limit, 1, 100_000).await.unwrap();
+
+    // The book now trades at twice the oracle price
+    oracle::publish(&mut harness, &mock, oracle::PRICE / 2, 0).await;
+    let deviating = place(&mut harness, &book, &bob, OrderSide::Bid, OrderType::Market, 4, 100_000).await;
+    assert_error(deviating, RouterError::OraclePriceDeviation);
+    assert_eq!(resting(&mut harness, &book.asks).await, vec![(1, 7)]);
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use ark_client::military_junta::{
+    accounts, find_citizen_address, find_junta_address, find_rebel_address, instruction, states, ID,
+};
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use states::{Citizen, InitializeJuntaArgs, Junta, JuntaTokenConfig, JuntaTokenType, PrimaryJuntaToken, Rebel};
+
+const JUNTA_NAME: &str = "Iron Council";
+
+/// A junta ruling with an existing SPL token, and its leader
+async fn setup(harness: &mut Harness) -> (Pubkey, Keypair) {
+    let leader = harness.funded_keypair().await;
+    let mint = harness.create_mint(&leader.pubkey(), 6).await;
+    let (junta, _) = find_junta_address(JUNTA_NAME);
+
+    let initialize = build_instruction(
+        ID,
+        accounts::InitializeJunta {
+            junta,
+            leader: leader.pubkey(),
+            nft_mint: None,
+            spl_mint: Some(mint),
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeMilJunta {
+            args: InitializeJuntaArgs {
+                name: JUNTA_NAME.to_string(),
+                supply: 0,
+                symbol: "IRON".to_string(),
+                support_threshold: 50,
+                collection_price: 0,
+                nft_config: None,
+                spl_config: Some(JuntaTokenConfig { token_type: JuntaTokenType::Existing, token_mint: mint }),
+                nft_symbol: String::new(),
+                spl_symbol: "IRON".to_string(),
+                nft_supply: 0,
+                spl_supply: 0,
+                primary_junta_token: PrimaryJuntaToken::SPL,
+                reclaim_requirement: None,
+            },
+        },
+    );
+    harness.process(&[initialize], &[&leader]).await.unwrap();
+
+    (junta, leader)
+}
+
+/// Register a citizen, moving the junta to a fresh citizen slot for the next rebel
+async fn init_citizen(harness: &mut Harness, junta: &Pubkey) {
+    let total_subjects = harness.account::<Junta>(junta).await.total_subjects;
+    let authority = harness.funded_keypair().await;
+
+    let init_citizen = build_instruction(
+        ID,
+        accounts::InitializeCitizen {
+            junta: *junta,
+            citizen: find_citizen_address(junta, total_subjects).0,
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitCitizen { authority: authority.pubkey() },
+    );
+    harness.process(&[init_citizen], &[&authority]).await.unwrap();
+}
+
+async fn start_rebellion(harness: &mut Harness, junta: &Pubkey, rebel_leader: &Keypair) -> Instruction {
+    let total_subjects = harness.account::<Junta>(junta).await.total_subjects;
+
+    let mut start_rebel = build_instruction(
+        ID,
+        accounts::StartRebellion {
+            junta: *junta,
+            rebel_leader: rebel_leader.pubkey(),
+            rebels: find_rebel_address(junta, total_subjects).0,
+            citizen: find_citizen_address(junta, total_subjects).0,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::StartRebel {},
+    );
+    // `StartRebellion` declares a `rebellion_scale` argument the instruction doesn't take
+    start_rebel.data.extend_from_slice(&1u64.to_le_bytes());
+    start_rebel
+}
+
+#[tokio::test]
+async fn rebellion_raises_dissent_and_weakens_control() {
+    let mut harness = Harness::start().await;
+    let (junta, _) = setup(&mut harness).await;
+    init_citizen(&mut harness, &junta).await;
+
+    let total_subjects = harness.account::<Junta>(&junta).await.total_subjects;
+    let rebel_leader = harness.funded_keypair().await;
+    let start_rebel = start_rebellion(&mut harness, &junta, &rebel_leader).await;
+    harness.process(&[start_rebel], &[&rebel_leader]).await.unwrap();
+
+    let state = harness.account::<Junta>(&junta).await;
+    assert_eq!(state.dissent_level, 1);
+    assert_eq!(state.control_level, 199);
+    assert!(!state.is_overthrown);
+
+    let rebels = harness.account::<Rebel>(&find_rebel_address(&junta, total_subjects).0).await;
+    assert_eq!(rebels.count, 1);
+    let citizen = harness.account::<Citizen>(&find_citizen_address(&junta, total_subjects).0).await;
+    assert!(citizen.is_dissident);
+}
+
+#[tokio::test]
+async fn rebellion_overthrows_a_junta_that_lost_control() {
+    let mut harness = Harness::start().await;
+    let (junta, _) = setup(&mut harness).await;
+    init_citizen(&mut harness, &junta).await;
+
+    // One rebellion away from losing control
+    harness.update_account::<Junta>(&junta, |junta| junta.control_level = 1).await;
+
+    let rebel_leader = harness.funded_keypair().await;
+    let start_rebel = start_rebellion(&mut harness, &junta, &rebel_leader).await;
+    harness.process(&[start_rebel], &[&rebel_leader]).await.unwrap();
+
+    let state = harness.account::<Junta>(&junta).await;
+    assert_eq!(state.control_level, 0);
+    assert!(state.is_overthrown);
+}
+
+#[tokio::test]
+async fn martial_law_is_reserved_to_the_leader() {
+    let mut harness = Harness::start().await;
+    let (junta, leader) = setup(&mut harness).await;
+    init_citizen(&mut harness, &junta).await;
+
+    let rebel_leader = harness.funded_keypair().await;
+    let start_rebel = start_rebellion(&mut harness, &junta, &rebel_leader).await;
+    harness.process(&[start_rebel], &[&rebel_leader]).await.unwrap();
+
+    let martial_law = |leader: &Keypair| build_instruction(
+        ID,
+        accounts::ImposeMartialLaw { junta, leader: leader.pubkey() },
+        instruction::ImposeMartiallaw {},
+    );
+    assert!(harness.process(&[martial_law(&rebel_leader)], &[&rebel_leader]).await.is_err());
+
+    harness.process(&[martial_law(&leader)], &[&leader]).await.unwrap();
+    let state = harness.account::<Junta>(&junta).await;
+    assert!(state.martial_law_active);
+    assert_eq!(state.dissent_level, 0);
+}
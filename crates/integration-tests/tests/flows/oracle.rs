@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use ark_client::standard::{accounts, instruction, ID};
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::oracle::{MockPriceAccount, OracleConfig, OracleSource};
+
+/// 100 whole quote tokens per whole base token, as `price * 10^EXPO`
+pub(crate) const PRICE: i64 = 10_000_000_000;
+pub(crate) const EXPO: i32 = -8;
+pub(crate) const MAX_AGE: i64 = 60;
+pub(crate) const MAX_CONFIDENCE_BPS: u64 = 100;
+pub(crate) const MAX_DEVIATION_BPS: u64 = 100;
+
+pub(crate) struct MockPrice {
+    pub(crate) account: Pubkey,
+    pub(crate) authority: Keypair,
+}
+
+/// A mock price account publishing [`PRICE`] with no confidence interval
+pub(crate) async fn mock_price(harness: &mut Harness) -> MockPrice {
+    let authority = harness.funded_keypair().await;
+    let account = Keypair::new();
+
+    let initialize = build_instruction(
+        ID,
+        accounts::InitializeMockPrice {
+            price_account: account.pubkey(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeMockPriceAccount {},
+    );
+    harness.process(&[initialize], &[&authority, &account]).await.unwrap();
+
+    let mock = MockPrice { account: account.pubkey(), authority };
+    publish(harness, &mock, PRICE, 0).await;
+    mock
+}
+
+/// Publish `price` with a confidence interval of `conf`, stamped with the current time
+pub(crate) async fn publish(harness: &mut Harness, mock: &MockPrice, price: i64, conf: u64) {
+    let update = build_instruction(
+        ID,
+        accounts::SetMockPrice { price_account: mock.account, authority: mock.authority.pubkey() },
+        instruction::UpdateMockPrice { price, conf, expo: EXPO },
+    );
+    harness.process(&[update], &[&mock.authority]).await.unwrap();
+}
+
+/// A guard reading `mock` for trades between mints of the given decimals
+pub(crate) fn mock_oracle(mock: &MockPrice, base_decimals: u8, quote_decimals: u8) -> OracleConfig {
+    OracleConfig {
+        source: OracleSource::Mock,
+        price_account: mock.account,
+        max_age: MAX_AGE,
+        max_confidence_bps: MAX_CONFIDENCE_BPS,
+        max_deviation_bps: MAX_DEVIATION_BPS,
+        base_decimals,
+        quote_decimals,
+    }
+}
+
+#[tokio::test]
+async fn only_the_authority_publishes_mock_prices() {
+    let mut harness = Harness::start().await;
+    let mock = mock_price(&mut harness).await;
+    let stranger = harness.funded_keypair().await;
+
+    let forged = build_instruction(
+        ID,
+        accounts::SetMockPrice { price_account: mock.account, authority: stranger.pubkey() },
+        instruction::UpdateMockPrice { price: 1, conf: 0, expo: EXPO },
+    );
+    assert_error(
+        harness.process(&[forged], &[&stranger]).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+
+    let published = harness.account::<MockPriceAccount>(&mock.account).await;
+    assert_eq!((published.price, published.expo), (PRICE, EXPO));
+    assert_eq!(published.publish_time, harness.now().await);
+}
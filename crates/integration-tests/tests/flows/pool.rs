@@ -0,0 +1,382 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use ark_client::standard::{
+    accounts, find_locked_lp_address, find_lp_mint_address, find_pool_address, find_pool_reserve_address,
+    instruction, ID,
+};
+use ark_client::the_ark_program::{self as ark, find_treasury_address};
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::contexts::swap::calculate_amount_out;
+use standard::errors::RouterError;
+use standard::oracle::OracleConfig;
+use standard::states::pool::{MAX_SWAP_FEE, MINIMUM_LIQUIDITY};
+use crate::oracle;
+
+const SWAP_FEE: u64 = 30;
+const DEPOSIT: u64 = 1_000_000;
+const FUNDING: u64 = 10 * DEPOSIT;
+
+struct Pair {
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    mint_authority: Keypair,
+}
+
+struct Pool {
+    address: Pubkey,
+    token_a_reserve: Pubkey,
+    token_b_reserve: Pubkey,
+    lp_mint: Pubkey,
+    locked_lp: Pubkey,
+    price_account: Option<Pubkey>,
+}
+
+/// Token accounts of a liquidity provider or trader
+struct Holdings {
+    token_a: Pubkey,
+    token_b: Pubkey,
+    lp_token: Pubkey,
+}
+
+async fn create_pair(harness: &mut Harness) -> Pair {
+    create_pair_with_decimals(harness, 6, 6).await
+}
+
+async fn create_pair_with_decimals(harness: &mut Harness, decimals_a: u8, decimals_b: u8) -> Pair {
+    let mint_authority = harness.funded_keypair().await;
+    let token_a_mint = harness.create_mint(&mint_authority.pubkey(), decimals_a).await;
+    let token_b_mint = harness.create_mint(&mint_authority.pubkey(), decimals_b).await;
+
+    Pair { token_a_mint, token_b_mint, mint_authority }
+}
+
+fn pool_accounts(pair: &Pair, swap_fee: u64, oracle: &Option<OracleConfig>) -> Pool {
+    let (address, _) = find_pool_address(&pair.token_a_mint, &pair.token_b_mint, swap_fee, oracle);
+
+    Pool {
+        address,
+        token_a_reserve: find_pool_reserve_address(&address, &pair.token_a_mint).0,
+        token_b_reserve: find_pool_reserve_address(&address, &pair.token_b_mint).0,
+        lp_mint: find_lp_mint_address(&address).0,
+        locked_lp: find_locked_lp_address(&address).0,
+        price_account: oracle.as_ref().map(|oracle| oracle.price_account),
+    }
+}
+
+fn initialize_pool(payer: &Pubkey, pair: &Pair, swap_fee: u64, oracle: Option<OracleConfig>) -> Instruction {
+    let pool = pool_accounts(pair, swap_fee, &oracle);
+
+    build_instruction(
+        ID,
+        accounts::InitializePool {
+            payer: *payer,
+            pool: pool.address,
+            token_a_mint: pair.token_a_mint,
+            token_b_mint: pair.token_b_mint,
+            token_a_reserve: pool.token_a_reserve,
+            token_b_reserve: pool.token_b_reserve,
+            lp_mint: pool.lp_mint,
+            locked_lp: pool.locked_lp,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializePool { swap_fee, oracle },
+    )
+}
+
+/// An oracle-free pool over a new pair, with no liquidity yet
+async fn setup(harness: &mut Harness, swap_fee: u64) -> (Pair, Pool) {
+    let pair = create_pair(harness).await;
+    let payer = harness.funded_keypair().await;
+    harness.process(&[initialize_pool(&payer.pubkey(), &pair, swap_fee, None)], &[&payer]).await.unwrap();
+
+    let pool = pool_accounts(&pair, swap_fee, &None);
+    (pair, pool)
+}
+
+/// Token accounts of `owner` holding [`FUNDING`] of both pool tokens
+async fn fund(harness: &mut Harness, pair: &Pair, pool: &Pool, owner: &Pubkey) -> Holdings {
+    let token_a = harness.create_token_account(&pair.token_a_mint, owner).await;
+    let token_b = harness.create_token_account(&pair.token_b_mint, owner).await;
+    let lp_token = harness.create_token_account(&pool.lp_mint, owner).await;
+    harness.mint_to(&pair.token_a_mint, &token_a, &pair.mint_authority, FUNDING).await;
+    harness.mint_to(&pair.token_b_mint, &token_b, &pair.mint_authority, FUNDING).await;
+
+    Holdings { token_a, token_b, lp_token }
+}
+
+fn modify_liquidity(pool: &Pool, provider: &Pubkey, holdings: &Holdings) -> accounts::ModifyLiquidity {
+    accounts::ModifyLiquidity {
+        provider: *provider,
+        pool: pool.address,
+        token_a_reserve: pool.token_a_reserve,
+        token_b_reserve: pool.token_b_reserve,
+        lp_mint: pool.lp_mint,
+        locked_lp: pool.locked_lp,
+        provider_token_a: holdings.token_a,
+        provider_token_b: holdings.token_b,
+        provider_lp_token: holdings.lp_token,
+        token_program: anchor_spl::token::ID,
+    }
+}
+
+fn add_liquidity(pool: &Pool, provider: &Pubkey, holdings: &Holdings, max_amount_a: u64, max_amount_b: u64) -> Instruction {
+    build_instruction(
+        ID,
+        modify_liquidity(pool, provider, holdings),
+        instruction::AddLiquidity { max_amount_a, max_amount_b, minimum_lp_out: 0 },
+    )
+}
+
+fn remove_liquidity(pool: &Pool, provider: &Pubkey, holdings: &Holdings, lp_amount: u64) -> Instruction {
+    build_instruction(
+        ID,
+        modify_liquidity(pool, provider, holdings),
+        instruction::RemoveLiquidity { lp_amount, minimum_amount_a: 0, minimum_amount_b: 0 },
+    )
+}
+
+fn swap(pool: &Pool, user: &Pubkey, holdings: &Holdings, amount_in: u64, minimum_amount_out: u64) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::Swap {
+            user: *user,
+            pool: pool.address,
+            token_a_reserve: pool.token_a_reserve,
+            token_b_reserve: pool.token_b_reserve,
+            user_token_a: holdings.token_a,
+            user_token_b: holdings.token_b,
+            price_account: pool.price_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::Swap { amount_in, minimum_amount_out, a_to_b: true },
+    )
+}
+
+#[tokio::test]
+async fn first_deposit_locks_minimum_liquidity() {
+    let mut harness = Harness::start().await;
+    let (pair, pool) = setup(&mut harness, SWAP_FEE).await;
+    let provider = harness.funded_keypair().await;
+    let holdings = fund(&mut harness, &pair, &pool, &provider.pubkey()).await;
+
+    // A deposit worth no more than the locked amount mints nothing
+    let dust = add_liquidity(&pool, &provider.pubkey(), &holdings, MINIMUM_LIQUIDITY, MINIMUM_LIQUIDITY);
+    assert_error(harness.process(&[dust], &[&provider]).await, RouterError::InsufficientLiquidity);
+
+    let deposit = add_liquidity(&pool, &provider.pubkey(), &holdings, DEPOSIT, DEPOSIT);
+    harness.process(&[deposit], &[&provider]).await.unwrap();
+    assert_eq!(harness.token_balance(&holdings.lp_token).await, DEPOSIT - MINIMUM_LIQUIDITY);
+    assert_eq!(harness.token_balance(&pool.locked_lp).await, MINIMUM_LIQUIDITY);
+
+    // Withdrawing every LP token the provider holds leaves the locked share behind
+    let withdraw = remove_liquidity(&pool, &provider.pubkey(), &holdings, DEPOSIT - MINIMUM_LIQUIDITY);
+    harness.process(&[withdraw], &[&provider]).await.unwrap();
+    assert_eq!(harness.token_balance(&holdings.token_a).await, FUNDING - MINIMUM_LIQUIDITY);
+    assert_eq!(harness.token_balance(&pool.token_a_reserve).await, MINIMUM_LIQUIDITY);
+    assert_eq!(harness.token_balance(&pool.token_b_reserve).await, MINIMUM_LIQUIDITY);
+
+    // Later deposits are still priced against the remaining reserves
+    let deposit = add_liquidity(&pool, &provider.pubkey(), &holdings, DEPOSIT, DEPOSIT);
+    harness.process(&[deposit], &[&provider]).await.unwrap();
+    assert_eq!(harness.token_balance(&holdings.lp_token).await, DEPOSIT);
+}
+
+#[tokio::test]
+async fn first_depositor_takes_tokens_left_in_the_reserves() {
+    let mut harness = Harness::start().await;
+    let (pair, pool) = setup(&mut harness, SWAP_FEE).await;
+    let provider = harness.funded_keypair().await;
+    let holdings = fund(&mut harness, &pair, &pool, &provider.pubkey()).await;
+    harness.mint_to(&pair.token_a_mint, &pool.token_a_reserve, &pair.mint_authority, DEPOSIT).await;
+
+    let deposit = add_liquidity(&pool, &provider.pubkey(), &holdings, DEPOSIT, DEPOSIT);
+    harness.process(&[deposit], &[&provider]).await.unwrap();
+
+    // isqrt(2 * DEPOSIT * DEPOSIT), less the locked share
+    let lp_amount = 1_414_213 - MINIMUM_LIQUIDITY;
+    assert_eq!(harness.token_balance(&holdings.lp_token).await, lp_amount);
+
+    let withdraw = remove_liquidity(&pool, &provider.pubkey(), &holdings, lp_amount);
+    harness.process(&[withdraw], &[&provider]).await.unwrap();
+    let withdrawn_a = harness.token_balance(&holdings.token_a).await - (FUNDING - DEPOSIT);
+    assert_eq!(withdrawn_a, lp_amount * 2 * DEPOSIT / (lp_amount + MINIMUM_LIQUIDITY));
+    assert!(withdrawn_a > DEPOSIT);
+}
+
+#[tokio::test]
+async fn each_fee_is_a_separate_pool() {
+    let mut harness = Harness::start().await;
+    let pair = create_pair(&mut harness).await;
+    let payer = harness.funded_keypair().await;
+    let high_fee = 100;
+
+    let too_high = initialize_pool(&payer.pubkey(), &pair, MAX_SWAP_FEE + 1, None);
+    assert_error(harness.process(&[too_high], &[&payer]).await, RouterError::InvalidSwapFee);
+
+    harness.process(&[initialize_pool(&payer.pubkey(), &pair, SWAP_FEE, None)], &[&payer]).await.unwrap();
+    harness.process(&[initialize_pool(&payer.pubkey(), &pair, high_fee, None)], &[&payer]).await.unwrap();
+    let again = initialize_pool(&payer.pubkey(), &pair, SWAP_FEE, None);
+    assert!(harness.process(&[again], &[&payer]).await.is_err());
+
+    let trader = harness.funded_keypair().await;
+    let amount_in = 10_000;
+    let mut received = Vec::new();
+    for swap_fee in [SWAP_FEE, high_fee] {
+        let pool = pool_accounts(&pair, swap_fee, &None);
+        let holdings = fund(&mut harness, &pair, &pool, &trader.pubkey()).await;
+        let deposit = add_liquidity(&pool, &trader.pubkey(), &holdings, DEPOSIT, DEPOSIT);
+        harness.process(&[deposit], &[&trader]).await.unwrap();
+
+        let expected = calculate_amount_out(amount_in, DEPOSIT, DEPOSIT, swap_fee).unwrap();
+        let greedy = swap(&pool, &trader.pubkey(), &holdings, amount_in, expected + 1);
+        assert_error(harness.process(&[greedy], &[&trader]).await, RouterError::ExcessiveSlippage);
+
+        let swap = swap(&pool, &trader.pubkey(), &holdings, amount_in, expected);
+        harness.process(&[swap], &[&trader]).await.unwrap();
+        let balance = harness.token_balance(&holdings.token_b).await;
+        assert_eq!(balance, FUNDING - DEPOSIT + expected);
+        received.push(expected);
+    }
+    assert!(received[1] < received[0]);
+}
+
+#[tokio::test]
+async fn treasury_liquidity_only_moves_between_the_pool_and_treasury_accounts() {
+    let mut harness = Harness::start().await;
+    let (pair, pool) = setup(&mut harness, SWAP_FEE).await;
+    let owner = harness.funded_keypair().await;
+    let authority = harness.funded_keypair().await;
+    let name = "liquidity".to_string();
+    let (treasury, _) = find_treasury_address(&owner.pubkey(), &name);
+
+    let create_treasury = build_instruction(
+        ark::ID,
+        ark::accounts::CreateTreasury {
+            treasury,
+            owner: owner.pubkey(),
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        ark::instruction::CreateGovernmentTreasury { name, authority: authority.pubkey() },
+    );
+    harness.process(&[create_treasury], &[&owner]).await.unwrap();
+    let holdings = fund(&mut harness, &pair, &pool, &treasury).await;
+
+    let treasury_liquidity = |holdings: &Holdings| ark::accounts::TreasuryLiquidity {
+        treasury,
+        authority: authority.pubkey(),
+        pool: pool.address,
+        token_a_reserve: pool.token_a_reserve,
+        token_b_reserve: pool.token_b_reserve,
+        lp_mint: pool.lp_mint,
+        locked_lp: pool.locked_lp,
+        treasury_token_a: holdings.token_a,
+        treasury_token_b: holdings.token_b,
+        treasury_lp_token: holdings.lp_token,
+        token_program: anchor_spl::token::ID,
+        pool_program: ID,
+    };
+    let deposit = || ark::instruction::AddTreasuryLiquidity {
+        max_amount_a: DEPOSIT,
+        max_amount_b: DEPOSIT,
+        minimum_lp_out: 0,
+    };
+
+    // LP tokens can't be minted to an account the treasury doesn't own
+    let outside_lp = harness.create_token_account(&pool.lp_mint, &authority.pubkey()).await;
+    let elsewhere = build_instruction(
+        ark::ID,
+        treasury_liquidity(&Holdings { lp_token: outside_lp, ..holdings }),
+        deposit(),
+    );
+    assert_error(
+        harness.process(&[elsewhere], &[&authority]).await,
+        anchor_lang::error::ErrorCode::ConstraintTokenOwner,
+    );
+
+    let add = build_instruction(ark::ID, treasury_liquidity(&holdings), deposit());
+    harness.process(&[add], &[&authority]).await.unwrap();
+    assert_eq!(harness.token_balance(&holdings.lp_token).await, DEPOSIT - MINIMUM_LIQUIDITY);
+    assert_eq!(harness.token_balance(&holdings.token_a).await, FUNDING - DEPOSIT);
+
+    let remove = build_instruction(
+        ark::ID,
+        treasury_liquidity(&holdings),
+        ark::instruction::RemoveTreasuryLiquidity {
+            lp_amount: DEPOSIT - MINIMUM_LIQUIDITY,
+            minimum_amount_a: 0,
+            minimum_amount_b: 0,
+        },
+    );
+    harness.process(&[remove], &[&authority]).await.unwrap();
+    assert_eq!(harness.token_balance(&holdings.lp_token).await, 0);
+    assert_eq!(harness.token_balance(&holdings.token_a).await, FUNDING - MINIMUM_LIQUIDITY);
+    assert_eq!(harness.token_balance(&holdings.token_b).await, FUNDING - MINIMUM_LIQUIDITY);
+}
+
+#[tokio::test]
+async fn oracle_guards_swaps_against_stale_unsure_or_deviating_prices() {
+    let mut harness = Harness::start().await;
+    let mock = oracle::mock_price(&mut harness).await;
+    // A base token with 9 decimals priced at 100 quote tokens with 6
+    let pair = create_pair_with_decimals(&mut harness, 9, 6).await;
+    let payer = harness.funded_keypair().await;
+
+    let swapped = oracle::mock_oracle(&mock, 6, 6);
+    let mismatch = initialize_pool(&payer.pubkey(), &pair, SWAP_FEE, Some(swapped));
+    assert_error(harness.process(&[mismatch], &[&payer]).await, RouterError::OracleDecimalsMismatch);
+
+    let config = oracle::mock_oracle(&mock, 9, 6);
+    let initialize = initialize_pool(&payer.pubkey(), &pair, SWAP_FEE, Some(config.clone()));
+    harness.process(&[initialize], &[&payer]).await.unwrap();
+    let pool = pool_accounts(&pair, SWAP_FEE, &Some(config));
+
+    // 0.005 base against 0.5 quote matches the oracle price
+    let trader = harness.funded_keypair().await;
+    let holdings = fund(&mut harness, &pair, &pool, &trader.pubkey()).await;
+    let deposit = add_liquidity(&pool, &trader.pubkey(), &holdings, FUNDING / 2, FUNDING / 20);
+    harness.process(&[deposit], &[&trader]).await.unwrap();
+
+    // A small swap only pays the fee and a little slippage
+    harness.process(&[swap(&pool, &trader.pubkey(), &holdings, 10_000, 0)], &[&trader]).await.unwrap();
+
+    // Swapping a fifth of the reserve moves the price too far from the oracle
+    let large = swap(&pool, &trader.pubkey(), &holdings, FUNDING / 10, 0);
+    assert_error(harness.process(&[large], &[&trader]).await, RouterError::OraclePriceDeviation);
+
+    let mut unpriced = swap(&pool, &trader.pubkey(), &holdings, 10_000, 0);
+    unpriced.accounts[6].pubkey = ID;
+    assert_error(harness.process(&[unpriced], &[&trader]).await, RouterError::InvalidOracleAccount);
+
+    oracle::publish(&mut harness, &mock, oracle::PRICE, oracle::PRICE as u64 / 50).await;
+    let unsure = swap(&pool, &trader.pubkey(), &holdings, 10_001, 0);
+    assert_error(harness.process(&[unsure], &[&trader]).await, RouterError::OracleConfidenceTooWide);
+
+    oracle::publish(&mut harness, &mock, oracle::PRICE, 0).await;
+    harness.warp_seconds(oracle::MAX_AGE + 1).await;
+    let stale = swap(&pool, &trader.pubkey(), &holdings, 10_002, 0);
+    assert_error(harness.process(&[stale], &[&trader]).await, RouterError::StaleOraclePrice);
+}
+
+#[tokio::test]
+async fn pricing_huge_reserves_fails_instead_of_overflowing() {
+    let overflow: anchor_lang::error::Error = RouterError::ArithmeticOverflow.into();
+    assert_eq!(calculate_amount_out(u64::MAX, u64::MAX, u64::MAX, SWAP_FEE).unwrap_err(), overflow);
+
+    let mut harness = Harness::start().await;
+    let (pair, pool) = setup(&mut harness, SWAP_FEE).await;
+    let provider = harness.funded_keypair().await;
+    let holdings = fund(&mut harness, &pair, &pool, &provider.pubkey()).await;
+    harness.mint_to(&pair.token_a_mint, &pool.token_a_reserve, &pair.mint_authority, u64::MAX - FUNDING).await;
+    harness.mint_to(&pair.token_b_mint, &pool.token_b_reserve, &pair.mint_authority, u64::MAX - FUNDING).await;
+
+    // The first deposit's liquidity is the square root of a product past u128
+    let deposit = add_liquidity(&pool, &provider.pubkey(), &holdings, u64::MAX, u64::MAX);
+    assert_error(harness.process(&[deposit], &[&provider]).await, RouterError::ArithmeticOverflow);
+}
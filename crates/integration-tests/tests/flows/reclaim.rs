@@ -0,0 +1,571 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use ark_client::reclaim::{
+    accounts, find_epoch_address, find_epoch_config_address, find_group_address,
+    find_group_member_address, find_nullifier_address, instruction, ID,
+};
+use integration_tests::*;
+use reclaim::errors::ReclaimError;
+use reclaim::instructions::{AddEpochArgs, CreateGroupArgs, InitializeEpochConfigArgs};
+use reclaim::state::{ClaimNullifier, ClaimPredicate, Dapp, Epoch, EpochConfig, Group, GroupMember, PredicateOperator};
+use reclaim_sdk::{
+    build_claim_data, build_claim_info, find_dapp_address, sign_claim, ClaimInfo, DappAccounts, DappPolicy,
+    ProofAccounts, SignedClaim, WitnessKey,
+};
+use standard::contexts::reclaim::{self as standard_reclaim, VerificationPage, MAX_INDEXED_VERIFICATIONS, MAX_LISTED_VERIFICATIONS};
+use standard::errors::RouterError;
+use standard::{AccountConstraint, AllowedInstruction};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::router;
+
+pub(crate) const PROVIDER: &str = "http";
+const OWNER: &str = "0x00000000000000000000000000000000000000a1";
+const EPOCH_DURATION: u64 = 24 * 60 * 60;
+const MAX_CLAIM_AGE: u64 = 60 * 60;
+pub(crate) const MINIMUM_WITNESSES: u8 = 2;
+
+pub(crate) struct Reclaim {
+    deployer: Keypair,
+    pub(crate) epoch_config: Pubkey,
+    pub(crate) epoch: Pubkey,
+    pub(crate) group: Pubkey,
+    pub(crate) witnesses: Vec<WitnessKey>,
+}
+
+/// An epoch of three local witnesses, two of which sign each claim, and a group for
+/// `PROVIDER`
+pub(crate) async fn setup(harness: &mut Harness) -> Reclaim {
+    let witnesses = (1..=3u8).map(|seed| WitnessKey::from_bytes(&[seed; 32]).unwrap()).collect();
+    let reclaim = setup_epoch_config(harness, witnesses).await;
+
+    let create_group = build_instruction(
+        ID,
+        accounts::CreateGroup {
+            group: reclaim.group,
+            creator: reclaim.deployer.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CreateGroup {
+            args: CreateGroupArgs { provider: PROVIDER.to_string() },
+        },
+    );
+    harness.process(&[create_group], &[&reclaim.deployer]).await.unwrap();
+
+    reclaim
+}
+
+/// An epoch config of its own deployer whose first epoch has `witnesses`, two of which
+/// sign each claim. The group for `PROVIDER` is not created.
+async fn setup_epoch_config(harness: &mut Harness, witnesses: Vec<WitnessKey>) -> Reclaim {
+    let deployer = harness.funded_keypair().await;
+    let create_key = Keypair::new();
+    let (epoch_config, _) = find_epoch_config_address(&create_key.pubkey());
+    let (epoch, _) = find_epoch_address(&epoch_config, 1);
+    let (group, _) = find_group_address(PROVIDER);
+
+    let initialize = build_instruction(
+        ID,
+        accounts::InitializeEpochConfig {
+            epoch_config,
+            create_key: create_key.pubkey(),
+            deployer: deployer.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeEpochConfig {
+            args: InitializeEpochConfigArgs {
+                epoch_duration_seconds: EPOCH_DURATION,
+                grace_period_seconds: 0,
+                max_claim_age_seconds: MAX_CLAIM_AGE,
+            },
+        },
+    );
+    let add_epoch = build_instruction(
+        ID,
+        accounts::AddEpoch {
+            epoch,
+            epoch_config,
+            rent_payer: deployer.pubkey(),
+            deployer: deployer.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::AddEpoch {
+            args: AddEpochArgs {
+                witnesses: witnesses.iter().map(|key| key.witness("https://witness.local")).collect(),
+                minimum_witnesses_for_claim: MINIMUM_WITNESSES,
+            },
+        },
+    );
+    harness.process(&[initialize, add_epoch], &[&deployer, &create_key]).await.unwrap();
+
+    Reclaim { deployer, epoch_config, epoch, group, witnesses }
+}
+
+/// A claim about `user`, attested now and signed by the witnesses the epoch selects for it
+pub(crate) async fn attest_claim(harness: &mut Harness, reclaim: &Reclaim, user: &Pubkey) -> (ClaimInfo, SignedClaim) {
+    attest_provider_claim(harness, reclaim, user, PROVIDER).await
+}
+
+/// [`attest_claim`] for `provider`
+async fn attest_provider_claim(harness: &mut Harness, reclaim: &Reclaim, user: &Pubkey, provider: &str) -> (ClaimInfo, SignedClaim) {
+    let claim_info = build_claim_info(provider, r#"{"url":"https://example.com"}"#, *user, "");
+    let claim_data = build_claim_data(&claim_info, OWNER, harness.now().await as u32, 1);
+    let epoch = harness.account::<Epoch>(&reclaim.epoch).await;
+
+    let signed_claim = sign_claim(claim_data, &epoch, &reclaim.witnesses).unwrap();
+    (claim_info, signed_claim)
+}
+
+fn verify_proof(reclaim: &Reclaim, user: &Keypair, claim_info: &ClaimInfo, signed_claim: &SignedClaim) -> Instruction {
+    let accounts = ProofAccounts { epoch_config: reclaim.epoch_config, signer: user.pubkey() };
+    reclaim_sdk::verify_proof(&accounts, None, claim_info.clone(), signed_claim.clone())
+}
+
+/// Register a dapp for `PROVIDER` under `creator`, requiring `required_witnesses` signatures
+pub(crate) fn create_dapp(reclaim: &Reclaim, create_key: &Keypair, creator: &Keypair, required_witnesses: u8) -> (Instruction, Pubkey) {
+    let policy = DappPolicy {
+        epoch_config: reclaim.epoch_config,
+        allowed_providers: vec![],
+        max_claim_age_seconds: None,
+        required_witnesses,
+        fee: None,
+        predicates: vec![],
+    };
+    let create_dapp = reclaim_sdk::create_dapp(create_key.pubkey(), creator.pubkey(), PROVIDER, reclaim.epoch, policy);
+    (create_dapp, find_dapp_address(&create_key.pubkey(), &reclaim.group).0)
+}
+
+/// Standard's `reclaim_verify`, recording the claim for `user`, optionally under `dapp`'s policy
+pub(crate) fn reclaim_verify(
+    reclaim: &Reclaim,
+    user: &Keypair,
+    claim_info: &ClaimInfo,
+    signed_claim: &SignedClaim,
+    dapp: Option<Pubkey>,
+) -> Instruction {
+    let claim_data = &signed_claim.claim_data;
+    let (group, _) = find_group_address(&claim_info.provider);
+
+    build_instruction(
+        standard::ID,
+        standard::accounts::Verify {
+            signer: user.pubkey(),
+            epoch_config: reclaim.epoch_config,
+            epoch: reclaim.epoch,
+            reclaim_group: group,
+            claim_nullifier: find_nullifier_address(&group, &claim_data.claim_hash()).0,
+            reclaim_dapp: dapp,
+            dapp_fee_recipient: None,
+            reclaim_program: ID,
+            system_program: system_program::ID,
+            verification_result: standard_reclaim::find_verification_address(&user.pubkey(), &claim_info.provider).0,
+            verification_index: ark_client::standard::find_verification_index_address(&user.pubkey()).0,
+        },
+        standard::instruction::ReclaimVerify {
+            args: standard_reclaim::VerifyArgs {
+                claim_info: standard_reclaim::ClaimInfo {
+                    provider: claim_info.provider.clone(),
+                    parameters: claim_info.parameters.clone(),
+                    context_address: claim_info.context_address,
+                    context_message: claim_info.context_message.clone(),
+                },
+                signed_claim: standard_reclaim::SignedClaim {
+                    claim_data: standard_reclaim::ClaimData {
+                        identifier: claim_data.identifier,
+                        owner: claim_data.owner.clone(),
+                        timestamp: claim_data.timestamp,
+                        epoch_index: claim_data.epoch_index,
+                    },
+                    signatures: signed_claim.signatures.clone(),
+                },
+            },
+        },
+    )
+}
+
+fn list_verifications(user: &Pubkey, provider: Option<&str>, offset: u8) -> Instruction {
+    build_instruction(
+        standard::ID,
+        standard::accounts::ListVerifications {
+            user: *user,
+            verification_index: ark_client::standard::find_verification_index_address(user).0,
+        },
+        standard::instruction::ListReclaimVerifications {
+            provider: provider.map(str::to_string),
+            active_only: false,
+            offset,
+        },
+    )
+}
+
+fn add_member(reclaim: &Reclaim, user: &Keypair, claim_info: &ClaimInfo, signed_claim: &SignedClaim) -> Instruction {
+    let accounts = ProofAccounts { epoch_config: reclaim.epoch_config, signer: user.pubkey() };
+    reclaim_sdk::add_member(&accounts, user.pubkey(), claim_info.clone(), signed_claim.clone())
+}
+
+#[tokio::test]
+async fn signed_claim_verifies_once_and_refreshes_when_attested_again() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+
+    let (claim_info, signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    assert_eq!(signed_claim.signatures.len(), usize::from(MINIMUM_WITNESSES));
+    harness.process(&[verify_proof(&reclaim, &user, &claim_info, &signed_claim)], &[&user]).await.unwrap();
+
+    let claim_hash = signed_claim.claim_data.claim_hash();
+    let nullifier = find_nullifier_address(&reclaim.group, &claim_hash).0;
+    assert_eq!(harness.account::<ClaimNullifier>(&nullifier).await.claim_hash, claim_hash);
+
+    harness.warp_seconds(1).await;
+    let replay = verify_proof(&reclaim, &user, &claim_info, &signed_claim);
+    assert!(harness.process(&[replay], &[&user]).await.is_err());
+
+    // The same claim attested later is a new signed claim with its own nullifier
+    let (claim_info, refreshed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    assert_eq!(refreshed_claim.claim_data.identifier, signed_claim.claim_data.identifier);
+    harness.process(&[verify_proof(&reclaim, &user, &claim_info, &refreshed_claim)], &[&user]).await.unwrap();
+}
+
+#[tokio::test]
+async fn claim_without_signatures_is_rejected() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+
+    let (claim_info, mut signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    signed_claim.signatures.clear();
+
+    let verify = verify_proof(&reclaim, &user, &claim_info, &signed_claim);
+    assert_error(harness.process(&[verify], &[&user]).await, ReclaimError::InvalidWitnessClaimCount);
+}
+
+#[tokio::test]
+async fn epoch_needs_between_one_and_all_witnesses_per_claim() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+
+    let add_epoch = |minimum_witnesses_for_claim| build_instruction(
+        ID,
+        accounts::AddEpoch {
+            epoch: find_epoch_address(&reclaim.epoch_config, 2).0,
+            epoch_config: reclaim.epoch_config,
+            rent_payer: reclaim.deployer.pubkey(),
+            deployer: reclaim.deployer.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::AddEpoch {
+            args: AddEpochArgs {
+                witnesses: reclaim.witnesses.iter().map(|key| key.witness("https://witness.local")).collect(),
+                minimum_witnesses_for_claim,
+            },
+        },
+    );
+
+    // Without a minimum, a proof would verify with no signature at all
+    for minimum in [0, 4] {
+        let result = harness.process(&[add_epoch(minimum)], &[&reclaim.deployer]).await;
+        assert_error(result, ReclaimError::InvalidWitnessClaimCount);
+    }
+    harness.process(&[add_epoch(3)], &[&reclaim.deployer]).await.unwrap();
+}
+
+#[tokio::test]
+async fn repeated_signature_does_not_count_twice() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+
+    let (claim_info, mut signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    signed_claim.signatures[1] = signed_claim.signatures[0];
+
+    let verify = verify_proof(&reclaim, &user, &claim_info, &signed_claim);
+    assert_error(harness.process(&[verify], &[&user]).await, ReclaimError::DuplicateWitnessSignature);
+}
+
+#[tokio::test]
+async fn signature_from_an_unselected_witness_is_rejected() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+
+    let (claim_info, mut signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    let epoch = harness.account::<Epoch>(&reclaim.epoch).await;
+    let selected = reclaim_sdk::select_witnesses(&signed_claim.claim_data, MINIMUM_WITNESSES, &epoch.witnesses).unwrap();
+    let outsider = reclaim.witnesses.iter().find(|key| !selected.contains(&key.address())).unwrap();
+    signed_claim.signatures[1] = outsider.sign(&signed_claim.claim_data);
+
+    let verify = verify_proof(&reclaim, &user, &claim_info, &signed_claim);
+    assert_error(harness.process(&[verify], &[&user]).await, ReclaimError::InvalidWitnessSignature);
+}
+
+#[tokio::test]
+async fn membership_needs_a_proof_from_the_selected_witnesses() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+    let group_member = find_group_member_address(&reclaim.group, &user.pubkey()).0;
+
+    let (claim_info, signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    let unsigned_claim = SignedClaim { signatures: vec![], ..signed_claim.clone() };
+    let join = add_member(&reclaim, &user, &claim_info, &unsigned_claim);
+    assert_error(harness.process(&[join], &[&user]).await, ReclaimError::InvalidWitnessClaimCount);
+
+    let repeated_signature_claim = SignedClaim { signatures: vec![signed_claim.signatures[0]; 2], ..signed_claim.clone() };
+    let join = add_member(&reclaim, &user, &claim_info, &repeated_signature_claim);
+    assert_error(harness.process(&[join], &[&user]).await, ReclaimError::DuplicateWitnessSignature);
+    assert!(!harness.account_exists(&group_member).await);
+
+    harness.process(&[add_member(&reclaim, &user, &claim_info, &signed_claim)], &[&user]).await.unwrap();
+
+    assert_eq!(harness.account::<GroupMember>(&group_member).await.member, user.pubkey());
+    assert_eq!(harness.account::<Group>(&reclaim.group).await.member_count, 1);
+}
+
+#[tokio::test]
+async fn dapp_policy_cannot_require_more_witnesses_than_a_claim_carries() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let creator = harness.funded_keypair().await;
+    let create_key = Keypair::new();
+
+    let (create_dapp_instruction, _) = create_dapp(&reclaim, &create_key, &creator, MINIMUM_WITNESSES + 1);
+    assert_error(
+        harness.process(&[create_dapp_instruction], &[&creator, &create_key]).await,
+        ReclaimError::InvalidWitnessClaimCount,
+    );
+
+    let (create_dapp_instruction, dapp) = create_dapp(&reclaim, &create_key, &creator, MINIMUM_WITNESSES);
+    harness.process(&[create_dapp_instruction], &[&creator, &create_key]).await.unwrap();
+    assert_eq!(harness.account::<Dapp>(&dapp).await.group, reclaim.group);
+}
+
+#[tokio::test]
+async fn dapp_only_accepts_proofs_of_its_epoch_config() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+    let create_key = Keypair::new();
+
+    // Anyone can set up an epoch config with witnesses of their own
+    let rogue_witnesses = (7..=9u8).map(|seed| WitnessKey::from_bytes(&[seed; 32]).unwrap()).collect();
+    let rogue = setup_epoch_config(&mut harness, rogue_witnesses).await;
+
+    // The policy is checked against the current epoch of the config it pins
+    let (mut create_dapp_instruction, dapp) = create_dapp(&reclaim, &create_key, &user, MINIMUM_WITNESSES);
+    for meta in create_dapp_instruction.accounts.iter_mut().filter(|meta| meta.pubkey == reclaim.epoch) {
+        meta.pubkey = rogue.epoch;
+    }
+    assert_error(
+        harness.process(&[create_dapp_instruction], &[&user, &create_key]).await,
+        anchor_lang::error::ErrorCode::ConstraintSeeds,
+    );
+    let (create_dapp_instruction, _) = create_dapp(&reclaim, &create_key, &user, MINIMUM_WITNESSES);
+    harness.process(&[create_dapp_instruction], &[&user, &create_key]).await.unwrap();
+
+    let (claim_info, signed_claim) = attest_claim(&mut harness, &rogue, &user.pubkey()).await;
+    let accounts = ProofAccounts { epoch_config: rogue.epoch_config, signer: user.pubkey() };
+    let dapp_accounts = DappAccounts { dapp, fee_recipient: None };
+    let verify = reclaim_sdk::verify_proof(&accounts, Some(&dapp_accounts), claim_info, signed_claim);
+    assert_error(harness.process(&[verify], &[&user]).await, ReclaimError::EpochConfigMismatch);
+    assert_eq!(harness.account::<Dapp>(&dapp).await.stats.verification_count, 0);
+}
+
+#[tokio::test]
+async fn verification_records_the_dapp_of_its_group() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+    let create_key = Keypair::new();
+
+    let other_group = find_group_address("other").0;
+    let create_other_group = build_instruction(
+        ID,
+        accounts::CreateGroup { group: other_group, creator: user.pubkey(), system_program: system_program::ID },
+        instruction::CreateGroup { args: CreateGroupArgs { provider: "other".to_string() } },
+    );
+    let other_dapp = find_dapp_address(&create_key.pubkey(), &other_group).0;
+    let create_other_dapp = reclaim_sdk::create_dapp(
+        create_key.pubkey(),
+        user.pubkey(),
+        "other",
+        reclaim.epoch,
+        DappPolicy {
+            epoch_config: reclaim.epoch_config,
+            allowed_providers: vec![],
+            max_claim_age_seconds: None,
+            required_witnesses: 0,
+            fee: None,
+            predicates: vec![],
+        },
+    );
+    let (create_dapp_instruction, dapp) = create_dapp(&reclaim, &create_key, &user, MINIMUM_WITNESSES);
+    harness
+        .process(&[create_other_group, create_other_dapp, create_dapp_instruction], &[&user, &create_key])
+        .await
+        .unwrap();
+
+    // A dapp only scopes proofs of its own group
+    let (claim_info, signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    let verify = reclaim_verify(&reclaim, &user, &claim_info, &signed_claim, Some(other_dapp));
+    assert_error(harness.process(&[verify], &[&user]).await, ReclaimError::Unauthorized);
+
+    harness.process(&[reclaim_verify(&reclaim, &user, &claim_info, &signed_claim, Some(dapp))], &[&user]).await.unwrap();
+
+    let verification = standard_reclaim::find_verification_address(&user.pubkey(), PROVIDER).0;
+    assert_eq!(harness.account::<standard_reclaim::VerificationResult>(&verification).await.dapp, Some(dapp));
+    assert_eq!(harness.account::<Dapp>(&dapp).await.stats.verification_count, 1);
+}
+
+#[tokio::test]
+async fn witness_set_is_handed_to_a_government_executor_only() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let router = router::setup(&mut harness).await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    assert_eq!(reclaim::constants::ROUTER_PROGRAM_ID, standard::ID);
+
+    let handover = reclaim_sdk::initialize_witness_governance(
+        reclaim.epoch_config,
+        1,
+        reclaim.deployer.pubkey(),
+        router.state,
+        realm.monarch,
+    );
+    let executor = reclaim_sdk::find_router_executor_address(&router.state, &realm.monarch).0;
+
+    // Any other signer is refused as the authority
+    let impostor = harness.funded_keypair().await;
+    let mut impostor_handover = handover.clone();
+    for meta in impostor_handover.accounts.iter_mut().filter(|meta| meta.pubkey == executor) {
+        meta.pubkey = impostor.pubkey();
+    }
+    assert_error(
+        harness.process(&[impostor_handover], &[&reclaim.deployer, &impostor]).await,
+        ReclaimError::InvalidWitnessAuthority,
+    );
+
+    // The kingdom's executor signs once the monarch approves the handover
+    router::register_with_allowlist(
+        &mut harness,
+        &router,
+        absolute_monarchy::ID,
+        realm.monarch,
+        vec![AllowedInstruction {
+            program_id: ID,
+            discriminator: instruction::InitializeWitnessGovernance::DISCRIMINATOR,
+            account_constraints: vec![AccountConstraint { index: 2, key: reclaim.epoch_config, is_writable: true }],
+        }],
+    )
+    .await;
+    let route = router::royal_route(&router, &realm, &realm.king.pubkey(), vec![handover]);
+    harness.process(&[route], &[&realm.king, &reclaim.deployer]).await.unwrap();
+
+    let epoch_config = harness.account::<EpochConfig>(&reclaim.epoch_config).await;
+    assert_eq!(epoch_config.witness_authority, Some(executor));
+}
+
+#[tokio::test]
+async fn dapp_predicates_apply_to_its_verifications_only() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+    let create_key = Keypair::new();
+    let policy = |operator, value: &str| DappPolicy {
+        epoch_config: reclaim.epoch_config,
+        allowed_providers: vec![],
+        max_claim_age_seconds: None,
+        required_witnesses: MINIMUM_WITNESSES,
+        fee: None,
+        predicates: vec![ClaimPredicate { path: "url".to_string(), operator, value: value.to_string() }],
+    };
+
+    let create_dapp = reclaim_sdk::create_dapp(
+        create_key.pubkey(),
+        user.pubkey(),
+        PROVIDER,
+        reclaim.epoch,
+        policy(PredicateOperator::Equal, "https://other.com"),
+    );
+    harness.process(&[create_dapp], &[&user, &create_key]).await.unwrap();
+    let dapp = find_dapp_address(&create_key.pubkey(), &reclaim.group).0;
+    let accounts = ProofAccounts { epoch_config: reclaim.epoch_config, signer: user.pubkey() };
+    let dapp_accounts = DappAccounts { dapp, fee_recipient: None };
+
+    // The dapp's rule is not the group's: the claim still verifies without the dapp
+    let (claim_info, signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    let verify = reclaim_sdk::verify_proof(&accounts, Some(&dapp_accounts), claim_info.clone(), signed_claim.clone());
+    assert_error(harness.process(&[verify], &[&user]).await, ReclaimError::PredicateNotSatisfied);
+    harness.process(&[verify_proof(&reclaim, &user, &claim_info, &signed_claim)], &[&user]).await.unwrap();
+
+    let update = reclaim_sdk::update_dapp_policy(
+        dapp,
+        user.pubkey(),
+        reclaim.epoch,
+        policy(PredicateOperator::Contains, "example.com"),
+    );
+    harness.process(&[update], &[&user]).await.unwrap();
+
+    harness.warp_seconds(1).await;
+    let (claim_info, signed_claim) = attest_claim(&mut harness, &reclaim, &user.pubkey()).await;
+    let verify = reclaim_sdk::verify_proof(&accounts, Some(&dapp_accounts), claim_info, signed_claim);
+    harness.process(&[verify], &[&user]).await.unwrap();
+    assert_eq!(harness.account::<Dapp>(&dapp).await.stats.verification_count, 1);
+}
+
+#[tokio::test]
+async fn verifications_are_listed_in_pages_and_revoking_frees_a_slot() {
+    let mut harness = Harness::start().await;
+    let reclaim = setup(&mut harness).await;
+    let user = harness.funded_keypair().await;
+    let providers: Vec<_> = (0..=MAX_INDEXED_VERIFICATIONS).map(|index| format!("provider-{index}")).collect();
+
+    let mut verify_provider = Vec::new();
+    for provider in &providers {
+        let create_group = build_instruction(
+            ID,
+            accounts::CreateGroup {
+                group: find_group_address(provider).0,
+                creator: user.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::CreateGroup { args: CreateGroupArgs { provider: provider.clone() } },
+        );
+        let (claim_info, signed_claim) = attest_provider_claim(&mut harness, &reclaim, &user.pubkey(), provider).await;
+        verify_provider.push([create_group, reclaim_verify(&reclaim, &user, &claim_info, &signed_claim, None)]);
+    }
+    let last = verify_provider.pop().unwrap();
+    for instructions in &verify_provider {
+        harness.process(instructions, &[&user]).await.unwrap();
+    }
+
+    // A full index is returned over pages that each fit in the return data
+    let data = harness.simulate_return_data(&[list_verifications(&user.pubkey(), None, 0)], &[]).await;
+    let page = VerificationPage::try_from_slice(&data).unwrap();
+    assert_eq!(page.entries.len(), MAX_LISTED_VERIFICATIONS);
+    assert_eq!(page.next_offset, Some(MAX_LISTED_VERIFICATIONS as u8));
+    let data = harness.simulate_return_data(&[list_verifications(&user.pubkey(), None, page.next_offset.unwrap())], &[]).await;
+    let page = VerificationPage::try_from_slice(&data).unwrap();
+    assert_eq!(page.entries.len(), MAX_INDEXED_VERIFICATIONS - MAX_LISTED_VERIFICATIONS);
+    assert_eq!(page.next_offset, None);
+
+    assert_error(harness.process(&last, &[&user]).await, RouterError::VerificationIndexFull);
+
+    // Revoking drops the entry, making room for another provider
+    let revoke = build_instruction(
+        standard::ID,
+        standard::accounts::RevokeVerification {
+            signer: user.pubkey(),
+            verification_result: standard_reclaim::find_verification_address(&user.pubkey(), &providers[0]).0,
+            verification_index: ark_client::standard::find_verification_index_address(&user.pubkey()).0,
+        },
+        standard::instruction::RevokeReclaimVerification {},
+    );
+    harness.process(&[revoke], &[&user]).await.unwrap();
+    let data = harness.simulate_return_data(&[list_verifications(&user.pubkey(), Some(&providers[0]), 0)], &[]).await;
+    assert!(VerificationPage::try_from_slice(&data).unwrap().entries.is_empty());
+
+    harness.process(&last, &[&user]).await.unwrap();
+}
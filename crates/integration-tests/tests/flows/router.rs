@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_spl::metadata::mpl_token_metadata;
+use ark_client::absolute_monarchy::{find_decision_address, ID as MONARCHY_ID};
+use ark_client::conviction::{self, ID as CONVICTION_ID};
+use ark_client::standard::{accounts, find_executor_address, instruction, ID};
+use conviction::states::{Proposal, ProposalStatus};
+use ::conviction::errors::ErrorCode as ConvictionError;
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::errors::RouterError;
+use standard::{AccountConstraint, AllowedInstruction, RoutedInstruction};
+use the_ark_program::{instructions_hash, Decision, DecisionRecord, GovernmentTypes, InstructionContext};
+
+use crate::absolute_monarchy::Realm;
+
+const AMOUNT: u64 = 1_000_000_000;
+
+pub(crate) struct Router {
+    pub(crate) state: Pubkey,
+    authority: Keypair,
+}
+
+/// A router with no government registered yet
+pub(crate) async fn setup(harness: &mut Harness) -> Router {
+    let authority = harness.funded_keypair().await;
+    let state = Keypair::new();
+
+    let initialize = build_instruction(
+        ID,
+        accounts::Initialize {
+            router_state: state.pubkey(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::Initialize {},
+    );
+    harness.process(&[initialize], &[&authority, &state]).await.unwrap();
+
+    Router { state: state.pubkey(), authority }
+}
+
+/// Register the instance `government_state` of `government`, allowed to move [`AMOUNT`]
+/// out of its funded executor
+async fn register(harness: &mut Harness, router: &Router, government: Pubkey, government_state: Pubkey) -> Pubkey {
+    let (executor, _) = find_executor_address(&router.state, &government_state);
+    let allowlist = vec![AllowedInstruction {
+        program_id: system_program::ID,
+        discriminator: transfer_data(&executor, AMOUNT)[..8].try_into().unwrap(),
+        account_constraints: vec![AccountConstraint { index: 0, key: executor, is_writable: true }],
+    }];
+
+    register_with_allowlist(harness, router, government, government_state, allowlist).await
+}
+
+/// Register the instance `government_state` of `government` with `allowlist` and fund its executor
+pub(crate) async fn register_with_allowlist(
+    harness: &mut Harness,
+    router: &Router,
+    government: Pubkey,
+    government_state: Pubkey,
+    allowlist: Vec<AllowedInstruction>,
+) -> Pubkey {
+    let (executor, _) = find_executor_address(&router.state, &government_state);
+
+    let register = build_instruction(
+        ID,
+        accounts::RegisterGovernment {
+            router_state: router.state,
+            authority: router.authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::RegisterGovernment {
+            government_type: GovernmentTypes::AbsoluteMonarchy,
+            government_program_id: government,
+            government_state,
+            allowlist,
+        },
+    );
+    harness.process(&[register], &[&router.authority]).await.unwrap();
+
+    harness.transfer_lamports(&executor, DEFAULT_FUNDING).await;
+
+    executor
+}
+
+fn transfer_data(from: &Pubkey, lamports: u64) -> Vec<u8> {
+    system_instruction::transfer(from, &Pubkey::default(), lamports).data
+}
+
+/// The hash a government records when it decides on `instructions`
+fn batch_hash(instructions: &[Instruction]) -> [u8; 32] {
+    let contexts = instructions.iter()
+        .enumerate()
+        .map(|(index, ix)| InstructionContext {
+            program_id: ix.program_id,
+            instruction_data: ix.data.clone(),
+            signer: Pubkey::default(),
+            accounts: ix.accounts.iter().map(|meta| meta.pubkey).collect(),
+            block_time: 0,
+            instruction_index: index as u8,
+        })
+        .collect::<Vec<_>>();
+    instructions_hash(&contexts).unwrap()
+}
+
+/// `route_instruction` for `instructions`, decided by `government` on `government_state`
+/// (and `proposal`, if it votes on proposals) and recorded in `decision`. Signers of the
+/// outer transaction keep signing; the executor is signed for by the router.
+pub(crate) fn route(
+    router: &Router,
+    government: Pubkey,
+    government_state: Pubkey,
+    proposal: Option<Pubkey>,
+    decision: Pubkey,
+    authority: &Pubkey,
+    instructions: Vec<Instruction>,
+) -> Instruction {
+    let (executor, _) = find_executor_address(&router.state, &government_state);
+    let routed = instructions.iter()
+        .map(|ix| RoutedInstruction {
+            program_id: ix.program_id,
+            accounts_len: ix.accounts.len() as u8,
+            data: ix.data.clone(),
+        })
+        .collect();
+    let programs = instructions.iter()
+        .map(|ix| AccountMeta::new_readonly(ix.program_id, false))
+        .collect::<Vec<_>>();
+
+    build_instruction_with_remaining_accounts(
+        ID,
+        accounts::RouteInstruction {
+            router_state: router.state,
+            government_account: government,
+            government_state,
+            decision_account: decision,
+            authority: *authority,
+            system_program: system_program::ID,
+            proposal,
+        },
+        instruction::RouteInstruction { instructions: routed },
+        instructions.into_iter()
+            .flat_map(|ix| ix.accounts)
+            .map(|meta| AccountMeta { is_signer: meta.is_signer && meta.pubkey != executor, ..meta })
+            .chain(programs)
+            .collect(),
+    )
+}
+
+/// A system transfer of `lamports` from `government`'s executor to `recipient`, routed by
+/// `authority`
+fn route_transfer(
+    router: &Router,
+    government: Pubkey,
+    government_state: Pubkey,
+    decision: Pubkey,
+    authority: &Keypair,
+    recipient: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let (executor, _) = find_executor_address(&router.state, &government_state);
+    let transfer = system_instruction::transfer(&executor, recipient, lamports);
+    route(router, government, government_state, None, decision, &authority.pubkey(), vec![transfer])
+}
+
+/// `route_instruction` of `instructions` out of the kingdom's executor, decided by `authority`
+pub(crate) fn royal_route(router: &Router, realm: &Realm, authority: &Pubkey, instructions: Vec<Instruction>) -> Instruction {
+    let (decision, _) = find_decision_address(&realm.monarch);
+    route(router, MONARCHY_ID, realm.monarch, None, decision, authority, instructions)
+}
+
+/// A transfer out of the kingdom's executor, decided by `authority`
+fn royal_transfer(router: &Router, realm: &Realm, authority: &Keypair, recipient: &Pubkey, lamports: u64) -> Instruction {
+    let (executor, _) = find_executor_address(&router.state, &realm.monarch);
+    let transfer = system_instruction::transfer(&executor, recipient, lamports);
+    royal_route(router, realm, &authority.pubkey(), vec![transfer])
+}
+
+#[tokio::test]
+async fn monarch_decision_executes_through_the_executor() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    let executor = register(&mut harness, &router, MONARCHY_ID, realm.monarch).await;
+    let recipient = Pubkey::new_unique();
+
+    let route = royal_transfer(&router, &realm, &realm.king, &recipient, AMOUNT);
+    harness.process(&[route], &[&realm.king]).await.unwrap();
+
+    assert_eq!(harness.lamports(&recipient).await, AMOUNT);
+    assert_eq!(harness.lamports(&executor).await, DEFAULT_FUNDING - AMOUNT);
+    let (decision, _) = find_decision_address(&realm.monarch);
+    assert!(harness.account::<DecisionRecord>(&decision).await.decision == Decision::Approve);
+}
+
+#[tokio::test]
+async fn decision_of_anyone_but_the_monarch_is_rejected() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    let executor = register(&mut harness, &router, MONARCHY_ID, realm.monarch).await;
+    let recipient = Pubkey::new_unique();
+
+    let route = royal_transfer(&router, &realm, &realm.noble, &recipient, AMOUNT);
+    assert_error(harness.process(&[route], &[&realm.noble]).await, RouterError::DecisionRejected);
+    assert_eq!(harness.lamports(&recipient).await, 0);
+    assert_eq!(harness.lamports(&executor).await, DEFAULT_FUNDING);
+
+    // The record is reused for the monarch's next decision
+    let route = royal_transfer(&router, &realm, &realm.king, &recipient, AMOUNT);
+    harness.process(&[route], &[&realm.king]).await.unwrap();
+    assert_eq!(harness.lamports(&recipient).await, AMOUNT);
+}
+
+#[tokio::test]
+async fn instruction_outside_the_allowlist_is_refused() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    register(&mut harness, &router, MONARCHY_ID, realm.monarch).await;
+    let recipient = Pubkey::new_unique();
+
+    let route = royal_transfer(&router, &realm, &realm.king, &recipient, AMOUNT + 1);
+    assert_error(harness.process(&[route], &[&realm.king]).await, RouterError::InstructionNotAllowed);
+}
+
+#[tokio::test]
+async fn unregistered_government_is_refused() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    let recipient = Pubkey::new_unique();
+
+    let route = royal_transfer(&router, &realm, &realm.king, &recipient, AMOUNT);
+    assert_error(harness.process(&[route], &[&realm.king]).await, RouterError::GovernmentNotFound);
+}
+
+#[tokio::test]
+async fn decision_not_recorded_by_the_government_is_refused() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    // The token metadata stand-in accepts any instruction without writing anything
+    let government = mpl_token_metadata::ID;
+    let government_state = Pubkey::new_unique();
+    let executor = register(&mut harness, &router, government, government_state).await;
+    let caller = harness.funded_keypair().await;
+    let recipient = Pubkey::new_unique();
+    let transfer = system_instruction::transfer(&executor, &recipient, AMOUNT);
+
+    let approval = |instructions_hash| {
+        let mut approval = Vec::new();
+        DecisionRecord { decision: Decision::Approve, instructions_hash }.try_serialize(&mut approval).unwrap();
+        approval
+    };
+
+    // An approval owned by someone other than the government
+    let forged = Pubkey::new_unique();
+    harness.set_raw_account(&forged, system_program::ID, approval(batch_hash(&[transfer.clone()])));
+    let route = route_transfer(&router, government, government_state, forged, &caller, &recipient, AMOUNT);
+    assert_error(harness.process(&[route], &[&caller]).await, RouterError::InvalidDecisionAccount);
+
+    // A government account that is not a decision record
+    let foreign = Pubkey::new_unique();
+    harness.set_raw_account(&foreign, government, vec![0; DecisionRecord::SPACE]);
+    let route = route_transfer(&router, government, government_state, foreign, &caller, &recipient, AMOUNT);
+    assert_error(harness.process(&[route], &[&caller]).await, RouterError::InvalidDecisionAccount);
+
+    // An approval the government recorded for another request
+    let stale = Pubkey::new_unique();
+    harness.set_raw_account(&stale, government, approval([7; 32]));
+    let route = route_transfer(&router, government, government_state, stale, &caller, &recipient, AMOUNT);
+    assert_error(harness.process(&[route], &[&caller]).await, RouterError::DecisionMismatch);
+
+    assert_eq!(harness.lamports(&recipient).await, 0);
+}
+
+#[tokio::test]
+async fn each_kingdom_has_its_own_executor() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    let realm = crate::absolute_monarchy::setup(&mut harness).await;
+    let executor = register(&mut harness, &router, MONARCHY_ID, realm.monarch).await;
+    let recipient = Pubkey::new_unique();
+
+    // Anyone can found a kingdom of the same program and rule it
+    let usurper = crate::absolute_monarchy::setup(&mut harness).await;
+    let raid = |router: &Router| {
+        let mut transfer = system_instruction::transfer(&executor, &recipient, AMOUNT);
+        transfer.accounts[0].is_signer = false;
+        royal_route(router, &usurper, &usurper.king.pubkey(), vec![transfer])
+    };
+    assert_error(harness.process(&[raid(&router)], &[&usurper.king]).await, RouterError::GovernmentNotFound);
+
+    // Registered too, its decisions are signed for by its own executor only
+    let allowlist = vec![AllowedInstruction {
+        program_id: system_program::ID,
+        discriminator: transfer_data(&executor, AMOUNT)[..8].try_into().unwrap(),
+        account_constraints: vec![],
+    }];
+    let usurper_executor = register_with_allowlist(&mut harness, &router, MONARCHY_ID, usurper.monarch, allowlist).await;
+    assert_ne!(usurper_executor, executor);
+    assert!(harness.process(&[raid(&router)], &[&usurper.king]).await.is_err());
+
+    assert_eq!(harness.lamports(&recipient).await, 0);
+    assert_eq!(harness.lamports(&executor).await, DEFAULT_FUNDING);
+}
+
+#[tokio::test]
+async fn passed_proposal_routes_its_own_batch_once() {
+    let mut harness = Harness::start().await;
+    let router = setup(&mut harness).await;
+    let governance = Pubkey::new_unique();
+    let (executor, _) = find_executor_address(&router.state, &governance);
+    let recipient = Pubkey::new_unique();
+    let transfer = system_instruction::transfer(&executor, &recipient, AMOUNT);
+
+    let conviction = crate::conviction::setup_for(&mut harness, governance, batch_hash(&[transfer.clone()])).await;
+    register(&mut harness, &router, CONVICTION_ID, governance).await;
+    let voter = crate::conviction::staked_voter(&mut harness, &conviction, 100, 0).await;
+    harness.process(&[crate::conviction::cast_vote(&conviction, &voter, true, 100)], &[&voter]).await.unwrap();
+    harness.warp_seconds(crate::conviction::VOTING_PERIOD).await;
+
+    let (decision, _) = conviction::find_decision_address(&governance);
+    let vote_route = |instructions| {
+        route(&router, CONVICTION_ID, governance, Some(conviction.proposal), decision, &voter.pubkey(), instructions)
+    };
+
+    // The vote did not approve sending the funds elsewhere
+    let diverted = system_instruction::transfer(&executor, &Pubkey::new_unique(), AMOUNT);
+    assert_error(
+        harness.process(&[vote_route(vec![diverted])], &[&voter]).await,
+        ConvictionError::ProposalInstructionsMismatch,
+    );
+
+    harness.process(&[vote_route(vec![transfer.clone()])], &[&voter]).await.unwrap();
+    assert_eq!(harness.lamports(&recipient).await, AMOUNT);
+    assert!(harness.account::<Proposal>(&conviction.proposal).await.status == ProposalStatus::Executed);
+
+    // Nor does it approve the same batch twice
+    harness.warp_seconds(1).await;
+    assert_error(
+        harness.process(&[vote_route(vec![transfer])], &[&voter]).await,
+        ConvictionError::ProposalNotActive,
+    );
+    assert_eq!(harness.lamports(&recipient).await, AMOUNT);
+}
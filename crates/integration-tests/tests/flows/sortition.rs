@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use ark_client::sortition::{
+    accounts, find_assembly_address, find_citizen_address, find_citizen_index_address,
+    find_current_citizen_index_address, find_governance_pool_address, find_pagination_address,
+    instruction, states, ID,
+};
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use sortition::error::GovernanceError;
+use states::{Assembly, GovernancePool, GovernanceTokenConfig, GovernanceTokenType, InitializeGovernmentArgs, PrimaryGovernanceToken};
+
+const TERM_LENGTH: i64 = 30 * 24 * 60 * 60;
+
+/// A pool selecting two members, one from each of two demographic groups
+async fn setup(harness: &mut Harness) -> (Pubkey, Keypair) {
+    let admin = harness.funded_keypair().await;
+    let mint = harness.create_mint(&admin.pubkey(), 6).await;
+    let (governance_pool, _) = find_governance_pool_address(&admin.pubkey());
+
+    let mut quotas = InitializeGovernmentArgs::default();
+    quotas.regions[..2].fill(1);
+    quotas.age_groups[..2].fill(1);
+    quotas.other_demographic[..2].fill(1);
+
+    let initialize = build_instruction(
+        ID,
+        accounts::InitializeGovernance {
+            governance_pool,
+            admin: admin.pubkey(),
+            nft_mint: None,
+            spl_mint: Some(mint),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeSortitionGovernance {
+            args: InitializeGovernmentArgs {
+                name: "Citizens' Assembly".to_string(),
+                assembly_size: 2,
+                spl_config: Some(GovernanceTokenConfig { token_type: GovernanceTokenType::Existing, token_mint: mint }),
+                spl_symbol: "CIV".to_string(),
+                primary_governance_token: PrimaryGovernanceToken::SPL,
+                ..quotas
+            },
+        },
+    );
+    harness.process(&[initialize], &[&admin]).await.unwrap();
+
+    (governance_pool, admin)
+}
+
+/// Register a citizen whose region, age group and other demographic are all `group`
+async fn register_citizen(harness: &mut Harness, governance_pool: &Pubkey, group: u8) -> Pubkey {
+    let citizen = harness.funded_keypair().await;
+    let total_citizens = harness.account::<GovernancePool>(governance_pool).await.total_citizens;
+
+    let register = build_instruction(
+        ID,
+        accounts::RegisterCitizen {
+            governance_pool: *governance_pool,
+            citizen_account: find_citizen_address(governance_pool, &citizen.pubkey()).0,
+            citizen: citizen.pubkey(),
+            citizen_index: find_current_citizen_index_address(governance_pool, total_citizens).0,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterSortitionCitizen {
+            name: format!("Citizen {total_citizens}"),
+            region: group,
+            age_group: group,
+            other_demographic: group,
+        },
+    );
+    harness.process(&[register], &[&citizen]).await.unwrap();
+
+    citizen.pubkey()
+}
+
+/// `select_sortition_assembly` for the assembly at the pool's current citizen count
+async fn select_assembly(harness: &mut Harness, governance_pool: &Pubkey, admin: &Keypair) -> (Instruction, Pubkey) {
+    let total_citizens = harness.account::<GovernancePool>(governance_pool).await.total_citizens;
+    let assembly = find_assembly_address(governance_pool, total_citizens).0;
+
+    let select = build_instruction(
+        ID,
+        accounts::SelectAssembly {
+            governance_pool: *governance_pool,
+            assembly,
+            pagination_state: find_pagination_address(governance_pool, total_citizens).0,
+            admin: admin.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SelectSortitionAssembly { term_length: TERM_LENGTH },
+    );
+    (select, assembly)
+}
+
+#[tokio::test]
+async fn selection_fills_the_assembly_within_demographic_quotas() {
+    let mut harness = Harness::start().await;
+    let (governance_pool, admin) = setup(&mut harness).await;
+
+    let mut citizens = Vec::new();
+    for group in [0, 0, 1, 1] {
+        citizens.push(register_citizen(&mut harness, &governance_pool, group).await);
+    }
+
+    let (select, assembly) = select_assembly(&mut harness, &governance_pool, &admin).await;
+    harness.process(&[select], &[&admin]).await.unwrap();
+    let total_citizens = harness.account::<GovernancePool>(&governance_pool).await.total_citizens;
+    assert!(harness.account::<GovernancePool>(&governance_pool).await.selection_in_progress);
+
+    let finalize = build_instruction_with_remaining_accounts(
+        ID,
+        accounts::FinalizeAssemblySelection {
+            governance_pool,
+            assembly,
+            pagination_state: find_pagination_address(&governance_pool, total_citizens).0,
+            citizen_index: find_citizen_index_address(&governance_pool, 0).0,
+            admin: admin.pubkey(),
+            recent_blockhashes: sysvar::recent_blockhashes::ID,
+            system_program: system_program::ID,
+        },
+        instruction::FinalizeSortitionAssemblySelection {},
+        citizens.iter()
+            .map(|citizen| AccountMeta::new_readonly(find_citizen_address(&governance_pool, citizen).0, false))
+            .collect(),
+    );
+    harness.process(&[finalize], &[&admin]).await.unwrap();
+
+    // The first citizen of each group fills its group's only seat
+    let state = harness.account::<GovernancePool>(&governance_pool).await;
+    assert!(!state.selection_in_progress);
+    assert_eq!(state.current_assembly, assembly);
+    let assembly = harness.account::<Assembly>(&assembly).await;
+    assert_eq!(assembly.members, vec![citizens[0], citizens[2]]);
+    assert_eq!(assembly.term_end - assembly.term_start, TERM_LENGTH);
+}
+
+#[tokio::test]
+async fn selection_needs_enough_citizens_for_the_assembly() {
+    let mut harness = Harness::start().await;
+    let (governance_pool, admin) = setup(&mut harness).await;
+    register_citizen(&mut harness, &governance_pool, 0).await;
+
+    let (select, _) = select_assembly(&mut harness, &governance_pool, &admin).await;
+    assert_error(harness.process(&[select], &[&admin]).await, GovernanceError::NotEnoughCitizens);
+}
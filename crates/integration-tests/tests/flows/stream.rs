@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use ark_client::standard::{accounts, find_stream_address, find_stream_vault_address, instruction, ID};
+use integration_tests::*;
+use solana_sdk::signature::{Keypair, Signer};
+use standard::errors::RouterError;
+use standard::states::{PaymentStream, StreamRate};
+
+const RATE: u64 = 10;
+const DEPOSIT: u64 = 1_000;
+const FUNDING: u64 = 10 * DEPOSIT;
+// Seconds until the deposit has fully streamed
+const DURATION: i64 = (DEPOSIT / RATE) as i64;
+
+struct Stream {
+    authority: Keypair,
+    recipient: Keypair,
+    mint: Pubkey,
+    address: Pubkey,
+    vault: Pubkey,
+    treasury: Pubkey,
+}
+
+/// A stream of [`DEPOSIT`] at [`RATE`] a second from a treasury holding [`FUNDING`]
+async fn setup(harness: &mut Harness, stream_id: u64) -> Stream {
+    let authority = harness.funded_keypair().await;
+    let recipient = harness.funded_keypair().await;
+    let mint_authority = harness.funded_keypair().await;
+    let mint = harness.create_mint(&mint_authority.pubkey(), 0).await;
+    let treasury = harness.create_token_account(&mint, &authority.pubkey()).await;
+    harness.mint_to(&mint, &treasury, &mint_authority, FUNDING).await;
+
+    let (address, _) = find_stream_address(&authority.pubkey(), stream_id);
+    let (vault, _) = find_stream_vault_address(&address);
+    let create = build_instruction(
+        ID,
+        accounts::CreatePaymentStream {
+            stream: address,
+            authority: authority.pubkey(),
+            funder: authority.pubkey(),
+            funding_token_account: treasury,
+            recipient: recipient.pubkey(),
+            mint,
+            vault,
+            payer: authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreatePaymentStream { stream_id, rate: StreamRate::PerSecond(RATE), deposit: DEPOSIT },
+    );
+    harness.process(&[create], &[&authority]).await.unwrap();
+
+    Stream { authority, recipient, mint, address, vault, treasury }
+}
+
+fn claim(stream: &Stream, recipient_token_account: &Pubkey) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::ClaimStream {
+            stream: stream.address,
+            recipient: stream.recipient.pubkey(),
+            recipient_token_account: *recipient_token_account,
+            vault: stream.vault,
+            mint: stream.mint,
+            rent_payer: stream.authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ClaimPaymentStream {},
+    )
+}
+
+fn cancel(stream: &Stream) -> Instruction {
+    build_instruction(
+        ID,
+        accounts::CancelStream {
+            stream: stream.address,
+            authority: stream.authority.pubkey(),
+            refund_token_account: stream.treasury,
+            vault: stream.vault,
+            mint: stream.mint,
+            rent_payer: stream.authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CancelPaymentStream {},
+    )
+}
+
+#[tokio::test]
+async fn claims_follow_the_rate_until_the_deposit_runs_out() {
+    let mut harness = Harness::start().await;
+    let stream = setup(&mut harness, 1).await;
+    let wallet = harness.create_token_account(&stream.mint, &stream.recipient.pubkey()).await;
+
+    let early = claim(&stream, &wallet);
+    assert_error(harness.process(&[early], &[&stream.recipient]).await, RouterError::NothingToClaim);
+
+    harness.warp_seconds(30).await;
+    harness.process(&[claim(&stream, &wallet)], &[&stream.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&wallet).await, 30 * RATE);
+
+    harness.warp_seconds(50).await;
+    harness.process(&[claim(&stream, &wallet)], &[&stream.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&wallet).await, 80 * RATE);
+    assert_eq!(harness.account::<PaymentStream>(&stream.address).await.withdrawn, 80 * RATE);
+
+    // Long after depletion, only the deposit is paid and the stream closes
+    harness.warp_seconds(DURATION).await;
+    harness.process(&[claim(&stream, &wallet)], &[&stream.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&wallet).await, DEPOSIT);
+    assert!(!harness.account_exists(&stream.address).await);
+    assert!(!harness.account_exists(&stream.vault).await);
+}
+
+#[tokio::test]
+async fn cancel_refunds_the_unstreamed_rest_without_the_recipient_account() {
+    let mut harness = Harness::start().await;
+    let stream = setup(&mut harness, 1).await;
+
+    // The recipient has no token account yet
+    harness.warp_seconds(25).await;
+    harness.process(&[cancel(&stream)], &[&stream.authority]).await.unwrap();
+    assert_eq!(harness.token_balance(&stream.treasury).await, FUNDING - 25 * RATE);
+    assert_eq!(harness.token_balance(&stream.vault).await, 25 * RATE);
+
+    // What had streamed stays claimable, and nothing more accrues
+    harness.warp_seconds(DURATION).await;
+    let wallet = harness.create_token_account(&stream.mint, &stream.recipient.pubkey()).await;
+    harness.process(&[claim(&stream, &wallet)], &[&stream.recipient]).await.unwrap();
+    assert_eq!(harness.token_balance(&wallet).await, 25 * RATE);
+    assert_eq!(harness.token_balance(&stream.treasury).await, FUNDING - 25 * RATE);
+    assert!(!harness.account_exists(&stream.address).await);
+}
+
+#[tokio::test]
+async fn cancel_before_anything_streamed_closes_the_stream() {
+    let mut harness = Harness::start().await;
+    let stream = setup(&mut harness, 7).await;
+
+    let forged = build_instruction(
+        ID,
+        accounts::CancelStream {
+            stream: stream.address,
+            authority: stream.recipient.pubkey(),
+            refund_token_account: stream.treasury,
+            vault: stream.vault,
+            mint: stream.mint,
+            rent_payer: stream.authority.pubkey(),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CancelPaymentStream {},
+    );
+    assert_error(
+        harness.process(&[forged], &[&stream.recipient]).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+
+    let rent = harness.lamports(&stream.address).await + harness.lamports(&stream.vault).await;
+    let before = harness.lamports(&stream.authority.pubkey()).await;
+    harness.process(&[cancel(&stream)], &[&stream.authority]).await.unwrap();
+
+    assert_eq!(harness.token_balance(&stream.treasury).await, FUNDING);
+    assert!(!harness.account_exists(&stream.address).await);
+    assert!(!harness.account_exists(&stream.vault).await);
+    assert_eq!(harness.lamports(&stream.authority.pubkey()).await, before + rent);
+}
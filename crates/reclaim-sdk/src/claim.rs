@@ -0,0 +1,37 @@
+use anchor_lang::prelude::Pubkey;
+use reclaim::state::{ClaimData, ClaimInfo};
+use reclaim::utils::hash_content;
+
+/// The identifier `verify_proof` expects for `claim_info`
+pub fn claim_identifier(claim_info: &ClaimInfo) -> [u8; 32] {
+    hash_content(&claim_info.serialize_for_identifier())
+}
+
+pub fn build_claim_info(
+    provider: &str,
+    parameters: &str,
+    context_address: Pubkey,
+    context_message: &str,
+) -> ClaimInfo {
+    ClaimInfo {
+        provider: provider.to_string(),
+        parameters: parameters.to_string(),
+        context_address,
+        context_message: context_message.to_string(),
+    }
+}
+
+/// Claim data for `claim_info`, attested at `timestamp` in the epoch `epoch_index`
+pub fn build_claim_data(
+    claim_info: &ClaimInfo,
+    owner: &str,
+    timestamp: u32,
+    epoch_index: u32,
+) -> ClaimData {
+    ClaimData {
+        identifier: claim_identifier(claim_info),
+        owner: owner.to_string(),
+        timestamp,
+        epoch_index,
+    }
+}
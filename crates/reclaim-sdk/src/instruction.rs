@@ -0,0 +1,176 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use reclaim::instructions::{
+    AddMemberGroupArgs, CreateDappArgs, InitializeWitnessGovernanceArgs, UpdatePolicyDappArgs, VerifyProofArgs,
+};
+use reclaim::state::{ClaimInfo, DappPolicy, SignedClaim};
+
+use crate::pda::*;
+
+/// Accounts of a proof submission; the signer must be the claim's context address
+pub struct ProofAccounts {
+    pub epoch_config: Pubkey,
+    pub signer: Pubkey,
+}
+
+/// Dapp a verification is scoped to, with the fee recipient when its policy charges one
+pub struct DappAccounts {
+    pub dapp: Pubkey,
+    pub fee_recipient: Option<Pubkey>,
+}
+
+pub fn verify_proof(
+    accounts: &ProofAccounts,
+    dapp: Option<&DappAccounts>,
+    claim_info: ClaimInfo,
+    signed_claim: SignedClaim,
+) -> Instruction {
+    let (group, _) = find_group_address(&claim_info.provider);
+    let (nullifier, _) = find_nullifier_address(&group, &signed_claim.claim_data.claim_hash());
+    let (epoch, _) = find_epoch_address(&accounts.epoch_config, signed_claim.claim_data.epoch_index);
+
+    Instruction {
+        program_id: reclaim::ID,
+        accounts: reclaim::accounts::VerifyProof {
+            group,
+            nullifier,
+            epoch,
+            epoch_config: accounts.epoch_config,
+            signer: accounts.signer,
+            system_program: system_program::ID,
+            dapp: dapp.map(|dapp| dapp.dapp),
+            fee_recipient: dapp.and_then(|dapp| dapp.fee_recipient),
+        }
+        .to_account_metas(None),
+        data: reclaim::instruction::VerifyProof {
+            args: VerifyProofArgs {
+                claim_info,
+                signed_claim,
+            },
+        }
+        .data(),
+    }
+}
+
+pub fn add_member(
+    accounts: &ProofAccounts,
+    rent_payer: Pubkey,
+    claim_info: ClaimInfo,
+    signed_claim: SignedClaim,
+) -> Instruction {
+    let (group, _) = find_group_address(&claim_info.provider);
+    let (group_member, _) = find_group_member_address(&group, &claim_info.context_address);
+    let (epoch, _) = find_epoch_address(&accounts.epoch_config, signed_claim.claim_data.epoch_index);
+
+    Instruction {
+        program_id: reclaim::ID,
+        accounts: reclaim::accounts::AddMemberGroup {
+            group,
+            group_member,
+            epoch,
+            epoch_config: accounts.epoch_config,
+            signer: accounts.signer,
+            rent_payer,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: reclaim::instruction::AddMember {
+            args: AddMemberGroupArgs {
+                claim_info,
+                signed_claim,
+            },
+        }
+        .data(),
+    }
+}
+
+/// Register a dapp for `provider`'s group, its policy checked against `epoch`, the current
+/// epoch of the policy's epoch config. Both `create_key` and `creator` sign; the creator can
+/// later update the policy.
+pub fn create_dapp(
+    create_key: Pubkey,
+    creator: Pubkey,
+    provider: &str,
+    epoch: Pubkey,
+    policy: DappPolicy,
+) -> Instruction {
+    let (group, _) = find_group_address(provider);
+    let (dapp, _) = find_dapp_address(&create_key, &group);
+
+    Instruction {
+        program_id: reclaim::ID,
+        accounts: reclaim::accounts::CreateDapp {
+            dapp,
+            group,
+            epoch,
+            epoch_config: policy.epoch_config,
+            create_key,
+            creator,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: reclaim::instruction::CreateDapp {
+            args: CreateDappArgs {
+                group_root: 0,
+                policy,
+            },
+        }
+        .data(),
+    }
+}
+
+/// Replace the policy of `dapp`, checked against `epoch`, the current epoch of the policy's
+/// epoch config
+pub fn update_dapp_policy(dapp: Pubkey, creator: Pubkey, epoch: Pubkey, policy: DappPolicy) -> Instruction {
+    Instruction {
+        program_id: reclaim::ID,
+        accounts: reclaim::accounts::UpdatePolicyDapp {
+            dapp,
+            epoch,
+            epoch_config: policy.epoch_config,
+            creator,
+        }
+        .to_account_metas(None),
+        data: reclaim::instruction::UpdateDappPolicy {
+            args: UpdatePolicyDappArgs { policy },
+        }
+        .data(),
+    }
+}
+
+/// Hand the witness set of `epoch_config`, currently at `epoch_index`, over to the router
+/// executor of the government instance `government_state`. The executor signs, so this is
+/// routed as an approved decision of that government, with the deployer signing the outer
+/// transaction.
+pub fn initialize_witness_governance(
+    epoch_config: Pubkey,
+    epoch_index: u32,
+    deployer: Pubkey,
+    router_state: Pubkey,
+    government_state: Pubkey,
+) -> Instruction {
+    let (witness_history, _) = find_witness_history_address(&epoch_config);
+    let (current_epoch, _) = find_epoch_address(&epoch_config, epoch_index);
+    let (authority, _) = find_router_executor_address(&router_state, &government_state);
+
+    Instruction {
+        program_id: reclaim::ID,
+        accounts: reclaim::accounts::InitializeWitnessGovernance {
+            witness_history,
+            current_epoch,
+            epoch_config,
+            deployer,
+            authority,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: reclaim::instruction::InitializeWitnessGovernance {
+            args: InitializeWitnessGovernanceArgs {
+                router_state,
+                government_state,
+            },
+        }
+        .data(),
+    }
+}
@@ -0,0 +1,17 @@
+//! Off-chain client for the Reclaim program.
+//!
+//! Claims are serialized with the program's own `ClaimInfo` and `ClaimData` methods, so
+//! identifiers and signed messages match what `verify_proof` checks byte for byte.
+
+pub mod claim;
+pub mod instruction;
+pub mod pda;
+pub mod witness;
+
+pub use claim::*;
+pub use instruction::*;
+pub use pda::*;
+pub use witness::*;
+
+pub use reclaim::state::{ClaimData, ClaimInfo, DappFee, DappPolicy, SignedClaim, Witness};
+pub use reclaim::ID as RECLAIM_PROGRAM_ID;
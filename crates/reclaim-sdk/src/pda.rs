@@ -0,0 +1,61 @@
+use anchor_lang::prelude::Pubkey;
+use reclaim::constants::*;
+
+pub fn find_epoch_config_address(create_key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_EPOCH_CONFIG, create_key.as_ref()],
+        &reclaim::ID,
+    )
+}
+
+pub fn find_epoch_address(epoch_config: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, epoch_config.as_ref(), SEED_EPOCH, &index.to_le_bytes()],
+        &reclaim::ID,
+    )
+}
+
+pub fn find_witness_history_address(epoch_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, epoch_config.as_ref(), SEED_WITNESS_HISTORY],
+        &reclaim::ID,
+    )
+}
+
+pub fn find_group_address(provider: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_GROUP, provider.as_bytes()],
+        &reclaim::ID,
+    )
+}
+
+pub fn find_group_member_address(group: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, group.as_ref(), SEED_GROUP_MEMBER, member.as_ref()],
+        &reclaim::ID,
+    )
+}
+
+/// Nullifier of a signed claim, keyed by `ClaimData::claim_hash`
+pub fn find_nullifier_address(group: &Pubkey, claim_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, group.as_ref(), SEED_NULLIFIER, claim_hash],
+        &reclaim::ID,
+    )
+}
+
+pub fn find_dapp_address(create_key: &Pubkey, group: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_DAPP, create_key.as_ref(), group.as_ref()],
+        &reclaim::ID,
+    )
+}
+
+/// Router executor of the government instance `government_state`, the only valid witness
+/// set authority
+pub fn find_router_executor_address(router_state: &Pubkey, government_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_ROUTER_EXECUTOR, router_state.as_ref(), government_state.as_ref()],
+        &ROUTER_PROGRAM_ID,
+    )
+}
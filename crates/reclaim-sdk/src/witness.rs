@@ -0,0 +1,81 @@
+use anchor_lang::prelude::Result;
+use anchor_lang::solana_program::keccak;
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use reclaim::errors::ReclaimError;
+use reclaim::state::{ClaimData, Epoch, SignedClaim, Witness};
+use reclaim::utils::{append_0x, hash_content, prepare_for_verification, select_witness_addresses};
+
+/// A local witness key, for signing claims in tests and scripts without a witness network
+pub struct WitnessKey {
+    secret_key: SecretKey,
+}
+
+impl WitnessKey {
+    pub fn from_bytes(secret_key: &[u8; 32]) -> std::result::Result<Self, libsecp256k1::Error> {
+        Ok(Self {
+            secret_key: SecretKey::parse(secret_key)?,
+        })
+    }
+
+    /// Ethereum style address, as stored in `Witness.address`
+    pub fn address(&self) -> String {
+        let public_key = PublicKey::from_secret_key(&self.secret_key).serialize();
+        let hash = keccak::hash(&public_key[1..]).to_bytes();
+        append_0x(&hex::encode(&hash[12..]))
+    }
+
+    pub fn witness(&self, url: &str) -> Witness {
+        Witness {
+            address: self.address(),
+            url: url.to_string(),
+        }
+    }
+
+    /// Sign `claim_data` the way `verify_proof` recovers it
+    pub fn sign(&self, claim_data: &ClaimData) -> [u8; 65] {
+        let hash = prepare_for_verification(&claim_data.serialize_for_recovery());
+        let (signature, recovery_id) = libsecp256k1::sign(&Message::parse(&hash), &self.secret_key);
+
+        let mut signed = [0u8; 65];
+        signed[..64].copy_from_slice(&signature.serialize());
+        signed[64] = recovery_id.serialize() + 27;
+        signed
+    }
+}
+
+/// The witnesses `verify_proof` selects for `claim_data` in an epoch
+pub fn select_witnesses(
+    claim_data: &ClaimData,
+    minimum_witnesses_for_claim: u8,
+    witnesses: &[Witness],
+) -> Result<Vec<String>> {
+    let minimum_witnesses = usize::from(minimum_witnesses_for_claim);
+    let hash = hash_content(&claim_data.serialize_for_witness(minimum_witnesses));
+    select_witness_addresses(minimum_witnesses, &hash, witnesses)
+}
+
+/// Sign `claim_data` with every selected witness of `epoch`.
+/// Fails if `keys` is missing any of them, as `verify_proof` would reject the claim.
+pub fn sign_claim(claim_data: ClaimData, epoch: &Epoch, keys: &[WitnessKey]) -> Result<SignedClaim> {
+    let selected = select_witnesses(
+        &claim_data,
+        epoch.minimum_witnesses_for_claim,
+        &epoch.witnesses,
+    )?;
+
+    let signatures = selected
+        .iter()
+        .map(|address| {
+            let key = keys
+                .iter()
+                .find(|key| key.address() == *address)
+                .ok_or(ReclaimError::NotEnoughWitnessSignatures)?;
+            Ok(key.sign(&claim_data))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(SignedClaim {
+        claim_data,
+        signatures,
+    })
+}
@@ -0,0 +1,181 @@
+//! Claims signed with the SDK against the Reclaim program in an in-process bank
+
+use anchor_lang::error::Error;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::{system_program, AccountDeserialize, InstructionData, ToAccountMetas};
+use reclaim::errors::ReclaimError;
+use reclaim::instructions::{AddEpochArgs, CreateGroupArgs, InitializeEpochConfigArgs};
+use reclaim::state::Epoch;
+use reclaim_sdk::*;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+const PROVIDER: &str = "http";
+const OWNER: &str = "0x00000000000000000000000000000000000000a1";
+const MINIMUM_WITNESSES: u8 = 2;
+
+// Anchor's `entry` ties the account slice and its infos to a single lifetime, which the
+// native processor signature can't express
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    reclaim::entry(program_id, accounts, data)
+}
+
+/// A bank running only the Reclaim program
+async fn start() -> ProgramTestContext {
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+    program_test.add_program("reclaim", RECLAIM_PROGRAM_ID, processor!(process_instruction));
+    program_test.start_with_context().await
+}
+
+fn witness_keys() -> Vec<WitnessKey> {
+    (1..=3u8).map(|seed| WitnessKey::from_bytes(&[seed; 32]).unwrap()).collect()
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: RECLAIM_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) {
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction =
+        Transaction::new_signed_with_payer(instructions, Some(&context.payer.pubkey()), &all_signers, blockhash);
+    context.banks_client.process_transaction(transaction).await.unwrap();
+}
+
+/// An epoch of the three [`witness_keys`], two of which sign each claim, and a group for
+/// [`PROVIDER`]. Returns the epoch config.
+async fn setup(context: &mut ProgramTestContext) -> Pubkey {
+    let create_key = Keypair::new();
+    let deployer = context.payer.pubkey();
+    let (epoch_config, _) = find_epoch_config_address(&create_key.pubkey());
+    let (epoch, _) = find_epoch_address(&epoch_config, 1);
+    let (group, _) = find_group_address(PROVIDER);
+
+    let initialize = instruction(
+        reclaim::accounts::InitializeEpochConfig {
+            epoch_config,
+            create_key: create_key.pubkey(),
+            deployer,
+            system_program: system_program::ID,
+        },
+        reclaim::instruction::InitializeEpochConfig {
+            args: InitializeEpochConfigArgs {
+                epoch_duration_seconds: 24 * 60 * 60,
+                grace_period_seconds: 0,
+                max_claim_age_seconds: 60 * 60,
+            },
+        },
+    );
+    let add_epoch = instruction(
+        reclaim::accounts::AddEpoch {
+            epoch,
+            epoch_config,
+            rent_payer: deployer,
+            deployer,
+            system_program: system_program::ID,
+        },
+        reclaim::instruction::AddEpoch {
+            args: AddEpochArgs {
+                witnesses: witness_keys().iter().map(|key| key.witness("https://witness.local")).collect(),
+                minimum_witnesses_for_claim: MINIMUM_WITNESSES,
+            },
+        },
+    );
+    let create_group = instruction(
+        reclaim::accounts::CreateGroup {
+            group,
+            creator: deployer,
+            system_program: system_program::ID,
+        },
+        reclaim::instruction::CreateGroup {
+            args: CreateGroupArgs { provider: PROVIDER.to_string() },
+        },
+    );
+    process(context, &[initialize, add_epoch, create_group], &[&create_key]).await;
+
+    epoch_config
+}
+
+async fn epoch(context: &mut ProgramTestContext, epoch_config: &Pubkey) -> Epoch {
+    let (epoch, _) = find_epoch_address(epoch_config, 1);
+    let account = context.banks_client.get_account(epoch).await.unwrap().unwrap();
+    Epoch::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+/// Claim data about `user`, attested now
+async fn claim(context: &mut ProgramTestContext, user: &Pubkey) -> (ClaimInfo, ClaimData) {
+    let now = context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let claim_info = build_claim_info(PROVIDER, r#"{"url":"https://example.com"}"#, *user, "");
+    let claim_data = build_claim_data(&claim_info, OWNER, now as u32, 1);
+    (claim_info, claim_data)
+}
+
+#[test]
+fn witness_addresses_match_ethereum() {
+    // Well-known addresses of the secret keys 1 and 2
+    let cases = [
+        (1u8, "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+        (2u8, "0x2b5ad5c4795c026514f8317c7a215e218dccd6cf"),
+    ];
+
+    for (secret, address) in cases {
+        let mut secret_key = [0u8; 32];
+        secret_key[31] = secret;
+        assert_eq!(WitnessKey::from_bytes(&secret_key).unwrap().address(), address);
+    }
+}
+
+#[tokio::test]
+async fn signed_claims_verify_on_chain() {
+    let mut context = start().await;
+
+    let epoch_config = setup(&mut context).await;
+    let epoch = epoch(&mut context, &epoch_config).await;
+    // The user pays for the claim's nullifier
+    let user = Keypair::new();
+    let fund = system_instruction::transfer(&context.payer.pubkey(), &user.pubkey(), 1_000_000_000);
+    process(&mut context, &[fund], &[]).await;
+    let (claim_info, claim_data) = claim(&mut context, &user.pubkey()).await;
+
+    let signed_claim = sign_claim(claim_data, &epoch, &witness_keys()).unwrap();
+    assert_eq!(signed_claim.signatures.len(), usize::from(MINIMUM_WITNESSES));
+
+    let (group, _) = find_group_address(PROVIDER);
+    let (nullifier, _) = find_nullifier_address(&group, &signed_claim.claim_data.claim_hash());
+    let accounts = ProofAccounts { epoch_config, signer: user.pubkey() };
+    process(&mut context, &[verify_proof(&accounts, None, claim_info, signed_claim)], &[&user]).await;
+
+    assert!(context.banks_client.get_account(nullifier).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn signing_without_every_selected_witness_fails() {
+    let mut context = start().await;
+
+    let epoch_config = setup(&mut context).await;
+    let epoch = epoch(&mut context, &epoch_config).await;
+    let (_, claim_data) = claim(&mut context, &Pubkey::new_unique()).await;
+
+    // Drop one of the witnesses the epoch selects for the claim
+    let selected = select_witnesses(&claim_data, MINIMUM_WITNESSES, &epoch.witnesses).unwrap();
+    let keys: Vec<_> = witness_keys().into_iter().filter(|key| key.address() != selected[0]).collect();
+
+    match sign_claim(claim_data, &epoch, &keys) {
+        Err(Error::AnchorError(error)) => {
+            assert_eq!(error.error_code_number, u32::from(ReclaimError::NotEnoughWitnessSignatures));
+        }
+        _ => panic!("signing should have failed"),
+    }
+}
@@ -3,6 +3,7 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::states::{Monarch, Kingdom, Subject};
 use crate::error::AbsoluteMonarchyError;
 use anchor_spl::associated_token::AssociatedToken;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 
 #[derive(Accounts)]
@@ -90,6 +91,9 @@ pub struct AddMember<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    // Required when the kingdom has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         init,
         payer = authority,
@@ -127,6 +131,13 @@ pub fn add_member(ctx: Context<AddMember>) -> Result<()> {
         AbsoluteMonarchyError::InsufficientTokens
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        kingdom.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &ctx.accounts.authority.key(),
+    )?;
+
     subject.key = subject.key();
     subject.role = "Citizen".to_string();
     subject.jurisdiction = "Kingdom".to_string();
@@ -85,6 +85,7 @@ pub fn initialize_kingdom(
     kingdom.officials_appointed = Vec::new();
     kingdom.nobles = Vec::new();
     kingdom.membership_tokens_threshold = args.membership_tokens_threshold;
+    kingdom.reclaim_requirement = args.reclaim_requirement;
     kingdom.bump = ctx.bumps.kingdom;
 
     if let Some(ref nft_config) = args.nft_config {
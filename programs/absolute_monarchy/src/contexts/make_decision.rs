@@ -1,52 +1,41 @@
 use anchor_lang::prelude::*;
-use standard::cpi::accounts::RouteInstruction as RouterAccounts;
-use standard::program::Standard;
-use crate::states::Kingdom;
-
-// Add this to your existing instruction set
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct MakeDecisionArgs {
-    pub instruction_data: Vec<u8>,
-}
+use the_ark_program::{instructions_hash, Decision, DecisionRecord, InstructionContext};
+use crate::states::Monarch;
 
 #[derive(Accounts)]
 pub struct MakeDecision<'info> {
-    #[account(mut)]
-    pub kingdom: Box<Account<'info, Kingdom>>,
-    pub monarch: Signer<'info>,
-    /// CHECK: This is the router program
-    pub router_program: Program<'info, Standard>,
-    /// CHECK: This account is checked in the CPI call
-    pub router_state: UncheckedAccount<'info>,
-    /// CHECK: This account is checked in the CPI call
-    pub government_account: UncheckedAccount<'info>,
-    /// CHECK: This account is used to store the decision
+    // Government state the router asks about
+    pub monarch: Box<Account<'info, Monarch>>,
+    /// CHECK: Decision record owned by this program, read back by the router
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DecisionRecord::SPACE,
+        seeds = [b"decision", monarch.key().as_ref()],
+        bump
+    )]
     pub decision_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
+pub fn make_decision(ctx: Context<MakeDecision>, contexts: Vec<InstructionContext>) -> Result<()> {
+    for context in &contexts {
+        the_ark_program::validate_instruction_data(&context.instruction_data)?;
+    }
 
-pub fn make_decision(ctx: Context<MakeDecision>, args: MakeDecisionArgs) -> Result<()> {
-        // Ensure only the monarch can make decisions
-        if ctx.accounts.monarch.key() != ctx.accounts.kingdom.monarch {
-            return Err(ProgramError::InvalidAccountData.into());
-        }
+    // The monarch alone decides
+    let decision = if ctx.accounts.authority.key() == ctx.accounts.monarch.authority {
+        Decision::Approve
+    } else {
+        Decision::Reject
+    };
 
-        // Prepare the accounts for the router CPI
-        let cpi_accounts = RouterAccounts {
-            router_state: ctx.accounts.router_state.to_account_info(),
-            government_account: ctx.accounts.government_account.to_account_info(),
-            decision_account: ctx.accounts.decision_account.to_account_info(),
-            authority: ctx.accounts.monarch.to_account_info(),
-        };
+    // The record is reused, so it names the request it answers
+    let instructions_hash = instructions_hash(&contexts)?;
+    let mut data = ctx.accounts.decision_account.try_borrow_mut_data()?;
+    DecisionRecord { decision, instructions_hash }.try_serialize(&mut &mut data[..])?;
 
-        // Prepare the CPI context
-        let cpi_program = ctx.accounts.router_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-
-        // Make the CPI call to the router's route_instruction
-        standard::cpi::route_instruction(cpi_ctx, args.instruction_data)?;
-
-        // The decision has been made and executed by the router
-
-        Ok(())
-}
\ No newline at end of file
+    Ok(())
+}
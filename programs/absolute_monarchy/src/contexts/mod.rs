@@ -16,6 +16,7 @@ pub mod make_decision;
 pub mod init_treasury;
 pub mod create_invite;
 pub mod use_invite;
+pub mod set_reclaim_requirement;
 
 pub use abdicate::*;
 pub use appoint::*;
@@ -35,5 +36,6 @@ pub use make_decision::*;
 pub use init_treasury::*;
 pub use create_invite::*;
 pub use use_invite::*;
+pub use set_reclaim_requirement::*;
 
 
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
+use crate::states::{Kingdom, Monarch};
+use crate::error::AbsoluteMonarchyError;
+
+#[derive(Accounts)]
+pub struct SetReclaimRequirement<'info> {
+    // Grows kingdoms created before the requirement was part of their layout
+    #[account(
+        mut,
+        realloc = Kingdom::SPACE,
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub kingdom: Box<Account<'info, Kingdom>>,
+
+    #[account(
+        has_one = authority @ AbsoluteMonarchyError::NotMonarch,
+        constraint = monarch.key() == kingdom.monarch @ AbsoluteMonarchyError::MonarchKingdomMismatch
+    )]
+    pub monarch: Box<Account<'info, Monarch>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the credential new subjects must hold, or drop it with `None`
+pub fn update_reclaim_requirement(
+    ctx: Context<SetReclaimRequirement>,
+    reclaim_requirement: Option<ReclaimRequirement>,
+) -> Result<()> {
+    ctx.accounts.kingdom.reclaim_requirement = reclaim_requirement;
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Mint, Token};
 use anchor_spl::associated_token::AssociatedToken;
+use standard::{check_reclaim_requirement, VerificationResult};
 use crate::states::{Kingdom, KingdomInvite, Subject};
 use crate::error::AbsoluteMonarchyError;
 
@@ -29,6 +30,9 @@ pub struct UseKingdomInvite<'info> {
     #[account(mut)]
     pub new_subject: Signer<'info>,
 
+    // Required when the kingdom has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         init_if_needed,
         payer = new_subject,
@@ -60,6 +64,13 @@ pub fn use_kingdom_invite(ctx: Context<UseKingdomInvite>) -> Result<()> {
         AbsoluteMonarchyError::InsufficientTokens
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        kingdom.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_subject.key(),
+    )?;
+
     // Initialize the subject account
     subject.key = new_subject.key();
     subject.role = "Citizen".to_string();
@@ -6,6 +6,8 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use the_ark_program::InstructionContext;
+use standard::ReclaimRequirement;
 
 pub mod error;
 
@@ -48,8 +50,8 @@ pub mod absolute_monarchy {
         abdicate::abdicate(ctx, heir_name)
     }
 
-    pub fn make_decision(ctx: Context<MakeDecision>, args: MakeDecisionArgs) -> Result<()> {
-        make_decision::make_decision(ctx, args)
+    pub fn make_decision(ctx: Context<MakeDecision>, contexts: Vec<InstructionContext>) -> Result<()> {
+        make_decision::make_decision(ctx, contexts)
     }
 
     pub fn create_kingdom_invite(ctx: Context<CreateKingdomInvite>, expiration_days: u64) -> Result<()> {
@@ -60,6 +62,13 @@ pub mod absolute_monarchy {
         use_invite::use_kingdom_invite(ctx)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        set_reclaim_requirement::update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     // Legislative Powers
     pub fn decree(ctx: Context<DecreeContext>, decree_text: String, decree_type: DecreeType) -> Result<()> {
         init_decree::decree(ctx, decree_text, decree_type)
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 #[account]
 pub struct Kingdom {
@@ -37,6 +38,8 @@ pub struct Kingdom {
     pub nobles: Vec<Pubkey>,
     pub collection_price: u64,
     pub bump: u8,
+    // Credential new subjects must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 impl Kingdom {
@@ -86,7 +89,8 @@ impl Kingdom {
         4 + (32 * Self::MAX_OFFICIALS) + // offficials
         4 + (32 * Self::MAX_NOBLES) + // nobles
         8 + // membership token threshold
-        1;  // bump
+        1 + // bump
+        1 + ReclaimRequirement::SIZE; // reclaim_requirement
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -105,6 +109,7 @@ pub struct InitializeKingdomArgs {
     pub nft_config: Option<TokenConfig>,
     pub spl_config: Option<TokenConfig>,
     pub primary_kingdom_token: PrimaryKingdomToken,
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Mint};
 use crate::states::{Governance, StakeAccount};
 use crate::errors::ErrorCode;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct AddMember<'info> {
@@ -20,6 +21,9 @@ pub struct AddMember<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the governance has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         associated_token::mint = governance_token_mint,
         associated_token::authority = new_member,
@@ -52,6 +56,13 @@ pub fn add_member(ctx: Context<AddMember>) -> Result<()> {
         ErrorCode::InsufficientTokens
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize or update the stake account
     if stake_account.user == Pubkey::default() {
         stake_account.user = new_member.key();
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use crate::states::{governance::Governance, proposal::Proposal, vote::{StakeAccount, Vote}};
-use crate::states::helpers::*;
+use governance_math::calculate_voting_power;
 use crate::errors::ErrorCode;
 #[derive(Accounts)]
 pub struct CastVote<'info> {
@@ -38,7 +38,8 @@ pub fn vote_with_power(ctx: Context<CastVote>, vote: bool, voting_power: u64) ->
         ErrorCode::VotingPeriodInactive
     );
 
-    let max_voting_power = calculate_voting_power(stake_account.amount, stake_account.conviction_multiplier);
+    let max_voting_power = calculate_voting_power(stake_account.amount, stake_account.conviction_multiplier)
+        .ok_or(ErrorCode::Overflow)?;
     require!(voting_power <= max_voting_power, ErrorCode::ExceedsAvailableVotingPower);
 
     // Initialize vote account if not already
@@ -63,9 +64,9 @@ pub fn vote_with_power(ctx: Context<CastVote>, vote: bool, voting_power: u64) ->
 
     // Record new vote
     if vote {
-        proposal.for_votes = proposal.for_votes.saturating_add(voting_power);
+        proposal.for_votes = proposal.for_votes.checked_add(voting_power).ok_or(ErrorCode::Overflow)?;
     } else {
-        proposal.against_votes = proposal.against_votes.saturating_add(voting_power);
+        proposal.against_votes = proposal.against_votes.checked_add(voting_power).ok_or(ErrorCode::Overflow)?;
     }
 
     vote_account.vote = Some(vote);
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Mint, Token};
 use anchor_spl::associated_token::AssociatedToken;
+use standard::{check_reclaim_requirement, VerificationResult};
 use crate::states::{StakeAccount, ConvictionInvite, Governance};
 use crate::errors::ErrorCode;
 
@@ -66,6 +67,9 @@ pub struct UseInvite<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the governance has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         init_if_needed,
         payer = new_member,
@@ -100,6 +104,13 @@ pub fn use_invite(ctx: Context<UseInvite>) -> Result<()> {
     let stake_account = &mut ctx.accounts.stake_account;
     let new_member = &ctx.accounts.new_member;
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Mark the invite as used
     invite.is_used = true;
     invite.used_by = Some(new_member.key());
@@ -25,6 +25,7 @@ pub fn create_proposal(
     voting_period: i64,
     execution_delay: i64,
     proposal_type: ProposalType,
+    instructions_hash: [u8; 32],
 ) -> Result<()> {
 
     // pub id: u64,
@@ -57,6 +58,7 @@ pub fn create_proposal(
     proposal.param_value = None;
     proposal.transfer_amount = None;
     proposal.proposal_type = proposal_type; 
+    proposal.instructions_hash = instructions_hash;
 
     governance.total_proposals += 1;
     governance.total_active_proposals += 1;
@@ -58,6 +58,7 @@ pub fn initialize_governance(
     governance.total_proposals = 0;
     governance.total_members = 0;
     governance.min_stake_amount = args.min_stake_amount;
+    governance.reclaim_requirement = args.reclaim_requirement;
     governance.approval_threshold = args.approval_threshold;
     governance.collection_price = args.collection_price;
     governance.bump = ctx.bumps.governance;
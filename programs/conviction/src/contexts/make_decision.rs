@@ -1,23 +1,28 @@
 use anchor_lang::prelude::*;
-use crate::states::{Proposal, ProposalStatus};
-use the_ark_program::{InstructionContext, Decision};
+use crate::states::{Governance, Proposal, ProposalStatus};
+use the_ark_program::{instructions_hash, Decision, DecisionRecord, InstructionContext};
 use crate::errors::ErrorCode;
-use standard::cpi::accounts::RouteInstruction as RouterAccounts;
-use standard::GovernmentInstruction;
-use standard::program::Standard;
 
-pub fn send_proposal_decision_to_router(
-    ctx: Context<SendProposalDecision>,
-    instruction_data: Vec<u8>
-) -> Result<()> {
+pub fn make_decision(ctx: Context<MakeDecision>, contexts: Vec<InstructionContext>) -> Result<()> {
     let proposal = &ctx.accounts.proposal;
-    let clock = &ctx.accounts.clock;
+    let clock = Clock::get()?;
 
     // Check if the proposal is still active and voting period has ended
     if proposal.status != ProposalStatus::Active || clock.unix_timestamp < proposal.end_time {
         return Err(ErrorCode::ProposalNotActive.into());
     }
 
+    for context in &contexts {
+        the_ark_program::validate_instruction_data(&context.instruction_data)?;
+    }
+
+    // A proposal only decides the batch it was created for
+    let instructions_hash = instructions_hash(&contexts)?;
+    require!(
+        proposal.instructions_hash == instructions_hash,
+        ErrorCode::ProposalInstructionsMismatch
+    );
+
     // Determine the decision based on the existing vote counts
     let total_votes = proposal.for_votes + proposal.against_votes;
     let decision = if total_votes > 0 && proposal.for_votes > proposal.against_votes {
@@ -26,44 +31,16 @@ pub fn send_proposal_decision_to_router(
         Decision::Reject
     };
 
-    // Prepare the accounts for the router CPI
-    let cpi_accounts = RouterAccounts {
-        router_state: ctx.accounts.router_state.to_account_info(),
-        government_account: ctx.accounts.government_account.to_account_info(),
-        decision_account: ctx.accounts.decision_account.to_account_info(),
-        authority: ctx.accounts.authority.to_account_info(),
-    };
-
-    // Prepare the CPI context
-    let cpi_program = ctx.accounts.router_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-
-    // Prepare the instruction context
-    let instruction_context = InstructionContext {
-        program_id: ctx.accounts.proposal.key(),
-        instruction_data,
-        signer: ctx.accounts.authority.key(),
-        accounts: ctx.remaining_accounts.iter().map(|a| *a.key).collect(),
-        block_time: clock.unix_timestamp,
-        instruction_index: 0, // You might want to track this separately
-    };
-
-    // Serialize the instruction context
-    let instruction_context_data = instruction_context.try_to_vec()?;
-
-    // Prepare the instruction data for the router program
-    let mut router_ix_data = GovernmentInstruction::MakeDecision.try_to_vec()?;
-    router_ix_data.extend_from_slice(&instruction_context_data);
+    // Record the decision for the router to read back
+    let mut data = ctx.accounts.decision_account.try_borrow_mut_data()?;
+    DecisionRecord { decision: decision.clone(), instructions_hash }.try_serialize(&mut &mut data[..])?;
 
-    // Make the CPI call to the router's route_instruction
-    standard::cpi::route_instruction(cpi_ctx, router_ix_data)?;
-
-    // Update the proposal status
+    // The approved batch runs right after, so the proposal is spent either way
     let proposal = &mut ctx.accounts.proposal;
-    proposal.status = if decision == Decision::Approve { 
-        ProposalStatus::Passed 
-    } else { 
-        ProposalStatus::Rejected 
+    proposal.status = if decision == Decision::Approve {
+        ProposalStatus::Executed
+    } else {
+        ProposalStatus::Rejected
     };
 
     // Emit an event with the decision details
@@ -79,19 +56,28 @@ pub fn send_proposal_decision_to_router(
 }
 
 #[derive(Accounts)]
-pub struct SendProposalDecision<'info> {
-    #[account(mut)]
-    pub proposal: Account<'info, Proposal>,
-    pub clock: Sysvar<'info, Clock>,
-    /// CHECK: This is the router program
-    pub router_program: Program<'info, Standard>,
-    /// CHECK: This account is checked in the CPI call
-    pub router_state: UncheckedAccount<'info>,
-    /// CHECK: This account is checked in the CPI call
-    pub government_account: UncheckedAccount<'info>,
-    /// CHECK: This account is used to store the decision
+pub struct MakeDecision<'info> {
+    // Government state the router asks about
+    pub governance: Box<Account<'info, Governance>>,
+    /// CHECK: Decision record owned by this program, read back by the router
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DecisionRecord::SPACE,
+        seeds = [b"decision", governance.key().as_ref()],
+        bump
+    )]
     pub decision_account: UncheckedAccount<'info>,
+    #[account(mut)]
     pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    // The router forwards the proposal after its own accounts
+    #[account(
+        mut,
+        seeds = [b"proposal", governance.key().as_ref(), &(proposal.id - 1).to_le_bytes()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
 }
 
 #[event]
@@ -101,4 +87,4 @@ pub struct ProposalDecisionEvent {
     pub for_votes: u64,
     pub against_votes: u64,
     pub total_votes: u64,
-}
\ No newline at end of file
+}
@@ -14,6 +14,7 @@ pub mod unstake_nft;
 pub mod add;
 pub mod make_decision;
 pub mod create_invite;
+pub mod set_reclaim_requirement;
 
 
 pub use cancel_proposal::*;
@@ -32,4 +33,5 @@ pub use unstake_nft::*;
 pub use add::*;
 pub use make_decision::*;
 pub use create_invite::*;
+pub use set_reclaim_requirement::*;
 
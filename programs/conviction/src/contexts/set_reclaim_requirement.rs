@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
+use crate::states::Governance;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetReclaimRequirement<'info> {
+    // Grows accounts created before the requirement was part of their layout
+    #[account(
+        mut,
+        realloc = Governance::SPACE,
+        realloc::payer = creator,
+        realloc::zero = false
+    )]
+    pub governance: Box<Account<'info, Governance>>,
+    #[account(mut, constraint = creator.key() == governance.creator @ ErrorCode::Unauthorized)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the credential new members must hold, or drop it with `None`
+pub fn update_reclaim_requirement(
+    ctx: Context<SetReclaimRequirement>,
+    reclaim_requirement: Option<ReclaimRequirement>,
+) -> Result<()> {
+    ctx.accounts.governance.reclaim_requirement = reclaim_requirement;
+    Ok(())
+}
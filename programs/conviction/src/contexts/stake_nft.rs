@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount, Mint};
 use crate::states::{proposal::{Proposal, ProposalStatus}, vote::StakeAccount, governance::Governance};
-use crate::states::helpers::*;
+use governance_math::{calculate_conviction_multiplier, calculate_lock_duration};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::errors::ErrorCode;
 
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::states::{proposal::{Proposal, ProposalStatus}, vote::StakeAccount};
 use anchor_spl::token::{TokenAccount, Token, Transfer, Mint, transfer};
 use anchor_spl::associated_token::AssociatedToken;
-use crate::states::helpers::*;
+use governance_math::{calculate_conviction_multiplier, calculate_lock_duration};
 use crate::errors::ErrorCode;
 
 
@@ -13,7 +13,7 @@ pub struct StakeOnProposal<'info> {
     #[account(
         init_if_needed,
         payer = user,
-        space = 8 + 32 + 8,
+        space = StakeAccount::SPACE,
         seeds = [b"stake", proposal.key().as_ref(), user.key().as_ref()],
         bump
     )]
@@ -28,7 +28,7 @@ pub struct StakeOnProposal<'info> {
         init_if_needed,
         payer = user,
         associated_token::mint = mint,
-        associated_token::authority = stake,
+        associated_token::authority = user,
     )]
     pub user_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
@@ -57,26 +57,15 @@ pub fn stake(ctx: Context<StakeOnProposal>, amount: u64, lock_period: u8) -> Res
 
     require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
 
-    let proposal_key = proposal.key();
-    let user_key = ctx.accounts.user.key();
-
-    let stake_seeds = &[
-        b"stake",
-        proposal_key.as_ref(),
-        user_key.as_ref(),
-        &[ctx.bumps.stake]
-    ];
-
     // Transfer tokens from user to proposal account
     transfer(
-        CpiContext::new_with_signer(
+        CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.user_token_account.to_account_info(),
                 to: ctx.accounts.proposal_account.to_account_info(),
                 authority: ctx.accounts.user.to_account_info(),
             },
-            &[stake_seeds]
         ),
         amount,
     )?;
@@ -85,6 +74,7 @@ pub fn stake(ctx: Context<StakeOnProposal>, amount: u64, lock_period: u8) -> Res
     stake_account.amount += amount;
     stake_account.lock_end = clock.unix_timestamp + calculate_lock_duration(lock_period);
     stake_account.conviction_multiplier = calculate_conviction_multiplier(lock_period);
+    stake_account.bump = ctx.bumps.stake;
 
     Ok(())
 }
\ No newline at end of file
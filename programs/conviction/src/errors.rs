@@ -54,5 +54,7 @@ pub enum ErrorCode {
     #[msg("Invite used")]
     InviteAlreadyUsed,
     #[msg("Invite invalid")]
-    InvalidInvite
+    InvalidInvite,
+    #[msg("The instructions do not match the ones the proposal was created for")]
+    ProposalInstructionsMismatch,
 }
\ No newline at end of file
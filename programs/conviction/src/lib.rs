@@ -6,6 +6,8 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use the_ark_program::InstructionContext;
+use standard::ReclaimRequirement;
 
 
 pub mod errors;
@@ -56,21 +58,26 @@ pub mod conviction {
         use_invite(ctx)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     pub fn new_proposal(
         ctx: Context<CreateProposal>,
         description: String,
         voting_period: i64,
         execution_delay: i64,
         proposal_type: ProposalType,
+        instructions_hash: [u8; 32],
     ) -> Result<()> {
-        create_proposal(ctx, description, voting_period, execution_delay, proposal_type)
+        create_proposal(ctx, description, voting_period, execution_delay, proposal_type, instructions_hash)
     }
 
-    pub fn send_decision_to_router(
-        ctx: Context<SendProposalDecision>,
-        instruction_data: Vec<u8>
-    ) -> Result<()> {
-        send_proposal_decision_to_router(ctx, instruction_data)
+    pub fn make_decision(ctx: Context<MakeDecision>, contexts: Vec<InstructionContext>) -> Result<()> {
+        contexts::make_decision::make_decision(ctx, contexts)
     }
 
     pub fn conclude_proposal(ctx: Context<EndAndExecuteProposal>) -> Result<()> {
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 #[account]
 pub struct Governance {
@@ -26,6 +27,8 @@ pub struct Governance {
     pub total_nft_token_supply: u64,
     pub total_sbt_token_supply: u64,
     pub bump: u8,
+    // Credential new members must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 impl Governance {
@@ -59,7 +62,8 @@ impl Governance {
         8 + // spl total token supply
         8 + // nft total token supply
         8 + // sbt total token supply
-        1;  // bump (1 byte for the bump seed)
+        1 + // bump (1 byte for the bump seed)
+        1 + ReclaimRequirement::SIZE; // reclaim_requirement
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -76,6 +80,7 @@ pub struct InitializeGovernanceArgs {
     pub nft_config: Option<TokenConfig>,
     pub spl_config: Option<TokenConfig>,
     pub primary_governance_token: PrimaryGovernanceToken,
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
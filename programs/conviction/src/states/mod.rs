@@ -1,5 +1,4 @@
 pub mod governance;
-pub mod helpers;
 pub mod proposal;
 pub mod vote;
 pub mod validate_proposal;
@@ -24,6 +24,8 @@ pub struct Proposal {
     pub sbt_args: Option<InitializeSbtArgs>,
     pub total_staked: u64,
     pub bump: u8,
+    // `instructions_hash` of the batch the proposal routes once it passes
+    pub instructions_hash: [u8; 32],
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -87,7 +89,8 @@ impl Proposal {
             4 + Self::MAX_URI_LEN
         ) +  // sbt_args (optional)
         8 + // total_staked
-        1;  // bump
+        1 + // bump
+        32;  // instructions_hash
 
     // ... other impl methods ...
 }
\ No newline at end of file
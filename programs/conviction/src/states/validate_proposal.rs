@@ -1,18 +1,15 @@
 use anchor_lang::prelude::*;
+use governance_math::exceeds_threshold;
+use crate::errors::ErrorCode;
 use crate::states::{proposal::{Proposal, ProposalStatus}, governance::Governance};
 
 pub fn check_proposal_status(proposal: &mut Proposal, governance: &Governance) -> Result<()> {
 
-    let total_votes = proposal.for_votes + proposal.against_votes;
+    let total_votes = proposal.for_votes
+        .checked_add(proposal.against_votes)
+        .ok_or(ErrorCode::Overflow)?;
 
-    if total_votes == 0 {
-        return Ok(());
-    }
-
-    let approval_ratio = (proposal.for_votes as f64) / (total_votes as f64);
-    let approval_threshold = (governance.approval_threshold as f64) / 100.0;
-
-    if approval_ratio > approval_threshold {
+    if exceeds_threshold(proposal.for_votes, total_votes, governance.approval_threshold) {
         proposal.status = ProposalStatus::Passed;
     }
 
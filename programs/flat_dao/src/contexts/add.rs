@@ -3,6 +3,7 @@ use anchor_spl::token::{TokenAccount, Mint};
 use crate::states::{DAO, UserMembership};
 use crate::error::ErrorCode;
 use crate::constants::*;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct AddMember<'info> {
@@ -21,6 +22,9 @@ pub struct AddMember<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the DAO has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         associated_token::mint = governance_token_mint,
         associated_token::authority = new_member,
@@ -41,6 +45,13 @@ pub fn add_member(ctx: Context<AddMember>) -> Result<()> {
 
     require!(dao.total_members < MAX_TOTAL_MEMBERS, ErrorCode::MaxMembersReached);
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        dao.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize the user membership
     user_membership.user = new_member.key();
     user_membership.dao = dao.key();
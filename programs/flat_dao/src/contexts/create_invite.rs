@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
 use crate::states::{DAOInvite, DAO, User};
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct CreateInvite<'info> {
@@ -42,6 +43,8 @@ pub struct UseInvite<'info> {
     pub invite: Account<'info, DAOInvite>,
     #[account(mut)]
     pub new_member: Signer<'info>,
+    // Required when the DAO has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
 }
 
 pub fn use_invite(ctx: Context<UseInvite>) -> Result<()> {
@@ -52,6 +55,13 @@ pub fn use_invite(ctx: Context<UseInvite>) -> Result<()> {
     require!(!invite.is_used, ErrorCode::InviteAlreadyUsed);
     require!(clock.unix_timestamp <= invite.expires_at, ErrorCode::InviteExpired);
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        dao.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &ctx.accounts.new_member.key(),
+    )?;
+
     invite.is_used = true;
     invite.used_by = Some(ctx.accounts.new_member.key());
 
@@ -8,6 +8,7 @@ use crate::{
 };
 
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 #[derive(Accounts)]
 #[instruction(time: Time, threshold: u8, min_poll_tokens: u64, sname: String)]
@@ -63,6 +64,7 @@ impl<'info> DAOCreate<'info> {
         threshold: u8,
         min_poll_tokens: u64,
         name: String,
+        reclaim_requirement: Option<ReclaimRequirement>,
     ) -> Result<()> {
         // pub creator: Pubkey,
         // pub mint: Pubkey,
@@ -100,6 +102,7 @@ impl<'info> DAOCreate<'info> {
         dao.name = name;
         dao.polls = Vec::new();
         dao.users = Vec::new();
+        dao.reclaim_requirement = reclaim_requirement;
         Ok(())
     }
 
@@ -8,6 +8,7 @@ pub mod new_poll;
 pub mod new_stake;
 pub mod add;
 pub mod create_invite;
+pub mod set_reclaim_requirement;
 
 pub use cast_vote::*;
 pub use claim_stake::*;
@@ -18,4 +19,5 @@ pub use init_dao::*;
 pub use new_poll::*;
 pub use new_stake::*;
 pub use add::*;
-pub use create_invite::*;
\ No newline at end of file
+pub use create_invite::*;
+pub use set_reclaim_requirement::*;
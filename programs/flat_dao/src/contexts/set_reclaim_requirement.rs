@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
+use crate::error::ErrorCode;
+use crate::states::{Deposit, Poll, User, Vote, DAO};
+
+#[derive(Accounts)]
+pub struct SetReclaimRequirement<'info> {
+    // Grows DAOs created before the requirement was part of their layout
+    #[account(
+        mut,
+        realloc = DAO::LEN
+            + dao.users.len() * User::LEN
+            + dao.total_deposits() * Deposit::LEN
+            + dao.total_polls() * Poll::LEN
+            + dao.total_votes() * Vote::LEN,
+        realloc::zero = false,
+        realloc::payer = creator,
+        seeds = [b"dao", dao.creator.as_ref(), dao.mint.as_ref()],
+        bump = dao.dao_bump
+    )]
+    pub dao: Box<Account<'info, DAO>>,
+    #[account(mut, constraint = creator.key() == dao.creator @ ErrorCode::Unauthorized)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the credential new members must hold, or drop it with `None`
+pub fn update_reclaim_requirement(
+    ctx: Context<SetReclaimRequirement>,
+    reclaim_requirement: Option<ReclaimRequirement>,
+) -> Result<()> {
+    ctx.accounts.dao.reclaim_requirement = reclaim_requirement;
+    Ok(())
+}
@@ -6,14 +6,15 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use standard::ReclaimRequirement;
 
 mod constants;
-mod contexts;
+pub mod contexts;
 mod error;
-mod states;
+pub mod states;
 
-use contexts::*;
-use states::*;
+pub use contexts::*;
+pub use states::*;
 
 declare_id!("FNF2M3rVeAhQ28VTCNVYzfKTnX1ZcStGuDZ9geVzY38Q");
 
@@ -47,6 +48,13 @@ pub mod flat_dao {
         use_invite(ctx)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     pub fn init_analytics(ctx: Context<Init>) -> Result<()> {
         ctx.accounts.init_analytics(&ctx.bumps)
     }
@@ -57,9 +65,10 @@ pub mod flat_dao {
         threshold: u8,
         min_poll_tokens: u64,
         name: String,
+        reclaim_requirement: Option<ReclaimRequirement>,
     ) -> Result<()> {
         ctx.accounts
-            .dao_create(&ctx.bumps, time, threshold, min_poll_tokens, name)?;
+            .dao_create(&ctx.bumps, time, threshold, min_poll_tokens, name, reclaim_requirement)?;
         ctx.accounts.update_analytics()
     }
 
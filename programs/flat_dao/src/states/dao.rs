@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 use crate::constants::*;
 use crate::states::{Poll, User};
@@ -20,6 +21,8 @@ pub struct DAO {
     pub polls: Vec<Poll>,
     pub users: Vec<User>,
     pub total_members: u32, 
+    // Credential new members must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 impl DAO {
@@ -34,7 +37,8 @@ impl DAO {
         + VECTOR_LENGTH_PREFIX * 2  // for polls and users vectors
         + STRING_LENGTH_PREFIX
         + MAX_DAO_NAME_LENGTH
-        + 4;  // total_members (u32)
+        + 4  // total_members (u32)
+        + 1 + ReclaimRequirement::SIZE;  // reclaim_requirement
 
     // ... other methods ...
 
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
+use governance_math::{meets_threshold, tally};
+
 use crate::constants::*;
+use crate::error::ErrorCode;
 use crate::states::{Status, Vote, Choice, DAO};
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
@@ -24,17 +27,10 @@ impl Poll {
         + MAX_CONTENT_LENGTH
         + VECTOR_LENGTH_PREFIX; // bump
 
-    pub fn is_approved(&self, dao: &DAO) -> bool {
-        let mut approve_power = 0u64;
-        let mut total_power = 0u64;
-
-        for vote in &self.votes {
-            total_power += vote.voting_power;
-            if vote.choice == Choice::Approve {
-                approve_power += vote.voting_power;
-            }
-        }
+    pub fn is_approved(&self, dao: &DAO) -> Result<bool> {
+        let (approve_power, total_power) = tally(
+            self.votes.iter().map(|vote| (vote.voting_power, vote.choice == Choice::Approve))
+        ).ok_or(ErrorCode::ArithmeticError)?;
 
-        let approval_percentage = (approve_power as f64 / total_power as f64) * 100.0;
-        approval_percentage >= dao.threshold as f64
+        Ok(meets_threshold(approve_power, total_power, dao.threshold as u64))
     }}
\ No newline at end of file
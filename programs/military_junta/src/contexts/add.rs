@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Mint};
 use crate::states::{Junta, Citizen};
 use crate::errors::ErrorCode;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct AddJuntaMember<'info> {
@@ -20,6 +21,9 @@ pub struct AddJuntaMember<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the junta has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         associated_token::mint = governance_token_mint,
         associated_token::authority = new_member,
@@ -50,6 +54,13 @@ pub fn add_junta_member(ctx: Context<AddJuntaMember>) -> Result<()> {
         ErrorCode::InsufficientTokens
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        junta.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize the citizen account
     citizen.authority = new_member.key();
     citizen.loyalty_score = 50; // Starting loyalty score
@@ -48,6 +48,7 @@ pub fn initialize_junta(ctx: Context<InitializeJunta>, args: InitializeJuntaArgs
     junta.martial_law_active = false;
     junta.is_overthrown = false;
     junta.support_threshold = args.support_threshold;
+    junta.reclaim_requirement = args.reclaim_requirement;
     junta.bump = ctx.bumps.junta;
 
     if let Some(ref spl_config) = args.spl_config {
@@ -18,6 +18,7 @@ pub mod mint_spl;
 pub mod add;
 pub mod create_invite;
 pub mod use_invite;
+pub mod set_reclaim_requirement;
 
 
 pub use initialize_junta::*;
@@ -40,3 +41,4 @@ pub use mint_spl::*;
 pub use add::*;
 pub use create_invite::*;
 pub use use_invite::*;
+pub use set_reclaim_requirement::*;
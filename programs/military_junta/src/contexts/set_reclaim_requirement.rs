@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
+use crate::states::Junta;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetReclaimRequirement<'info> {
+    // Grows accounts created before the requirement was part of their layout
+    #[account(
+        mut,
+        realloc = Junta::SPACE,
+        realloc::payer = leader,
+        realloc::zero = false
+    )]
+    pub junta: Box<Account<'info, Junta>>,
+    #[account(mut, constraint = leader.key() == junta.leader @ ErrorCode::Unauthorized)]
+    pub leader: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the credential new citizens must hold, or drop it with `None`
+pub fn update_reclaim_requirement(
+    ctx: Context<SetReclaimRequirement>,
+    reclaim_requirement: Option<ReclaimRequirement>,
+) -> Result<()> {
+    ctx.accounts.junta.reclaim_requirement = reclaim_requirement;
+    Ok(())
+}
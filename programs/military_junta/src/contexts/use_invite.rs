@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Mint};
 use crate::states::{Junta, JuntaInvite, Citizen};
 use crate::errors::ErrorCode;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct UseJuntaInvite<'info> {
@@ -28,6 +29,9 @@ pub struct UseJuntaInvite<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the junta has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
         associated_token::mint = governance_token_mint,
         associated_token::authority = new_member,
@@ -54,6 +58,13 @@ pub fn use_junta_invite(ctx: Context<UseJuntaInvite>) -> Result<()> {
         ErrorCode::InsufficientTokens
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        junta.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize the citizen account
     citizen.authority = new_member.key();
     citizen.loyalty_score = 50; // Starting loyalty score
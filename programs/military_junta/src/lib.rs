@@ -6,6 +6,7 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use standard::ReclaimRequirement;
 
 pub mod states;
 pub mod contexts;
@@ -50,6 +51,13 @@ pub mod military_junta {
         use_invite::use_junta_invite(ctx)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        set_reclaim_requirement::update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     pub fn appoint_officers(ctx: Context<AppointOfficer>, rank: u8) -> Result<()> {
         appoint_officer(ctx, rank)
     }
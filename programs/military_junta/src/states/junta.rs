@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 #[account]
 pub struct Junta {
@@ -28,6 +29,8 @@ pub struct Junta {
     pub sbt_mint: Option<Pubkey>,
     pub total_subjects: u64,
     pub bump: u8,
+    // Credential new citizens must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -44,6 +47,7 @@ pub struct InitializeJuntaArgs {
     pub nft_supply: u64,
     pub spl_supply: u64,
     pub primary_junta_token: PrimaryJuntaToken,
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -90,7 +94,8 @@ impl Junta {
         1 + // support_threshold
         33 + // spl_mint (Option<Pubkey>)
         33 + // nft_mint (Option<Pubkey>)
-        1; // bump
+        1 + // bump
+        1 + ReclaimRequirement::SIZE; // reclaim_requirement
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -3,6 +3,7 @@ use crate::states::{GovernancePool, Assembly, Citizen};
 use anchor_spl::token::{Token, TokenAccount, Mint};
 use crate::error::GovernanceError; 
 use anchor_spl::associated_token::AssociatedToken;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct InitializeCitizen<'info> {
@@ -21,6 +22,9 @@ pub struct InitializeCitizen<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    // Required when the governance pool has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     pub mint: Account<'info, Mint>,
 
     #[account(
@@ -43,6 +47,13 @@ pub fn initialize_citizen(ctx: Context<InitializeCitizen>) -> Result<()> {
     let citizen = &mut ctx.accounts.citizen;
     let user = &ctx.accounts.user;
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance_pool.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &user.key(),
+    )?;
+
     citizen.governance_pool = governance_pool.key();
     citizen.user = user.key();
     citizen.assemblies = Vec::new();
@@ -80,6 +91,9 @@ pub struct AddMember<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the governance pool has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     /// The mint of the governance token
     pub governance_token_mint: Account<'info, Mint>,
 
@@ -107,6 +121,13 @@ pub fn add_member(ctx: Context<AddMember>) -> Result<()> {
         GovernanceError::InsufficientTokens
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance_pool.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
 
     require!(
         !assembly.members.contains(&new_member.key()),
@@ -61,6 +61,7 @@ pub fn handler(ctx: Context<InitializeGovernment>, args: InitializeGovernmentArg
     governance_pool.spl_symbol = args.spl_symbol.clone();
     governance_pool.collection_price = args.collection_price;
     governance_pool.resources = 0;
+    governance_pool.reclaim_requirement = args.reclaim_requirement;
     governance_pool.bump = ctx.bumps.governance_pool;
 
 
@@ -13,6 +13,7 @@ pub mod mint_sbt;
 pub mod mint_nft;
 pub mod create_invite;
 pub mod use_invite;
+pub mod set_reclaim_requirement;
 
 
 pub use create_assembly::*;
@@ -30,3 +31,4 @@ pub use mint_sbt::*;
 pub use mint_nft::*;
 pub use use_invite::*;
 pub use create_invite::*;
+pub use set_reclaim_requirement::*;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
+use crate::states::GovernancePool;
+use crate::error::GovernanceError;
+
+#[derive(Accounts)]
+pub struct SetReclaimRequirement<'info> {
+    // Grows accounts created before the requirement was part of their layout
+    #[account(
+        mut,
+        realloc = GovernancePool::SPACE,
+        realloc::payer = admin,
+        realloc::zero = false
+    )]
+    pub governance_pool: Box<Account<'info, GovernancePool>>,
+    #[account(mut, constraint = admin.key() == governance_pool.admin @ GovernanceError::Unauthorized)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the credential new citizens must hold, or drop it with `None`
+pub fn update_reclaim_requirement(
+    ctx: Context<SetReclaimRequirement>,
+    reclaim_requirement: Option<ReclaimRequirement>,
+) -> Result<()> {
+    ctx.accounts.governance_pool.reclaim_requirement = reclaim_requirement;
+    Ok(())
+}
@@ -3,6 +3,7 @@ use crate::states::{GovernancePool, GovernanceInvite, Citizen};
 use crate::error::GovernanceError;
 use anchor_spl::token::{Token, TokenAccount, Mint};
 use anchor_spl::associated_token::AssociatedToken;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct UseGovernanceInvite<'info> {
@@ -29,6 +30,9 @@ pub struct UseGovernanceInvite<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the governance pool has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     pub governance_token_mint: Account<'info, Mint>,
 
     #[account(
@@ -52,6 +56,13 @@ pub fn use_governance_invite(ctx: Context<UseGovernanceInvite>) -> Result<()> {
     let new_member = &ctx.accounts.new_member;
     let clock = Clock::get()?;
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance_pool.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize the citizen (similar to initialize_citizen function)
     citizen.governance_pool = governance_pool.key();
     citizen.user = new_member.key();
@@ -6,6 +6,7 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use standard::ReclaimRequirement;
 
 declare_id!("5MkjpkHC6FuXQgkjJiTc6QNNAzYyAHfRFHyQFNjXT1kv");
 
@@ -51,6 +52,13 @@ pub mod polycentric {
         use_governance_invite(ctx)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     pub fn create_assembly(ctx: Context<CreateAssembly>, name: String, description: String) -> Result<()> {
         create_assembly::handler(ctx, name, description)
     }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::GovernanceError;
+use standard::ReclaimRequirement;
 
 #[account]
 pub struct GovernancePool {
@@ -31,6 +32,8 @@ pub struct GovernancePool {
     pub governance_token_mint: Pubkey,
     pub membership_token_threshold: u64,
     pub bump: u8,
+    // Credential new citizens must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 
 }
 
@@ -72,7 +75,8 @@ impl GovernancePool {
         32 +  // sbt_mint
         32 +  // governance_token_mint
         8 +  // membership_token_threshold
-        1;   // bump
+        1 +  // bump
+        1 + ReclaimRequirement::SIZE;  // reclaim_requirement
 
 
     pub fn add_assembly(&mut self, assembly: Pubkey) -> Result<()> {
@@ -116,6 +120,7 @@ pub struct InitializeGovernmentArgs {
     pub nft_symbol: String,
     pub spl_symbol: String,
     pub collection_price: u64,
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
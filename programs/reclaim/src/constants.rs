@@ -1,13 +1,30 @@
+use anchor_lang::prelude::*;
+
 pub const MAX_EPOCHS: u16 = u16::MAX;
 pub const MAX_WITNESSES: u16 = u16::MAX;
-pub const MAX_MEMBERS: u16 = u16::MAX;
+pub const MAX_MEMBERS: u32 = u32::MAX;
+// Epoch addresses kept in the epoch config: the current one and the previous one
+pub const MAX_RETAINED_EPOCHS: usize = 2;
+// Tolerated drift between witness clocks and the cluster clock
+pub const MAX_CLAIM_CLOCK_DRIFT_SECONDS: i64 = 60;
 
 pub const MAX_WITNESS_URL_SIZE: usize = 50;
 pub const MAX_WITNESS_ADDRESS_SIZE: usize = 42;
 pub const MAX_GROUP_PROVIDER_SIZE: usize = 50;
+pub const MAX_PREDICATES: usize = 8;
+pub const MAX_DAPP_PROVIDERS: usize = 8;
+pub const MAX_PREDICATE_PATH_SIZE: usize = 64;
+pub const MAX_PREDICATE_VALUE_SIZE: usize = 64;
 
 pub const SEED_PREFIX: &[u8] = b"reclaim";
 pub const SEED_EPOCH_CONFIG: &[u8] = b"epoch_config";
 pub const SEED_EPOCH: &[u8] = b"epoch";
 pub const SEED_GROUP: &[u8] = b"group";
+pub const SEED_GROUP_MEMBER: &[u8] = b"group_member";
+pub const SEED_NULLIFIER: &[u8] = b"nullifier";
+pub const SEED_WITNESS_HISTORY: &[u8] = b"witness_history";
 pub const SEED_DAPP: &[u8] = b"dapp";
+
+// Router whose government executors may govern a witness set
+pub const ROUTER_PROGRAM_ID: Pubkey = pubkey!("7aQvq1fEiDXqK36H7mW8MSTGdnHn6XAHDd9pauZwZXGQ");
+pub const SEED_ROUTER_EXECUTOR: &[u8] = b"executor";
@@ -32,4 +32,46 @@ pub enum ReclaimError {
     InvalidWitnessSignature,
     #[msg("Arithmetic Error")]
     ArithmeticPanic,
+    #[msg("Epoch has not expired yet")]
+    EpochNotExpired,
+    #[msg("Epoch no longer accepts proofs")]
+    EpochExpired,
+    #[msg("Invalid Claim Age")]
+    InvalidClaimAge,
+    #[msg("Claim is too old")]
+    ClaimTooOld,
+    #[msg("Claim timestamp is in the future")]
+    ClaimFromFuture,
+    #[msg("Invalid JSON Path")]
+    InvalidJsonPath,
+    #[msg("JSON Path not found in claim parameters")]
+    JsonPathNotFound,
+    #[msg("Invalid Claim Parameters")]
+    InvalidClaimParameters,
+    #[msg("Invalid Claim Predicate")]
+    InvalidPredicate,
+    #[msg("Max Predicates reached")]
+    MaxPredicatesReached,
+    #[msg("Claim Predicate not satisfied")]
+    PredicateNotSatisfied,
+    #[msg("Witness set is managed by governance")]
+    WitnessSetGoverned,
+    #[msg("Witness already exists")]
+    WitnessAlreadyExists,
+    #[msg("Witness not found")]
+    WitnessNotFound,
+    #[msg("Provider not allowed by the dapp")]
+    ProviderNotAllowed,
+    #[msg("Max Dapp Providers reached")]
+    MaxDappProvidersReached,
+    #[msg("Not enough witness signatures")]
+    NotEnoughWitnessSignatures,
+    #[msg("Invalid Fee Recipient")]
+    InvalidFeeRecipient,
+    #[msg("Duplicate Witness Signature")]
+    DuplicateWitnessSignature,
+    #[msg("Witness authority is not the router executor of the government")]
+    InvalidWitnessAuthority,
+    #[msg("Epoch config is not the one the dapp trusts")]
+    EpochConfigMismatch,
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::Witness;
+use crate::state::{Witness, WitnessChange};
 
 #[event]
 pub struct AddEpochEvent {
@@ -30,8 +30,36 @@ pub struct CreateGroupEvent {
     pub provider: String,
 }
 
+#[event]
+pub struct AddMemberEvent {
+    // Group PDA
+    pub group: Pubkey,
+    // Member
+    pub member: Pubkey,
+    // Member record PDA
+    pub group_member: Pubkey,
+}
+
+#[event]
+pub struct RemoveMemberEvent {
+    // Group PDA
+    pub group: Pubkey,
+    // Member
+    pub member: Pubkey,
+}
+
 #[event]
 pub struct CreateDappEvent {
     // Dapp ID
     pub id: u32,
 }
+
+#[event]
+pub struct WitnessChangeEvent {
+    // Epoch Config
+    pub epoch_config: Pubkey,
+    // Applied change
+    pub change: WitnessChange,
+    // First epoch using the change
+    pub effective_epoch_index: u32,
+}
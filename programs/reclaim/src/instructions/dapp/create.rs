@@ -1,4 +1,5 @@
 use crate::constants::*;
+use crate::errors::*;
 use crate::events::CreateDappEvent;
 use crate::state::*;
 use crate::utils::fetch_dapp_id;
@@ -32,6 +33,29 @@ pub struct CreateDapp<'info> {
     )]
     pub group: Account<'info, Group>,
 
+    // Current epoch of the pinned epoch config, whose witness minimum the policy is checked against
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_EPOCH,
+            &epoch_config.epoch_index.to_le_bytes()
+        ],
+        bump = epoch.bump,
+        has_one = epoch_config @ ReclaimError::Unauthorized,
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            SEED_EPOCH_CONFIG,
+            epoch_config.create_key.as_ref()
+        ],
+        bump = epoch_config.bump,
+    )]
+    pub epoch_config: Account<'info, EpochConfig>,
+
     pub create_key: Signer<'info>,
 
     // TODO: Not sure if the dapp creator is required to check with the group creator
@@ -47,6 +71,8 @@ pub fn create(ctx: Context<CreateDapp>, args: CreateDappArgs) -> Result<()> {
 
     let group_root = args.group_root;
 
+    args.policy.check_epoch(&ctx.accounts.epoch)?;
+
     let id = fetch_dapp_id(creator.key, group_root)?;
 
     dapp.set_inner(Dapp {
@@ -56,6 +82,8 @@ pub fn create(ctx: Context<CreateDapp>, args: CreateDappArgs) -> Result<()> {
         group: ctx.accounts.group.key(),
         group_root,
         id,
+        policy: args.policy,
+        stats: DappStats::default(),
     });
 
     dapp.validate()?;
@@ -68,5 +96,6 @@ pub fn create(ctx: Context<CreateDapp>, args: CreateDappArgs) -> Result<()> {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateDappArgs {
     // Currently this is a dummy value, just to replicate ethereum's code structure
-    group_root: u64,
+    pub group_root: u64,
+    pub policy: DappPolicy,
 }
@@ -1,3 +1,7 @@
 pub mod create;
+pub mod stats;
+pub mod update_policy;
 
 pub use create::*;
+pub use stats::*;
+pub use update_policy::*;
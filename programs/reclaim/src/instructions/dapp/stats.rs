@@ -0,0 +1,22 @@
+use crate::constants::*;
+use crate::state::*;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetStatsDapp<'info> {
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            SEED_DAPP,
+            dapp.create_key.as_ref(),
+            dapp.group.as_ref()
+        ],
+        bump = dapp.bump
+    )]
+    pub dapp: Account<'info, Dapp>,
+}
+
+pub fn get_stats(ctx: Context<GetStatsDapp>) -> Result<DappStats> {
+    Ok(ctx.accounts.dapp.stats.clone())
+}
@@ -0,0 +1,62 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdatePolicyDapp<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            SEED_DAPP,
+            dapp.create_key.as_ref(),
+            dapp.group.as_ref()
+        ],
+        bump = dapp.bump,
+        has_one = creator @ ReclaimError::Unauthorized
+    )]
+    pub dapp: Account<'info, Dapp>,
+
+    // Current epoch of the pinned epoch config, whose witness minimum the policy is checked against
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_EPOCH,
+            &epoch_config.epoch_index.to_le_bytes()
+        ],
+        bump = epoch.bump,
+        has_one = epoch_config @ ReclaimError::Unauthorized,
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            SEED_EPOCH_CONFIG,
+            epoch_config.create_key.as_ref()
+        ],
+        bump = epoch_config.bump,
+    )]
+    pub epoch_config: Account<'info, EpochConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn update_policy(ctx: Context<UpdatePolicyDapp>, args: UpdatePolicyDappArgs) -> Result<()> {
+    let dapp = &mut ctx.accounts.dapp;
+
+    args.policy.check_epoch(&ctx.accounts.epoch)?;
+    dapp.policy = args.policy;
+
+    dapp.validate()?;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePolicyDappArgs {
+    pub policy: DappPolicy,
+}
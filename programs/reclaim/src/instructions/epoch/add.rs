@@ -31,7 +31,8 @@ pub struct AddEpoch<'info> {
             epoch_config.create_key.as_ref()
         ],
         bump = epoch_config.bump,
-        has_one = deployer @ ReclaimError::Unauthorized
+        has_one = deployer @ ReclaimError::Unauthorized,
+        constraint = epoch_config.witness_authority.is_none() @ ReclaimError::WitnessSetGoverned
     )]
     pub epoch_config: Account<'info, EpochConfig>,
 
@@ -76,10 +77,7 @@ pub fn add(ctx: Context<AddEpoch>, args: AddEpochArgs) -> Result<()> {
     let epoch_index = epoch_config.epoch_index.checked_add(1).unwrap();
     epoch_config.epoch_index = epoch_index;
 
-    match epoch_config.epochs.binary_search(&epoch.key()) {
-        Ok(_) => return err!(ReclaimError::EpochAlreadyExists),
-        Err(epoch_index) => epoch_config.epochs.insert(epoch_index, epoch.key()),
-    }
+    epoch_config.push_epoch(epoch.key())?;
 
     let required_size = EpochConfig::size(&epoch_config.epochs);
     realloc(
@@ -0,0 +1,144 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::*;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdvanceEpoch<'info> {
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Epoch::size(next_witness_set(&current_epoch, &witness_history).0),
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_EPOCH,
+            &epoch_config.epoch_index.checked_add(1).unwrap().to_le_bytes()
+        ],
+        bump,
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_EPOCH,
+            &epoch_config.epoch_index.to_le_bytes()
+        ],
+        bump = current_epoch.bump,
+        has_one = epoch_config @ ReclaimError::Unauthorized,
+    )]
+    pub current_epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            SEED_EPOCH_CONFIG,
+            epoch_config.create_key.as_ref()
+        ],
+        bump = epoch_config.bump,
+    )]
+    pub epoch_config: Account<'info, EpochConfig>,
+
+    // Required once the witness set is governed; its witnesses are used for the new epoch
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_WITNESS_HISTORY,
+        ],
+        bump = witness_history.bump,
+        has_one = epoch_config @ ReclaimError::Unauthorized,
+    )]
+    pub witness_history: Option<Account<'info, WitnessHistory>>,
+
+    // Anyone can advance an expired epoch
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Witnesses and minimum witnesses for claim of the epoch after `current_epoch`
+fn next_witness_set<'a>(
+    current_epoch: &'a Epoch,
+    witness_history: &'a Option<Account<WitnessHistory>>,
+) -> (&'a [Witness], u8) {
+    match witness_history {
+        Some(history) => (&history.witnesses, history.minimum_witnesses_for_claim),
+        None => (&current_epoch.witnesses, current_epoch.minimum_witnesses_for_claim),
+    }
+}
+
+/// Roll over to a new epoch once the current one has expired, carrying over its witnesses
+/// or, for a governed witness set, applying the pending changes
+pub fn advance(ctx: Context<AdvanceEpoch>) -> Result<()> {
+    let epoch_config = &mut ctx.accounts.epoch_config;
+    let current_epoch = &ctx.accounts.current_epoch;
+    let system_program = &ctx.accounts.system_program;
+    let rent_payer = &ctx.accounts.rent_payer;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        current_timestamp >= current_epoch.expired_at,
+        ReclaimError::EpochNotExpired
+    );
+
+    // Epoch mutations
+    let epoch_index = epoch_config.epoch_index.checked_add(1).unwrap();
+    let expiry_timestamp = current_timestamp
+        .checked_add(i64::try_from(epoch_config.epoch_duration_seconds).unwrap())
+        .unwrap();
+    require!(
+        epoch_config.witness_authority.is_none() || ctx.accounts.witness_history.is_some(),
+        ReclaimError::WitnessSetGoverned
+    );
+    let (witnesses, minimum_witnesses_for_claim) =
+        next_witness_set(current_epoch, &ctx.accounts.witness_history);
+    let witnesses = witnesses.to_vec();
+
+    let epoch = &mut ctx.accounts.epoch;
+    epoch.set_inner(Epoch {
+        bump: ctx.bumps.epoch,
+        created_at: current_timestamp,
+        expired_at: expiry_timestamp,
+        epoch_config: epoch_config.key(),
+        index: epoch_index,
+        minimum_witnesses_for_claim,
+        witnesses: witnesses.clone(),
+    });
+
+    epoch.validate()?;
+
+    // Epoch config mutations
+    epoch_config.epoch_index = epoch_index;
+    epoch_config.push_epoch(epoch.key())?;
+
+    // Pruning keeps the list bounded, so this only grows the account until it is full
+    let required_size = EpochConfig::size(&epoch_config.epochs);
+    realloc(
+        epoch_config.to_account_info(),
+        required_size,
+        rent_payer.to_account_info(),
+        system_program.to_account_info(),
+    )?;
+
+    epoch_config.validate()?;
+
+    emit!(AddEpochEvent {
+        bump: ctx.bumps.epoch,
+        created_at: current_timestamp,
+        expired_at: expiry_timestamp,
+        epoch_config: epoch_config.key(),
+        index: epoch_index,
+        minimum_witnesses_for_claim,
+        witnesses,
+    });
+
+    Ok(())
+}
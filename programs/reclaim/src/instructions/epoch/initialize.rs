@@ -41,11 +41,19 @@ pub fn initialize(
         ReclaimError::InvalidEpochDuration
     );
 
+    require!(
+        args.max_claim_age_seconds > 0,
+        ReclaimError::InvalidClaimAge
+    );
+
     epoch_config.set_inner(EpochConfig {
         bump: ctx.bumps.epoch_config,
         create_key: create_key.key(),
         deployer: deployer.key(),
         epoch_duration_seconds: args.epoch_duration_seconds,
+        grace_period_seconds: args.grace_period_seconds,
+        max_claim_age_seconds: args.max_claim_age_seconds,
+        witness_authority: None,
         epoch_index: 0,
         epochs: vec![],
     });
@@ -56,4 +64,6 @@ pub fn initialize(
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeEpochConfigArgs {
     pub epoch_duration_seconds: u64,
+    pub grace_period_seconds: u64,
+    pub max_claim_age_seconds: u64,
 }
@@ -1,7 +1,9 @@
 pub mod add;
+pub mod advance;
 pub mod change_epoch_index;
 pub mod initialize;
 
 pub use add::*;
+pub use advance::*;
 pub use change_epoch_index::*;
 pub use initialize::*;
@@ -1,5 +1,6 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use crate::utils::*;
 
@@ -19,6 +20,20 @@ pub struct AddMemberGroup<'info> {
     )]
     pub group: Account<'info, Group>,
 
+    #[account(
+        init,
+        payer = rent_payer,
+        space = GroupMember::size(),
+        seeds = [
+            SEED_PREFIX,
+            group.key().as_ref(),
+            SEED_GROUP_MEMBER,
+            args.claim_info.context_address.as_ref(),
+        ],
+        bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
     #[account(
         seeds = [
             SEED_PREFIX,
@@ -53,9 +68,6 @@ pub struct AddMemberGroup<'info> {
 pub fn add_member(ctx: Context<AddMemberGroup>, args: AddMemberGroupArgs) -> Result<()> {
     let epoch = &ctx.accounts.epoch;
     let rent_payer = &ctx.accounts.rent_payer;
-    let system_program = &ctx.accounts.system_program;
-
-    let group = &mut ctx.accounts.group;
 
     let SignedClaim {
         claim_data,
@@ -66,6 +78,14 @@ pub fn add_member(ctx: Context<AddMemberGroup>, args: AddMemberGroupArgs) -> Res
         context_address, ..
     } = args.claim_info;
 
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .epoch_config
+        .check_epoch_accepts_proofs(epoch, current_timestamp)?;
+    ctx.accounts
+        .epoch_config
+        .check_claim_freshness(epoch, &claim_data, current_timestamp)?;
+
     let received_identifier = append_0x(&hex::encode(claim_data.identifier));
     let expected_identifier = hash_claim_info(&args.claim_info);
 
@@ -74,56 +94,57 @@ pub fn add_member(ctx: Context<AddMemberGroup>, args: AddMemberGroupArgs) -> Res
         ReclaimError::InvalidIdentifier
     );
 
-    match group.members.binary_search(&context_address) {
-        Ok(_) => return err!(ReclaimError::MemberAlreadyExists),
-        Err(member_index) => {
-            /* Selecting witnesses */
-            let minimum_witnesses = usize::from(epoch.minimum_witnesses_for_claim);
-            let witness_serialized_data = claim_data.serialize_for_witness(minimum_witnesses);
-
-            let hashed_witness_serialized_data = hash_content(&witness_serialized_data);
-
-            let selected_witnesses = select_witness_addresses(
-                minimum_witnesses,
-                &hashed_witness_serialized_data,
-                &epoch.witnesses,
-            )?;
-
-            msg!("Selected Witnesses: {:?}", selected_witnesses);
-
-            /* Recovering witnesses from signatures */
-            let recovery_serialized_data = claim_data.serialize_for_recovery();
-            let hashed_recovery_serialized_data =
-                prepare_for_verification(&recovery_serialized_data);
-
-            let recovered_witnesses =
-                recover_witness_addresses(&hashed_recovery_serialized_data, &signatures)?;
-
-            msg!("Recovered Witnesses: {:?}", recovered_witnesses);
-
-            /* Checking selected vs recovered witnesses */
-            for recovered_witness in recovered_witnesses {
-                require!(
-                    selected_witnesses.contains(&recovered_witness),
-                    ReclaimError::InvalidWitnessSignature
-                );
-            }
-
-            group.members.insert(member_index, context_address);
-        }
-    }
-
-    /* Reallocating space */
-    let required_size = Group::size(&group.members);
-    realloc(
-        group.to_account_info(),
-        required_size,
-        rent_payer.to_account_info(),
-        system_program.to_account_info(),
+    /* Selecting witnesses */
+    let minimum_witnesses = usize::from(epoch.minimum_witnesses_for_claim);
+    let witness_serialized_data = claim_data.serialize_for_witness(minimum_witnesses);
+
+    let hashed_witness_serialized_data = hash_content(&witness_serialized_data);
+
+    let selected_witnesses = select_witness_addresses(
+        minimum_witnesses,
+        &hashed_witness_serialized_data,
+        &epoch.witnesses,
     )?;
 
+    msg!("Selected Witnesses: {:?}", selected_witnesses);
+
+    /* Recovering witnesses from signatures */
+    let recovery_serialized_data = claim_data.serialize_for_recovery();
+    let hashed_recovery_serialized_data = prepare_for_verification(&recovery_serialized_data);
+
+    let recovered_witnesses =
+        recover_witness_addresses(&hashed_recovery_serialized_data, &signatures)?;
+
+    msg!("Recovered Witnesses: {:?}", recovered_witnesses);
+
+    /* Checking selected vs recovered witnesses */
+    check_witness_signatures(&selected_witnesses, &recovered_witnesses)?;
+
+    /* Recording membership */
+    let group = &mut ctx.accounts.group;
+    let group_member = &mut ctx.accounts.group_member;
+
+    group_member.set_inner(GroupMember {
+        bump: ctx.bumps.group_member,
+        group: group.key(),
+        member: context_address,
+        rent_payer: rent_payer.key(),
+        added_at: current_timestamp,
+    });
+
+    group.member_count = group
+        .member_count
+        .checked_add(1)
+        .ok_or(ReclaimError::MaxMembersReached)?;
+
     group.validate()?;
 
+    emit!(AddMemberEvent {
+        group: group.key(),
+        member: context_address,
+        group_member: group_member.key(),
+    });
+
     Ok(())
 }
 
@@ -11,7 +11,7 @@ pub struct CreateGroup<'info> {
     #[account(
         init,
         payer = creator,
-        space = Group::size(&[]),
+        space = Group::size(),
         seeds = [
             SEED_PREFIX,
             SEED_GROUP,
@@ -37,7 +37,7 @@ pub fn create(ctx: Context<CreateGroup>, args: CreateGroupArgs) -> Result<()> {
         bump: ctx.bumps.group,
         creator: creator.key(),
         provider: args.provider.clone(),
-        members: vec![],
+        member_count: 0,
     });
 
     group.validate()?;
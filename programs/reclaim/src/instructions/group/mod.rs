@@ -1,5 +1,9 @@
+pub mod add_member;
 pub mod create;
+pub mod remove_member;
 pub mod verify_proof;
 
+pub use add_member::*;
 pub use create::*;
+pub use remove_member::*;
 pub use verify_proof::*;
@@ -0,0 +1,63 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveMemberGroup<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            SEED_GROUP,
+            group.provider.as_bytes(),
+        ],
+        bump = group.bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            group.key().as_ref(),
+            SEED_GROUP_MEMBER,
+            group_member.member.as_ref(),
+        ],
+        bump = group_member.bump,
+        has_one = group @ ReclaimError::Unauthorized,
+        has_one = rent_payer @ ReclaimError::Unauthorized,
+        close = rent_payer
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    // Either the member leaving or the group creator
+    #[account(
+        constraint = signer.key().eq(&group_member.member)
+            || signer.key().eq(&group.creator) @ ReclaimError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Checked against the member record; receives its rent
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn remove_member(ctx: Context<RemoveMemberGroup>) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    let member = ctx.accounts.group_member.member;
+
+    group.member_count = group
+        .member_count
+        .checked_sub(1)
+        .ok_or(ReclaimError::ArithmeticPanic)?;
+
+    emit!(RemoveMemberEvent {
+        group: group.key(),
+        member,
+    });
+
+    Ok(())
+}
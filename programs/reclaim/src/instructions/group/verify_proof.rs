@@ -4,10 +4,36 @@ use crate::state::*;
 use crate::utils::*;
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 #[derive(Accounts)]
 #[instruction(args: VerifyProofArgs)]
 pub struct VerifyProof<'info> {
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            SEED_GROUP,
+            args.claim_info.provider.as_bytes(),
+        ],
+        bump = group.bump
+    )]
+    pub group: Account<'info, Group>,
+
+    // Creating this fails if the signed claim was already verified in the group
+    #[account(
+        init,
+        payer = signer,
+        space = ClaimNullifier::size(),
+        seeds = [
+            SEED_PREFIX,
+            group.key().as_ref(),
+            SEED_NULLIFIER,
+            &args.signed_claim.claim_data.claim_hash(),
+        ],
+        bump
+    )]
+    pub nullifier: Account<'info, ClaimNullifier>,
+
     #[account(
         seeds = [
             SEED_PREFIX,
@@ -31,9 +57,29 @@ pub struct VerifyProof<'info> {
     pub epoch_config: Account<'info, EpochConfig>,
 
     #[account(
+        mut,
         constraint = signer.key().eq(&args.claim_info.context_address) @ ReclaimError::Unauthorized
     )]
     pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+
+    // Scopes the verification to a dapp, enforcing its policy and recording usage
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            SEED_DAPP,
+            dapp.create_key.as_ref(),
+            dapp.group.as_ref()
+        ],
+        bump = dapp.bump,
+        has_one = group @ ReclaimError::Unauthorized,
+    )]
+    pub dapp: Option<Account<'info, Dapp>>,
+
+    /// CHECK: Checked against the dapp fee recipient
+    #[account(mut)]
+    pub fee_recipient: Option<UncheckedAccount<'info>>,
 }
 
 pub fn verify_proof(ctx: Context<VerifyProof>, args: VerifyProofArgs) -> Result<()> {
@@ -44,6 +90,14 @@ pub fn verify_proof(ctx: Context<VerifyProof>, args: VerifyProofArgs) -> Result<
         signatures,
     } = args.signed_claim;
 
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .epoch_config
+        .check_epoch_accepts_proofs(epoch, current_timestamp)?;
+    ctx.accounts
+        .epoch_config
+        .check_claim_freshness(epoch, &claim_data, current_timestamp)?;
+
     let received_identifier = append_0x(&hex::encode(claim_data.identifier));
     let expected_identifier = hash_claim_info(&args.claim_info);
 
@@ -75,13 +129,66 @@ pub fn verify_proof(ctx: Context<VerifyProof>, args: VerifyProofArgs) -> Result<
     msg!("Recovered Witnesses: {:?}", recovered_witnesses);
 
     /* Checking selected vs recovered witnesses */
-    for recovered_witness in recovered_witnesses {
-        require!(
-            selected_witnesses.contains(&recovered_witness),
-            ReclaimError::InvalidWitnessSignature
+    check_witness_signatures(&selected_witnesses, &recovered_witnesses)?;
+
+    /* Applying the dapp policy */
+    if let Some(dapp) = ctx.accounts.dapp.as_mut() {
+        require_keys_eq!(
+            ctx.accounts.epoch_config.key(),
+            dapp.policy.epoch_config,
+            ReclaimError::EpochConfigMismatch
         );
+        dapp.policy.check(
+            &args.claim_info.provider,
+            i64::from(claim_data.timestamp),
+            current_timestamp,
+            recovered_witnesses.len(),
+        )?;
+        check_predicates(&dapp.policy.predicates, &args.claim_info.parameters)?;
+
+        if let Some(fee) = &dapp.policy.fee {
+            let fee_recipient = ctx
+                .accounts
+                .fee_recipient
+                .as_ref()
+                .ok_or(ReclaimError::InvalidFeeRecipient)?;
+            require!(
+                fee_recipient.key().eq(&fee.recipient),
+                ReclaimError::InvalidFeeRecipient
+            );
+
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.signer.to_account_info(),
+                        to: fee_recipient.to_account_info(),
+                    },
+                ),
+                fee.lamports,
+            )?;
+
+            dapp.stats.fees_collected = dapp
+                .stats
+                .fees_collected
+                .checked_add(fee.lamports)
+                .ok_or(ReclaimError::ArithmeticPanic)?;
+        }
+
+        dapp.stats.verification_count = dapp
+            .stats
+            .verification_count
+            .checked_add(1)
+            .ok_or(ReclaimError::ArithmeticPanic)?;
+        dapp.stats.last_verified_at = current_timestamp;
     }
 
+    ctx.accounts.nullifier.set_inner(ClaimNullifier {
+        bump: ctx.bumps.nullifier,
+        claim_hash: claim_data.claim_hash(),
+        used_at: current_timestamp,
+    });
+
     Ok(())
 }
 
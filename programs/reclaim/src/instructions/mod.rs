@@ -1,7 +1,9 @@
 pub mod dapp;
 pub mod epoch;
 pub mod group;
+pub mod witness;
 
 pub use dapp::*;
 pub use epoch::*;
 pub use group::*;
+pub use witness::*;
@@ -0,0 +1,100 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeWitnessGovernance<'info> {
+    #[account(
+        init,
+        payer = deployer,
+        space = WitnessHistory::size(&current_epoch.witnesses, &[]),
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_WITNESS_HISTORY,
+        ],
+        bump
+    )]
+    pub witness_history: Account<'info, WitnessHistory>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_EPOCH,
+            &epoch_config.epoch_index.to_le_bytes()
+        ],
+        bump = current_epoch.bump,
+        has_one = epoch_config @ ReclaimError::Unauthorized,
+    )]
+    pub current_epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            SEED_EPOCH_CONFIG,
+            epoch_config.create_key.as_ref()
+        ],
+        bump = epoch_config.bump,
+        has_one = deployer @ ReclaimError::Unauthorized
+    )]
+    pub epoch_config: Account<'info, EpochConfig>,
+
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+    // Router executor of the government instance taking over, signing an approved decision
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Hand the witness set over to the router executor of a registered government instance.
+/// The deployer can no longer add epochs with arbitrary witnesses afterwards.
+pub fn initialize(
+    ctx: Context<InitializeWitnessGovernance>,
+    args: InitializeWitnessGovernanceArgs,
+) -> Result<()> {
+    let epoch_config = &mut ctx.accounts.epoch_config;
+    let current_epoch = &ctx.accounts.current_epoch;
+
+    require!(
+        epoch_config.witness_authority.is_none(),
+        ReclaimError::WitnessSetGoverned
+    );
+
+    // Only the router signs as an executor, and only for its registered government instances
+    let (executor, _) = Pubkey::find_program_address(
+        &[
+            SEED_ROUTER_EXECUTOR,
+            args.router_state.as_ref(),
+            args.government_state.as_ref(),
+        ],
+        &ROUTER_PROGRAM_ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        executor,
+        ReclaimError::InvalidWitnessAuthority
+    );
+
+    ctx.accounts.witness_history.set_inner(WitnessHistory {
+        bump: ctx.bumps.witness_history,
+        epoch_config: epoch_config.key(),
+        witnesses: current_epoch.witnesses.clone(),
+        minimum_witnesses_for_claim: current_epoch.minimum_witnesses_for_claim,
+        changes: vec![],
+    });
+
+    epoch_config.witness_authority = Some(executor);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeWitnessGovernanceArgs {
+    pub router_state: Pubkey,
+    // Government instance registered in the router, e.g. a kingdom's monarch
+    pub government_state: Pubkey,
+}
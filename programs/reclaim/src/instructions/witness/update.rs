@@ -0,0 +1,81 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::*;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateWitnessSet<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            epoch_config.key().as_ref(),
+            SEED_WITNESS_HISTORY,
+        ],
+        bump = witness_history.bump,
+        has_one = epoch_config @ ReclaimError::Unauthorized,
+    )]
+    pub witness_history: Account<'info, WitnessHistory>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            SEED_EPOCH_CONFIG,
+            epoch_config.create_key.as_ref()
+        ],
+        bump = epoch_config.bump,
+        constraint = epoch_config.witness_authority == Some(authority.key()) @ ReclaimError::Unauthorized
+    )]
+    pub epoch_config: Account<'info, EpochConfig>,
+
+    // Router executor of the governing government instance, signing an approved decision
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Change the witness set used from the next epoch on
+pub fn update(ctx: Context<UpdateWitnessSet>, args: UpdateWitnessSetArgs) -> Result<()> {
+    let epoch_config = &ctx.accounts.epoch_config;
+    let rent_payer = &ctx.accounts.rent_payer;
+    let system_program = &ctx.accounts.system_program;
+
+    let witness_history = &mut ctx.accounts.witness_history;
+
+    witness_history.apply(&args.change)?;
+    witness_history.validate()?;
+
+    let effective_epoch_index = epoch_config.epoch_index.checked_add(1).unwrap();
+    witness_history.changes.push(WitnessChangeRecord {
+        change: args.change.clone(),
+        effective_epoch_index,
+        recorded_at: Clock::get()?.unix_timestamp,
+    });
+
+    /* Reallocating space */
+    let required_size = WitnessHistory::size(&witness_history.witnesses, &witness_history.changes);
+    realloc(
+        witness_history.to_account_info(),
+        required_size,
+        rent_payer.to_account_info(),
+        system_program.to_account_info(),
+    )?;
+
+    emit!(WitnessChangeEvent {
+        epoch_config: epoch_config.key(),
+        change: args.change,
+        effective_epoch_index,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateWitnessSetArgs {
+    pub change: WitnessChange,
+}
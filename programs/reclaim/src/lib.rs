@@ -3,6 +3,7 @@
 
 use anchor_lang::prelude::*;
 use instructions::*;
+use state::DappStats;
 
 pub mod constants;
 pub mod errors;
@@ -35,10 +36,36 @@ pub mod reclaim {
         epoch::add(ctx, args)
     }
 
+    pub fn advance_epoch(ctx: Context<AdvanceEpoch>) -> Result<()> {
+        epoch::advance(ctx)
+    }
+
+    pub fn initialize_witness_governance(
+        ctx: Context<InitializeWitnessGovernance>,
+        args: InitializeWitnessGovernanceArgs,
+    ) -> Result<()> {
+        witness::initialize(ctx, args)
+    }
+
+    pub fn update_witness_set(
+        ctx: Context<UpdateWitnessSet>,
+        args: UpdateWitnessSetArgs,
+    ) -> Result<()> {
+        witness::update(ctx, args)
+    }
+
     pub fn create_group(ctx: Context<CreateGroup>, args: CreateGroupArgs) -> Result<()> {
         group::create(ctx, args)
     }
 
+    pub fn add_member(ctx: Context<AddMemberGroup>, args: AddMemberGroupArgs) -> Result<()> {
+        group::add_member(ctx, args)
+    }
+
+    pub fn remove_member(ctx: Context<RemoveMemberGroup>) -> Result<()> {
+        group::remove_member(ctx)
+    }
+
     pub fn verify_proof(ctx: Context<VerifyProof>, args: VerifyProofArgs) -> Result<()> {
         group::verify_proof(ctx, args)
     }
@@ -46,4 +73,15 @@ pub mod reclaim {
     pub fn create_dapp(ctx: Context<CreateDapp>, args: CreateDappArgs) -> Result<()> {
         dapp::create(ctx, args)
     }
+
+    pub fn update_dapp_policy(
+        ctx: Context<UpdatePolicyDapp>,
+        args: UpdatePolicyDappArgs,
+    ) -> Result<()> {
+        dapp::update_policy(ctx, args)
+    }
+
+    pub fn get_dapp_stats(ctx: Context<GetStatsDapp>) -> Result<DappStats> {
+        dapp::get_stats(ctx)
+    }
 }
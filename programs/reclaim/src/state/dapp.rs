@@ -1,5 +1,134 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::{validate_predicates, ClaimPredicate, Epoch};
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct DappFee {
+    // Lamports charged per verification
+    pub lamports: u64,
+    // Fee recipient
+    pub recipient: Pubkey,
+}
+
+impl DappFee {
+    pub fn size() -> usize {
+        8 + // Lamports
+        32 // Recipient
+    }
+}
+
+/// Requirements a proof must meet to be verified for a dapp
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct DappPolicy {
+    // Epoch config whose witnesses the dapp trusts
+    pub epoch_config: Pubkey,
+    // Accepted providers, any provider when empty
+    pub allowed_providers: Vec<String>,
+    // Oldest claim accepted, on top of the epoch config limit
+    pub max_claim_age_seconds: Option<u64>,
+    // Witness signatures required, at most the epoch minimum every proof carries
+    pub required_witnesses: u8,
+    // Charged to the signer on every verification
+    pub fee: Option<DappFee>,
+    // Rules the proven data must satisfy
+    pub predicates: Vec<ClaimPredicate>,
+}
+
+impl DappPolicy {
+    pub fn size() -> usize {
+        32 + // Epoch Config
+        4 + // Vector discriminator
+        (MAX_DAPP_PROVIDERS * (4 + MAX_GROUP_PROVIDER_SIZE)) +
+        1 + 8 + // Max Claim Age Seconds
+        1 + // Required Witnesses
+        1 + DappFee::size() +
+        4 + // Vector discriminator
+        (MAX_PREDICATES * ClaimPredicate::size())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.allowed_providers.len() > MAX_DAPP_PROVIDERS {
+            return err!(ReclaimError::MaxDappProvidersReached);
+        }
+
+        for provider in &self.allowed_providers {
+            if provider.len() > MAX_GROUP_PROVIDER_SIZE {
+                return err!(ReclaimError::ProviderTooLong);
+            }
+        }
+
+        if self.max_claim_age_seconds == Some(0) {
+            return err!(ReclaimError::InvalidClaimAge);
+        }
+
+        validate_predicates(&self.predicates)
+    }
+
+    /// The epoch must belong to the pinned config. Proofs carry exactly the epoch's minimum
+    /// of witness signatures, so a policy requiring more could never be met
+    pub fn check_epoch(&self, epoch: &Epoch) -> Result<()> {
+        require_keys_eq!(
+            epoch.epoch_config,
+            self.epoch_config,
+            ReclaimError::EpochConfigMismatch
+        );
+        require!(
+            self.required_witnesses <= epoch.minimum_witnesses_for_claim,
+            ReclaimError::InvalidWitnessClaimCount
+        );
+
+        Ok(())
+    }
+
+    pub fn allows_provider(&self, provider: &str) -> bool {
+        self.allowed_providers.is_empty() || self.allowed_providers.iter().any(|p| p == provider)
+    }
+
+    /// Check a proof against the policy, given how many distinct witnesses signed it
+    pub fn check(&self, provider: &str, claimed_at: i64, now: i64, witness_count: usize) -> Result<()> {
+        require!(
+            self.allows_provider(provider),
+            ReclaimError::ProviderNotAllowed
+        );
+
+        if let Some(max_claim_age_seconds) = self.max_claim_age_seconds {
+            let max_claim_age = i64::try_from(max_claim_age_seconds)
+                .map_err(|_| ReclaimError::ArithmeticPanic)?;
+            require!(
+                now.saturating_sub(claimed_at) <= max_claim_age,
+                ReclaimError::ClaimTooOld
+            );
+        }
+
+        require!(
+            witness_count >= usize::from(self.required_witnesses),
+            ReclaimError::NotEnoughWitnessSignatures
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub struct DappStats {
+    // Proofs verified for the dapp
+    pub verification_count: u64,
+    // Lamports paid to the fee recipient
+    pub fees_collected: u64,
+    // Last verification timestamp
+    pub last_verified_at: i64,
+}
+
+impl DappStats {
+    pub fn size() -> usize {
+        8 + // Verification Count
+        8 + // Fees Collected
+        8 // Last Verified At
+    }
+}
+
 #[account]
 pub struct Dapp {
     // Dapp ID
@@ -14,6 +143,10 @@ pub struct Dapp {
     pub creator: Pubkey,
     // Group address
     pub group: Pubkey,
+    // Verification policy
+    pub policy: DappPolicy,
+    // Usage stats
+    pub stats: DappStats,
 }
 
 impl Dapp {
@@ -24,10 +157,12 @@ impl Dapp {
         1 + // Bump
         32 + // Create key
         32 + // Creator
-        32 // Group
+        32 + // Group
+        DappPolicy::size() +
+        DappStats::size()
     }
 
     pub fn validate(&self) -> Result<()> {
-        Ok(())
+        self.policy.validate()
     }
 }
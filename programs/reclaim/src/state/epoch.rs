@@ -10,6 +10,12 @@ pub struct Witness {
     pub url: String,
 }
 
+impl Witness {
+    pub fn size() -> usize {
+        (4 + MAX_WITNESS_ADDRESS_SIZE) + (4 + MAX_WITNESS_URL_SIZE)
+    }
+}
+
 #[account]
 pub struct Epoch {
     // Bump for the address
@@ -30,7 +36,7 @@ pub struct Epoch {
 
 impl Epoch {
     pub fn size(witnesses: &[Witness]) -> usize {
-        let witness_size = (4 + MAX_WITNESS_ADDRESS_SIZE) + (4 + MAX_WITNESS_URL_SIZE);
+        let witness_size = Witness::size();
         8 + // Anchor discriminator
         1 + // Bump
         32 + // Epoch Config
@@ -43,25 +49,33 @@ impl Epoch {
     }
 
     pub fn validate(&self) -> Result<()> {
-        let witnesses = &self.witnesses;
-        if witnesses.len().gt(&usize::from(MAX_WITNESSES)) {
-            return err!(ReclaimError::MaxWitnessesReached);
-        }
+        validate_witnesses(&self.witnesses, self.minimum_witnesses_for_claim)
+    }
+}
 
-        for witness in witnesses {
-            if !is_valid_ethereum_address(&witness.address) {
-                return err!(ReclaimError::InvalidWitness);
-            }
+pub fn validate_witnesses(witnesses: &[Witness], minimum_witnesses_for_claim: u8) -> Result<()> {
+    if witnesses.len().gt(&usize::from(MAX_WITNESSES)) {
+        return err!(ReclaimError::MaxWitnessesReached);
+    }
 
-            if witness.url.len().gt(&MAX_WITNESS_URL_SIZE) {
-                return err!(ReclaimError::HostTooLong);
-            }
+    for (index, witness) in witnesses.iter().enumerate() {
+        if !is_valid_ethereum_address(&witness.address) {
+            return err!(ReclaimError::InvalidWitness);
         }
 
-        if usize::from(self.minimum_witnesses_for_claim) > witnesses.len() {
-            return err!(ReclaimError::InvalidWitnessClaimCount);
+        if witnesses[..index].iter().any(|w| w.address == witness.address) {
+            return err!(ReclaimError::WitnessAlreadyExists);
         }
 
-        Ok(())
+        if witness.url.len().gt(&MAX_WITNESS_URL_SIZE) {
+            return err!(ReclaimError::HostTooLong);
+        }
     }
+
+    // A claim needs at least one signature, and no more than there are witnesses
+    if minimum_witnesses_for_claim == 0 || usize::from(minimum_witnesses_for_claim) > witnesses.len() {
+        return err!(ReclaimError::InvalidWitnessClaimCount);
+    }
+
+    Ok(())
 }
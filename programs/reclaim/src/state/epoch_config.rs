@@ -1,4 +1,8 @@
-use crate::{constants::MAX_EPOCHS, errors::ReclaimError};
+use crate::{
+    constants::{MAX_CLAIM_CLOCK_DRIFT_SECONDS, MAX_EPOCHS, MAX_RETAINED_EPOCHS},
+    errors::ReclaimError,
+    state::{ClaimData, Epoch},
+};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -11,9 +15,15 @@ pub struct EpochConfig {
     pub deployer: Pubkey,
     // Duration of each epoch
     pub epoch_duration_seconds: u64,
+    // How long an epoch keeps accepting proofs after it expires
+    pub grace_period_seconds: u64,
+    // Oldest claim, by its witness timestamp, that is still accepted
+    pub max_claim_age_seconds: u64,
+    // Router executor of the government that manages the witness set, once governed
+    pub witness_authority: Option<Pubkey>,
     // Current Epoch Index
     pub epoch_index: u32,
-    // Registered Epochs
+    // Registered Epochs, oldest first
     pub epochs: Vec<Pubkey>,
 }
 
@@ -24,6 +34,9 @@ impl EpochConfig {
         32 + // Create key
         32 + // Deployer
         8 + // Epoch Duration Seconds
+        8 + // Grace Period Seconds
+        8 + // Max Claim Age Seconds
+        1 + 32 + // Witness Authority
         4 + // Epoch Index
         4 + // Vector Discriminator
         (epochs.len() * 32) // Epoch addresses
@@ -35,4 +48,54 @@ impl EpochConfig {
         }
         Ok(())
     }
+
+    /// Register a new current epoch, forgetting the ones older than the previous epoch
+    pub fn push_epoch(&mut self, epoch: Pubkey) -> Result<()> {
+        if self.epochs.contains(&epoch) {
+            return err!(ReclaimError::EpochAlreadyExists);
+        }
+
+        self.epochs.push(epoch);
+
+        let excess = self.epochs.len().saturating_sub(MAX_RETAINED_EPOCHS);
+        self.epochs.drain(..excess);
+
+        Ok(())
+    }
+
+    /// Proofs are accepted for the current epoch and the previous one,
+    /// each until its expiry plus the grace period
+    pub fn check_epoch_accepts_proofs(&self, epoch: &Epoch, now: i64) -> Result<()> {
+        let is_recent = epoch.index.saturating_add(1) >= self.epoch_index;
+        let grace_period = i64::try_from(self.grace_period_seconds)
+            .map_err(|_| ReclaimError::ArithmeticPanic)?;
+        let valid_until = epoch.expired_at.saturating_add(grace_period);
+
+        require!(is_recent && now <= valid_until, ReclaimError::EpochExpired);
+
+        Ok(())
+    }
+
+    /// Reject claims signed for another epoch, too long ago or in the future
+    pub fn check_claim_freshness(&self, epoch: &Epoch, claim_data: &ClaimData, now: i64) -> Result<()> {
+        require!(
+            claim_data.epoch_index == epoch.index,
+            ReclaimError::InvalidEpochIndex
+        );
+
+        let claimed_at = i64::from(claim_data.timestamp);
+        require!(
+            claimed_at <= now.saturating_add(MAX_CLAIM_CLOCK_DRIFT_SECONDS),
+            ReclaimError::ClaimFromFuture
+        );
+
+        let max_claim_age = i64::try_from(self.max_claim_age_seconds)
+            .map_err(|_| ReclaimError::ArithmeticPanic)?;
+        require!(
+            now.saturating_sub(claimed_at) <= max_claim_age,
+            ReclaimError::ClaimTooOld
+        );
+
+        Ok(())
+    }
 }
@@ -13,22 +13,21 @@ pub struct Group {
     pub creator: Pubkey,
     // Provider
     pub provider: String,
-    // Members of the group
-    pub members: Vec<Pubkey>,
+    // Number of members, each stored in its own `GroupMember` account
+    pub member_count: u32,
     // TODO: Need to know if a group can be created only under one epoch
     // i.e Only a given epoch can be accesed to add members or not.
 }
 
 impl Group {
-    pub fn size(members: &[Pubkey]) -> usize {
+    pub fn size() -> usize {
         8 + // Anchor discriminator
         4 + // Group ID
         1 + // Bump
         32 + // Creator
         4 + // String discriminator
         MAX_GROUP_PROVIDER_SIZE +
-        4 + // Vector discriminator
-        (members.len() * 32)
+        4 // Member count
     }
 
     pub fn validate(&self) -> Result<()> {
@@ -36,9 +35,57 @@ impl Group {
             return err!(ReclaimError::ProviderTooLong);
         }
 
-        if self.members.len().gt(&usize::from(MAX_MEMBERS)) {
+        if self.member_count.gt(&MAX_MEMBERS) {
             return err!(ReclaimError::MaxMembersReached);
         }
+
         Ok(())
     }
 }
+
+/// Membership of one address in a group. Its existence is the membership.
+#[account]
+pub struct GroupMember {
+    // Bump for the address
+    pub bump: u8,
+    // Group
+    pub group: Pubkey,
+    // Member
+    pub member: Pubkey,
+    // Receives the rent back when the member is removed
+    pub rent_payer: Pubkey,
+    // Membership creation timestamp
+    pub added_at: i64,
+}
+
+impl GroupMember {
+    pub fn size() -> usize {
+        8 + // Anchor discriminator
+        1 + // Bump
+        32 + // Group
+        32 + // Member
+        32 + // Rent payer
+        8 // Added At
+    }
+}
+
+/// Marks a signed claim as used within a group, so it verifies only once.
+/// Keyed by the signed message, so a fresh attestation of the same claim verifies again.
+#[account]
+pub struct ClaimNullifier {
+    // Bump for the address
+    pub bump: u8,
+    // Hash of the signed claim
+    pub claim_hash: [u8; 32],
+    // Verification timestamp
+    pub used_at: i64,
+}
+
+impl ClaimNullifier {
+    pub fn size() -> usize {
+        8 + // Anchor discriminator
+        1 + // Bump
+        32 + // Claim Hash
+        8 // Used At
+    }
+}
@@ -2,10 +2,14 @@ pub mod dapp;
 pub mod epoch;
 pub mod epoch_config;
 pub mod group;
+pub mod predicate;
 pub mod proof;
+pub mod witness_history;
 
 pub use dapp::*;
 pub use epoch::*;
 pub use epoch_config::*;
 pub use group::*;
+pub use predicate::*;
 pub use proof::*;
+pub use witness_history::*;
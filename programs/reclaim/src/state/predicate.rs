@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::utils::extract_json_value;
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PredicateOperator {
+    Equal,
+    NotEqual,
+    // Numeric comparisons
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    // Substring match
+    Contains,
+}
+
+/// A rule on the proven data, e.g. `followers >= 1000`, evaluated over `ClaimInfo.parameters`
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ClaimPredicate {
+    // JSON path into the claim parameters
+    pub path: String,
+    // Comparison
+    pub operator: PredicateOperator,
+    // Expected value, compared as an integer for numeric operators
+    pub value: String,
+}
+
+impl ClaimPredicate {
+    pub fn size() -> usize {
+        4 + // String discriminator
+        MAX_PREDICATE_PATH_SIZE +
+        1 + // Operator
+        4 + // String discriminator
+        MAX_PREDICATE_VALUE_SIZE
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.path.is_empty()
+            || self.path.len() > MAX_PREDICATE_PATH_SIZE
+            || self.value.len() > MAX_PREDICATE_VALUE_SIZE
+        {
+            return err!(ReclaimError::InvalidPredicate);
+        }
+
+        if self.is_numeric() && parse_number(&self.value).is_err() {
+            return err!(ReclaimError::InvalidPredicate);
+        }
+
+        Ok(())
+    }
+
+    pub fn evaluate(&self, parameters: &str) -> Result<bool> {
+        let actual = extract_json_value(parameters, &self.path)?;
+
+        if self.is_numeric() {
+            let actual = parse_number(&actual)?;
+            let expected = parse_number(&self.value)?;
+
+            return Ok(match self.operator {
+                PredicateOperator::GreaterThan => actual > expected,
+                PredicateOperator::GreaterThanOrEqual => actual >= expected,
+                PredicateOperator::LessThan => actual < expected,
+                _ => actual <= expected,
+            });
+        }
+
+        Ok(match self.operator {
+            PredicateOperator::Equal => actual == self.value,
+            PredicateOperator::NotEqual => actual != self.value,
+            _ => actual.contains(&self.value),
+        })
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(
+            self.operator,
+            PredicateOperator::GreaterThan
+                | PredicateOperator::GreaterThanOrEqual
+                | PredicateOperator::LessThan
+                | PredicateOperator::LessThanOrEqual
+        )
+    }
+}
+
+fn parse_number(value: &str) -> Result<i128> {
+    value
+        .trim()
+        .parse::<i128>()
+        .map_err(|_| error!(ReclaimError::InvalidClaimParameters))
+}
+
+/// Every predicate must hold for the claim to be accepted
+pub fn check_predicates(predicates: &[ClaimPredicate], parameters: &str) -> Result<()> {
+    for predicate in predicates {
+        require!(
+            predicate.evaluate(parameters)?,
+            ReclaimError::PredicateNotSatisfied
+        );
+    }
+
+    Ok(())
+}
+
+pub fn validate_predicates(predicates: &[ClaimPredicate]) -> Result<()> {
+    if predicates.len() > MAX_PREDICATES {
+        return err!(ReclaimError::MaxPredicatesReached);
+    }
+
+    for predicate in predicates {
+        predicate.validate()?;
+    }
+
+    Ok(())
+}
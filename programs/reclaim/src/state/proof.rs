@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::utils::append_0x;
+use crate::utils::{append_0x, hash_content};
 
 #[derive(AnchorDeserialize, AnchorSerialize, PartialEq, Eq, Clone, Debug)]
 pub struct SignedClaim {
@@ -62,6 +62,11 @@ impl ClaimData {
         .join("\n")
     }
 
+    /// Hash of the message the witnesses sign, distinct for every attestation of a claim
+    pub fn claim_hash(&self) -> [u8; 32] {
+        hash_content(&self.serialize_for_recovery())
+    }
+
     pub fn serialize_for_witness(&self, epoch_minimum_witness: usize) -> String {
         let ClaimData {
             identifier,
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum WitnessChange {
+    // Onboard a witness
+    Add(Witness),
+    // Remove the witness with this address
+    Remove { address: String },
+    // Update the minimum witnesses for claim
+    SetMinimumWitnessesForClaim(u8),
+}
+
+impl WitnessChange {
+    // Size of the largest variant
+    pub fn size() -> usize {
+        1 + Witness::size()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct WitnessChangeRecord {
+    // Applied change
+    pub change: WitnessChange,
+    // First epoch using the change
+    pub effective_epoch_index: u32,
+    // Change timestamp
+    pub recorded_at: i64,
+}
+
+impl WitnessChangeRecord {
+    pub fn size() -> usize {
+        WitnessChange::size() +
+        4 + // Effective Epoch Index
+        8 // Recorded At
+    }
+}
+
+/// Witness set for the next epoch of a governed epoch config, along with every change made to it
+#[account]
+pub struct WitnessHistory {
+    // Bump for the address
+    pub bump: u8,
+    // Epoch Config
+    pub epoch_config: Pubkey,
+    // Witnesses of the next epoch
+    pub witnesses: Vec<Witness>,
+    // Minimum witnesses for claim of the next epoch
+    pub minimum_witnesses_for_claim: u8,
+    // Audit log, oldest first
+    pub changes: Vec<WitnessChangeRecord>,
+}
+
+impl WitnessHistory {
+    pub fn size(witnesses: &[Witness], changes: &[WitnessChangeRecord]) -> usize {
+        8 + // Anchor discriminator
+        1 + // Bump
+        32 + // Epoch Config
+        4 + // Vector discriminator
+        (witnesses.len() * Witness::size()) +
+        1 + // Minimum Witnesses for Claim
+        4 + // Vector discriminator
+        (changes.len() * WitnessChangeRecord::size())
+    }
+
+    pub fn apply(&mut self, change: &WitnessChange) -> Result<()> {
+        match change {
+            WitnessChange::Add(witness) => {
+                if self.witnesses.iter().any(|w| w.address.eq_ignore_ascii_case(&witness.address)) {
+                    return err!(ReclaimError::WitnessAlreadyExists);
+                }
+                self.witnesses.push(witness.clone());
+            }
+            WitnessChange::Remove { address } => {
+                let index = self
+                    .witnesses
+                    .iter()
+                    .position(|w| w.address.eq_ignore_ascii_case(address))
+                    .ok_or(ReclaimError::WitnessNotFound)?;
+                self.witnesses.remove(index);
+            }
+            WitnessChange::SetMinimumWitnessesForClaim(minimum_witnesses_for_claim) => {
+                self.minimum_witnesses_for_claim = *minimum_witnesses_for_claim;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        validate_witnesses(&self.witnesses, self.minimum_witnesses_for_claim)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::errors::*;
+
+use anchor_lang::prelude::*;
+
+/// Extract the value at `path` from a JSON document.
+///
+/// Paths are dot separated keys with optional array indices, e.g. `user.stats.followers`
+/// or `responseMatches[0].value`. Strings are returned unescaped, every other value
+/// (numbers, booleans, null, objects and arrays) as its raw JSON text.
+pub fn extract_json_value(json: &str, path: &str) -> Result<String> {
+    let mut parser = JsonParser::new(json);
+    let (mut start, mut end) = parser.value_span()?;
+    parser.skip_whitespace();
+    require!(parser.peek().is_none(), ReclaimError::InvalidClaimParameters);
+
+    for segment in parse_json_path(path)? {
+        let mut parser = JsonParser::new(&json[..end]);
+        parser.position = start;
+        (start, end) = match segment {
+            PathSegment::Key(key) => parser.find_key(key)?,
+            PathSegment::Index(index) => parser.find_index(index)?,
+        };
+    }
+
+    let value = &json[start..end];
+    if value.starts_with('"') {
+        unescape_json_string(&value[1..value.len() - 1])
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+enum PathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+fn parse_json_path(path: &str) -> Result<Vec<PathSegment<'_>>> {
+    let mut segments = vec![];
+
+    for part in path.split('.') {
+        let (key, mut indices) = match part.find('[') {
+            Some(bracket) => (&part[..bracket], &part[bracket..]),
+            None => (part, ""),
+        };
+
+        if !key.is_empty() {
+            segments.push(PathSegment::Key(key));
+        } else if indices.is_empty() {
+            return err!(ReclaimError::InvalidJsonPath);
+        }
+
+        while !indices.is_empty() {
+            let close = indices.find(']').ok_or(ReclaimError::InvalidJsonPath)?;
+            require!(indices.starts_with('['), ReclaimError::InvalidJsonPath);
+            let index = indices[1..close]
+                .parse::<usize>()
+                .map_err(|_| ReclaimError::InvalidJsonPath)?;
+            segments.push(PathSegment::Index(index));
+            indices = &indices[close + 1..];
+        }
+    }
+
+    Ok(segments)
+}
+
+fn unescape_json_string(content: &str) -> Result<String> {
+    let mut unescaped = String::with_capacity(content.len());
+    let mut chars = content.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        let escaped = match chars.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let mut code = read_hex_escape(&mut chars)?;
+                // Characters outside the basic plane are escaped as a surrogate pair
+                if (0xD800..0xDC00).contains(&code) {
+                    require!(
+                        chars.next() == Some('\\') && chars.next() == Some('u'),
+                        ReclaimError::InvalidClaimParameters
+                    );
+                    let low = read_hex_escape(&mut chars)?;
+                    require!(
+                        (0xDC00..0xE000).contains(&low),
+                        ReclaimError::InvalidClaimParameters
+                    );
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                }
+                // Lone low surrogates are not characters
+                char::from_u32(code).ok_or(ReclaimError::InvalidClaimParameters)?
+            }
+            _ => return err!(ReclaimError::InvalidClaimParameters),
+        };
+        unescaped.push(escaped);
+    }
+
+    Ok(unescaped)
+}
+
+/// The four hex digits of a `\u` escape
+fn read_hex_escape(chars: &mut std::str::Chars) -> Result<u32> {
+    let mut code = 0;
+    for _ in 0..4 {
+        let digit = chars
+            .next()
+            .and_then(|c| c.to_digit(16))
+            .ok_or(ReclaimError::InvalidClaimParameters)?;
+        code = code * 16 + digit;
+    }
+    Ok(code)
+}
+
+/// Minimal JSON scanner that locates values without building a document tree
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(json: &'a str) -> Self {
+        Self {
+            bytes: json.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\n' | b'\r' | b'\t')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<()> {
+        self.skip_whitespace();
+        require!(
+            self.peek() == Some(byte),
+            ReclaimError::InvalidClaimParameters
+        );
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Skip the next value, returning its start and end offsets
+    fn value_span(&mut self) -> Result<(usize, usize)> {
+        self.skip_whitespace();
+        let start = self.position;
+
+        match self.peek().ok_or(ReclaimError::InvalidClaimParameters)? {
+            b'"' => self.skip_string()?,
+            b'{' => self.skip_container(b'{', b'}')?,
+            b'[' => self.skip_container(b'[', b']')?,
+            b't' => self.skip_literal(b"true")?,
+            b'f' => self.skip_literal(b"false")?,
+            b'n' => self.skip_literal(b"null")?,
+            _ => self.skip_number()?,
+        }
+
+        Ok((start, self.position))
+    }
+
+    fn skip_string(&mut self) -> Result<()> {
+        self.expect(b'"')?;
+        loop {
+            match self.peek().ok_or(ReclaimError::InvalidClaimParameters)? {
+                b'"' => {
+                    self.position += 1;
+                    return Ok(());
+                }
+                b'\\' => {
+                    self.position += 1;
+                    match self.peek().ok_or(ReclaimError::InvalidClaimParameters)? {
+                        b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' => self.position += 1,
+                        b'u' => {
+                            self.position += 1;
+                            for _ in 0..4 {
+                                require!(
+                                    self.peek().is_some_and(|byte| byte.is_ascii_hexdigit()),
+                                    ReclaimError::InvalidClaimParameters
+                                );
+                                self.position += 1;
+                            }
+                        }
+                        _ => return err!(ReclaimError::InvalidClaimParameters),
+                    }
+                }
+                // Control characters must be escaped
+                0x00..=0x1f => return err!(ReclaimError::InvalidClaimParameters),
+                _ => self.position += 1,
+            }
+        }
+    }
+
+    fn skip_literal(&mut self, literal: &[u8]) -> Result<()> {
+        require!(
+            self.bytes[self.position..].starts_with(literal),
+            ReclaimError::InvalidClaimParameters
+        );
+        self.position += literal.len();
+        Ok(())
+    }
+
+    /// `-?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?`
+    fn skip_number(&mut self) -> Result<()> {
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => {
+                self.skip_digits();
+            }
+            _ => return err!(ReclaimError::InvalidClaimParameters),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            require!(self.skip_digits() > 0, ReclaimError::InvalidClaimParameters);
+        }
+
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            require!(self.skip_digits() > 0, ReclaimError::InvalidClaimParameters);
+        }
+
+        Ok(())
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.position;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn skip_container(&mut self, open: u8, close: u8) -> Result<()> {
+        self.expect(open)?;
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.position += 1;
+            return Ok(());
+        }
+
+        loop {
+            if open == b'{' {
+                self.skip_string()?;
+                self.expect(b':')?;
+            }
+            self.value_span()?;
+            self.skip_whitespace();
+            match self.peek().ok_or(ReclaimError::InvalidClaimParameters)? {
+                b',' => self.position += 1,
+                byte if byte == close => {
+                    self.position += 1;
+                    return Ok(());
+                }
+                _ => return err!(ReclaimError::InvalidClaimParameters),
+            }
+            self.skip_whitespace();
+        }
+    }
+
+    fn find_key(&mut self, key: &str) -> Result<(usize, usize)> {
+        self.expect(b'{')?;
+
+        loop {
+            self.skip_whitespace();
+            require!(
+                self.peek() == Some(b'"'),
+                ReclaimError::JsonPathNotFound
+            );
+
+            let key_start = self.position + 1;
+            self.skip_string()?;
+            let key_end = self.position - 1;
+            self.expect(b':')?;
+            let span = self.value_span()?;
+
+            let raw_key = std::str::from_utf8(&self.bytes[key_start..key_end])
+                .map_err(|_| ReclaimError::InvalidClaimParameters)?;
+            if unescape_json_string(raw_key)? == key {
+                return Ok(span);
+            }
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                _ => return err!(ReclaimError::JsonPathNotFound),
+            }
+        }
+    }
+
+    fn find_index(&mut self, index: usize) -> Result<(usize, usize)> {
+        self.expect(b'[')?;
+
+        let mut current = 0;
+        loop {
+            self.skip_whitespace();
+            require!(self.peek() != Some(b']'), ReclaimError::JsonPathNotFound);
+
+            let span = self.value_span()?;
+            if current == index {
+                return Ok(span);
+            }
+            current += 1;
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                _ => return err!(ReclaimError::JsonPathNotFound),
+            }
+        }
+    }
+}
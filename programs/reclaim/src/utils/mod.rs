@@ -1,7 +1,9 @@
 pub mod common;
 pub mod eth;
+pub mod json;
 pub mod solana;
 
 pub use common::*;
 pub use eth::*;
+pub use json::*;
 pub use solana::*;
@@ -25,13 +25,10 @@ pub fn select_witness_addresses(
         let random_seed = fetch_random_seed(hash, byte_offset)?;
         let witness_index = random_seed % witnesses_left.len() as u32;
 
-        let witness = witnesses_left[witness_index as usize].clone();
+        // Drawn without replacement, the last witness left taking the drawn one's place
+        let witness = witnesses_left.swap_remove(witness_index as usize);
         selected_witnesses.push(witness);
 
-        witnesses_left[witness_index as usize] =
-            witnesses[witnesses_left.len() - 1].address.clone();
-        witnesses_left.pop();
-
         byte_offset = (byte_offset + 4) % u8::try_from(hash.len()).unwrap();
     }
 
@@ -69,6 +66,37 @@ pub fn recover_witness_addresses(hash: &[u8; 32], signatures: &[[u8; 65]]) -> Re
     Ok(recovered_witnesses)
 }
 
+/// Require exactly one signature from every selected witness and none from anyone else
+pub fn check_witness_signatures(
+    selected_witnesses: &[String],
+    recovered_witnesses: &[String],
+) -> Result<()> {
+    require!(
+        recovered_witnesses.len() == selected_witnesses.len(),
+        ReclaimError::InvalidWitnessClaimCount
+    );
+
+    for (index, recovered_witness) in recovered_witnesses.iter().enumerate() {
+        require!(
+            selected_witnesses.contains(recovered_witness),
+            ReclaimError::InvalidWitnessSignature
+        );
+        require!(
+            !recovered_witnesses[..index].contains(recovered_witness),
+            ReclaimError::DuplicateWitnessSignature
+        );
+    }
+
+    for selected_witness in selected_witnesses {
+        require!(
+            recovered_witnesses.contains(selected_witness),
+            ReclaimError::InvalidWitnessSignature
+        );
+    }
+
+    Ok(())
+}
+
 pub fn realloc<'info>(
     account: AccountInfo<'info>,
     required_size: usize,
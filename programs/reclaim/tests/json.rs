@@ -0,0 +1,138 @@
+//! Claim parameter lookups of `extract_json_value` over well-formed and malformed JSON
+
+use anchor_lang::error::Error;
+use reclaim::errors::ReclaimError;
+use reclaim::utils::json::extract_json_value;
+
+/// The lookup, with failures reduced to their error code
+fn extract(json: &str, path: &str) -> Result<String, u32> {
+    extract_json_value(json, path).map_err(|error| match error {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => panic!("unexpected program error {error}"),
+    })
+}
+
+fn code(error: ReclaimError) -> u32 {
+    error.into()
+}
+
+#[test]
+fn unescapes_strings() {
+    let cases = [
+        (r#"{"a":"plain"}"#, "plain"),
+        (r#"{"a":"quote \" and backslash \\"}"#, r#"quote " and backslash \"#),
+        (r#"{"a":"\/\b\f\n\r\t"}"#, "/\u{8}\u{c}\n\r\t"),
+        (r#"{"a":"\u00e9\u4E2D"}"#, "é中"),
+        (r#"{"a":"\ud83d\ude00"}"#, "😀"),
+        (r#"{"a":"é \ud83d\ude00 \u0041"}"#, "é 😀 A"),
+        (r#"{"a":""}"#, ""),
+    ];
+
+    for (json, expected) in cases {
+        assert_eq!(extract(json, "a").as_deref(), Ok(expected), "{json}");
+    }
+}
+
+#[test]
+fn follows_nested_paths() {
+    let json = r#" {
+        "user": {"name": "ada", "stats": {"followers": 1200, "tags": ["a", "b"]}},
+        "responseMatches": [{"value": "first"}, {"value": "second"}],
+        "matrix": [[1, 2], [3, 4]],
+        "escaped \"key\"": true
+    } "#;
+    let cases = [
+        ("user.name", "ada"),
+        ("user.stats.followers", "1200"),
+        ("user.stats.tags[1]", "b"),
+        ("responseMatches[1].value", "second"),
+        ("matrix[1][0]", "3"),
+        ("user.stats", r#"{"followers": 1200, "tags": ["a", "b"]}"#),
+        ("escaped \"key\"", "true"),
+    ];
+
+    for (path, expected) in cases {
+        assert_eq!(extract(json, path).as_deref(), Ok(expected), "{path}");
+    }
+}
+
+#[test]
+fn returns_numbers_and_literals_as_written() {
+    let cases = [
+        (r#"{"a":0}"#, "0"),
+        (r#"{"a":-12}"#, "-12"),
+        (r#"{"a":3.25}"#, "3.25"),
+        (r#"{"a":1e9}"#, "1e9"),
+        (r#"{"a":-0.5E-3}"#, "-0.5E-3"),
+        (r#"{"a":true}"#, "true"),
+        (r#"{"a":false}"#, "false"),
+        (r#"{"a":null}"#, "null"),
+    ];
+
+    for (json, expected) in cases {
+        assert_eq!(extract(json, "a").as_deref(), Ok(expected), "{json}");
+    }
+}
+
+#[test]
+fn reports_missing_paths() {
+    let json = r#"{"a":{"b":[1,2]}}"#;
+    let cases = [
+        ("c", ReclaimError::JsonPathNotFound),
+        ("a.c", ReclaimError::JsonPathNotFound),
+        ("a.b[2]", ReclaimError::JsonPathNotFound),
+        ("a.b.c", ReclaimError::InvalidClaimParameters),
+        ("a[0]", ReclaimError::InvalidClaimParameters),
+        ("", ReclaimError::InvalidJsonPath),
+        ("a..b", ReclaimError::InvalidJsonPath),
+        ("a.b[x]", ReclaimError::InvalidJsonPath),
+        ("a.b[0", ReclaimError::InvalidJsonPath),
+    ];
+
+    for (path, expected) in cases {
+        assert_eq!(extract(json, path), Err(code(expected)), "{path}");
+    }
+}
+
+#[test]
+fn rejects_malformed_documents() {
+    let cases = [
+        "",
+        "{",
+        r#"{"a":1"#,
+        r#"{"a" 1}"#,
+        r#"{"a":1,}"#,
+        r#"{a:1}"#,
+        r#"{"a":[1,2}"#,
+        r#"{"a":1} trailing"#,
+        r#"{"a":tru}"#,
+        r#"{"a":nul}"#,
+        r#"{"a":01}"#,
+        r#"{"a":1.}"#,
+        r#"{"a":.5}"#,
+        r#"{"a":1e}"#,
+        r#"{"a":+1}"#,
+        "{\"a\":\"raw\nnewline\"}",
+        r#"{"a":"bad \x escape"}"#,
+        r#"{"a":"\u12"}"#,
+        r#"{"a":"\u+1ab"}"#,
+    ];
+
+    for json in cases {
+        assert_eq!(extract(json, "a"), Err(code(ReclaimError::InvalidClaimParameters)), "{json}");
+    }
+}
+
+#[test]
+fn rejects_unpaired_surrogates() {
+    let cases = [
+        r#"{"a":"\ud83d"}"#,
+        r#"{"a":"\ud83d tail"}"#,
+        r#"{"a":"\ud83dA"}"#,
+        r#"{"a":"\ude00"}"#,
+    ];
+
+    for json in cases {
+        assert_eq!(extract(json, "a"), Err(code(ReclaimError::InvalidClaimParameters)), "{json}");
+    }
+}
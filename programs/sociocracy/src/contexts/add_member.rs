@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::states::{Circle, CircleMemberRecord};
 use crate::errors::GovernanceError;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 
 #[derive(Accounts)]
@@ -24,6 +25,9 @@ pub struct AddMemberToCircle<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    // Required when the circle has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -44,6 +48,13 @@ pub fn add_member_to_circle(ctx: Context<AddMemberToCircle>) -> Result<()> {
         GovernanceError::MemberAlreadyExists
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        circle.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &member_pubkey,
+    )?;
+
     // Add member to the circle
     circle.members.push(member_pubkey);
 
@@ -45,6 +45,7 @@ pub fn create_circle(ctx: Context<CreateCircle>, args: CreateCircleArgs) -> Resu
     circle.updated_at = clock.unix_timestamp;
     circle.members = vec![];
     circle.proposals = vec![];
+    circle.reclaim_requirement = args.reclaim_requirement;
     circle.bump = ctx.bumps.circle;
 
     if args.spl_config.is_some() {
@@ -9,6 +9,7 @@ pub mod add_member;
 pub mod init_member;
 pub mod create_invite;
 pub mod use_invite;
+pub mod set_reclaim_requirement;
 
 pub use create_circle::*;
 pub use create_proposal::*;
@@ -21,3 +22,4 @@ pub use add_member::*;
 pub use init_member::*;
 pub use create_invite::*;
 pub use use_invite::*;
+pub use set_reclaim_requirement::*;
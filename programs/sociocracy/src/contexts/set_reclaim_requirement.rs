@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
+use crate::states::{circle::Circle, proposal::{Proposal, ProposalStatus}};
+use crate::errors::GovernanceError;
+
+#[derive(Accounts)]
+pub struct SetReclaimRequirement<'info> {
+    // Grows circles created before the requirement was part of their layout
+    #[account(
+        mut,
+        realloc = Circle::SPACE,
+        realloc::payer = proposer,
+        realloc::zero = false
+    )]
+    pub circle: Box<Account<'info, Circle>>,
+    // The circle consented to the change, and the proposal is spent applying it
+    #[account(
+        mut,
+        has_one = circle,
+        has_one = proposer,
+        constraint = proposal.status == ProposalStatus::Passed @ GovernanceError::ProposalNotPassed,
+        close = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the credential new members must hold, or drop it with `None`
+pub fn update_reclaim_requirement(
+    ctx: Context<SetReclaimRequirement>,
+    reclaim_requirement: Option<ReclaimRequirement>,
+) -> Result<()> {
+    ctx.accounts.circle.reclaim_requirement = reclaim_requirement;
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::states::{Circle, CircleInvite, CircleMemberRecord, Member};
 use crate::errors::GovernanceError;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 pub struct UseCircleInvite<'info> {
@@ -40,6 +41,9 @@ pub struct UseCircleInvite<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the circle has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -63,6 +67,13 @@ pub fn use_invite(ctx: Context<UseCircleInvite>, name: String) -> Result<()> {
         GovernanceError::MemberAlreadyExists
     );
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        circle.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize or update the Member account
     if member.name.is_empty() {
         require!(name.len() <= Member::MAX_NAME_LENGTH, GovernanceError::NameTooLong);
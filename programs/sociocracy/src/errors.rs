@@ -52,5 +52,7 @@ pub enum GovernanceError {
     #[msg("Invite used")]
     InviteAlreadyUsed,
     #[msg("Invite invalid")]
-    InvalidInvite
+    InvalidInvite,
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed
 }
\ No newline at end of file
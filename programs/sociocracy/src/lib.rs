@@ -6,6 +6,7 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use standard::ReclaimRequirement;
 
 
 pub mod errors;
@@ -48,6 +49,13 @@ pub mod sociocracy {
         use_invite(ctx, name)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     pub fn create_sociocracy_circle(ctx: Context<CreateCircle>, args: CreateCircleArgs) -> Result<()> {
         create_circle(ctx, args)
     }
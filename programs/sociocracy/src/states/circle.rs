@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 #[account]
 pub struct Circle {
@@ -24,6 +25,8 @@ pub struct Circle {
     pub sbt_mint: Option<Pubkey>,
     pub governance_token_mint: Pubkey,
     pub bump: u8,
+    // Credential new members must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 // circle.description = args.description;
@@ -62,7 +65,8 @@ impl Circle {
         33 + // nft_mint
         33 + // sbt_mint
         32 + // governance_token_mint
-        1; // bump
+        1 + // bump
+        1 + ReclaimRequirement::SIZE; // reclaim_requirement
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -78,6 +82,7 @@ pub struct CreateCircleArgs {
     pub spl_supply: u64,
     pub collection_price: u64,
     pub primary_governance_token: PrimaryGovernanceToken,
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(Default)]
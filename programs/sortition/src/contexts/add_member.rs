@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Mint};
 use crate::states::{governance::GovernancePool, citizen::Citizen, citizen_index::CitizenIndex};
 use crate::error::GovernanceError;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 #[instruction(name: String, region: u8, age_group: u8, other_demographic: u8)]
@@ -21,8 +22,13 @@ pub struct AddGovernanceMember<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the governance pool has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
-        mut,
+        init_if_needed,
+        payer = new_member,
+        space = CitizenIndex::SPACE,
         seeds = [b"citizen_index", governance_pool.key().as_ref(), &(governance_pool.total_citizens / CitizenIndex::MAX_CITIZENS_PER_INDEX as u32).to_le_bytes()],
         bump
     )]
@@ -58,6 +64,13 @@ pub fn add_governance_member(
     require!(age_group < 5, GovernanceError::InvalidDemographic);
     require!(other_demographic < 4, GovernanceError::InvalidDemographic);
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance_pool.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &ctx.accounts.new_member.key(),
+    )?;
+
     // Initialize citizen account
     citizen_account.name = name;
     citizen_account.governance_pool = governance_pool.key();
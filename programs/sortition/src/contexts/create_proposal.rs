@@ -30,6 +30,7 @@ pub fn create_proposal(ctx: Context<CreateProposal>, name: String, description:
     require!(assembly.members.contains(&proposer.key()), GovernanceError::NotAssemblyMember);
     require!(Clock::get()?.unix_timestamp < assembly.term_end, GovernanceError::AssemblyTermEnded);
     require!(description.len() <= Proposal::MAX_DESCRIPTION_LENGTH, GovernanceError::InvalidInput);
+    require!(assembly.proposals.len() < Assembly::MAX_PROPOSALS, GovernanceError::InvalidInput);
 
     proposal.assembly = assembly.key();
     proposal.name = name;
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::states::{assembly::Assembly, pagination::PaginationState, citizen_index::CitizenIndex, citizen::Citizen, governance::{GovernancePool, DemographicQuotas}};
 use crate::error::GovernanceError;
 use anchor_lang::solana_program::{sysvar::recent_blockhashes::ID, hash::hash};
+use governance_math::{calculate_selection_probability, GroupSeats, SELECTION_SCALE};
 
 #[derive(Accounts)]
 pub struct FinalizeAssemblySelection<'info> {
@@ -24,12 +25,7 @@ pub struct FinalizeAssemblySelection<'info> {
     #[account(mut, constraint = admin.key() == governance_pool.admin)]
     pub admin: Signer<'info>,
     /// CHECK: This is safe as we only read from this account
-    #[account(
-        mut,
-        seeds = [b"recent-blockhashes"],
-        bump,
-        seeds::program = ID
-    )]
+    #[account(address = ID)]
     pub recent_blockhashes: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -60,12 +56,13 @@ pub fn finalize_assembly_selection<'info>(ctx: Context<'_, '_, 'info, 'info, Fin
     while processed < batch_size && pagination_state.current_citizen_in_index < citizen_index.citizens.len() as u32 {
         let citizen_pubkey = citizen_index.citizens[pagination_state.current_citizen_in_index as usize];
 
-        if let Some(citizen_account) = find_and_verify_citizen_account(ctx.program_id, &citizen_pubkey, ctx.remaining_accounts)? {
-                if citizen_account.is_eligible && is_demographically_eligible(&citizen_account, &pagination_state.demographic_counts, &governance_pool.demographic_quotas) {
+        if let Some(citizen_account) = find_and_verify_citizen_account(ctx.program_id, &governance_pool.key(), &citizen_pubkey, ctx.remaining_accounts)? {
+                // Citizens in a full demographic group have no chance of being drawn
+                if citizen_account.is_eligible {
                     // We'll use deterministic randomness to generate a unique random number for each citizen
-                    // Using the first 8 bytes of the hash as a u64, then convert to a float between 0 and 1
-                    let random_number = generate_random_number(&new_seed, pagination_state.current_citizen_in_index, &citizen_pubkey);
-                    if random_number < calculate_selection_probability(&citizen_account, &pagination_state.demographic_counts, &governance_pool.demographic_quotas) {
+                    // Using the first 8 bytes of the hash as a u64, reduced to the probability scale
+                    let random_number = generate_random_number(&new_seed, pagination_state.current_citizen_in_index, &citizen_pubkey) % SELECTION_SCALE;
+                    if random_number < selection_probability(&citizen_account, &pagination_state.demographic_counts, &governance_pool.demographic_quotas) {
                         pagination_state.selected_citizens.push(citizen_pubkey);
                         update_demographic_counts(&mut pagination_state.demographic_counts, &citizen_account);
                         
@@ -115,13 +112,19 @@ fn finalize_selection(
     Ok(())
 }
 
+// The index holds citizen wallets; their `Citizen` accounts are passed as remaining accounts
 fn find_and_verify_citizen_account<'a>(
     program_id: &Pubkey,
+    governance_pool: &Pubkey,
     citizen_pubkey: &Pubkey,
     remaining_accounts: &'a [AccountInfo<'a>]
 ) -> Result<Option<Account<'a, Citizen>>> {
+    let (citizen_account, _) = Pubkey::find_program_address(
+        &[b"citizen", governance_pool.as_ref(), citizen_pubkey.as_ref()],
+        program_id
+    );
     for account_info in remaining_accounts {
-        if account_info.key() == *citizen_pubkey {
+        if account_info.key() == citizen_account {
             return Ok(Some(Account::try_from(account_info)?));
         }
     }
@@ -135,44 +138,17 @@ pub struct AssemblySelected {
     member_count: u32
 }
 
-fn is_demographically_eligible(citizen: &Citizen, current_counts: &DemographicQuotas, quotas: &DemographicQuotas) -> bool {
-    current_counts.regions[citizen.region as usize] < quotas.regions[citizen.region as usize] &&
-    current_counts.age_groups[citizen.age_group as usize] < quotas.age_groups[citizen.age_group as usize] &&
-    current_counts.other_demographic[citizen.other_demographic as usize] < quotas.other_demographic[citizen.other_demographic as usize]
-}
-
-fn calculate_selection_probability(citizen: &Citizen, current_counts: &DemographicQuotas, quotas: &DemographicQuotas) -> u64 {
-    const SCALE_FACTOR: u64 = 10_000;
-    const REGION_WEIGHT: u64 = 4_000; // 40%
-    const AGE_WEIGHT: u64 = 3_000;    // 30%
-    const OTHER_WEIGHT: u64 = 3_000;  // 30%
-
-    let region_factor = calculate_factor(
-        quotas.regions[citizen.region as usize],
-        current_counts.regions[citizen.region as usize],
-        REGION_WEIGHT
-    );
-    let age_factor = calculate_factor(
-        quotas.age_groups[citizen.age_group as usize],
-        current_counts.age_groups[citizen.age_group as usize],
-        AGE_WEIGHT
-    );
-    let other_factor = calculate_factor(
-        quotas.other_demographic[citizen.other_demographic as usize],
-        current_counts.other_demographic[citizen.other_demographic as usize],
-        OTHER_WEIGHT
-    );
-    
-    region_factor.saturating_add(age_factor).saturating_add(other_factor)
-}
-
-fn calculate_factor(quota: u32, current_count: u32, weight: u64) -> u64 {
-    if quota == 0 {
-        return 0;
-    }
-    (quota.saturating_sub(current_count) as u64)
-        .saturating_mul(weight)
-        .saturating_div(quota as u64)
+fn selection_probability(citizen: &Citizen, current_counts: &DemographicQuotas, quotas: &DemographicQuotas) -> u64 {
+    let seats = |quota: u32, filled: u32| GroupSeats { quota, filled };
+
+    calculate_selection_probability(
+        seats(quotas.regions[citizen.region as usize], current_counts.regions[citizen.region as usize]),
+        seats(quotas.age_groups[citizen.age_group as usize], current_counts.age_groups[citizen.age_group as usize]),
+        seats(
+            quotas.other_demographic[citizen.other_demographic as usize],
+            current_counts.other_demographic[citizen.other_demographic as usize],
+        ),
+    )
 }
 
 fn update_demographic_counts(counts: &mut DemographicQuotas, citizen: &Citizen) {
@@ -50,6 +50,7 @@ pub fn initialize_governance(ctx: Context<InitializeGovernance>, args: Initializ
     governance_pool.last_random_seed = [0; 32];
     governance_pool.selection_in_progress = false;
     governance_pool.total_citizen_indices = 0;
+    governance_pool.reclaim_requirement = args.reclaim_requirement;
     governance_pool.demographic_quotas = DemographicQuotas {
         regions: args.regions,
         age_groups: args.age_groups,
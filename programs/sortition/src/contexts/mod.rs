@@ -10,6 +10,7 @@ pub mod mint_nft;
 pub mod add_member;
 pub mod create_invite;
 pub mod use_invite;
+pub mod set_reclaim_requirement;
 
 pub use create_proposal::*;
 pub use finalize_selection::*;
@@ -23,3 +24,4 @@ pub use mint_nft::*;
 pub use add_member::*;
 pub use create_invite::*;
 pub use use_invite::*;
+pub use set_reclaim_requirement::*;
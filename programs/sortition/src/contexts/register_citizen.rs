@@ -18,7 +18,9 @@ pub struct RegisterCitizen<'info> {
     #[account(mut)]
     pub citizen: Signer<'info>,
     #[account(
-        mut,
+        init_if_needed,
+        payer = citizen,
+        space = CitizenIndex::SPACE,
         seeds = [b"citizen_index", governance_pool.key().as_ref(), &(governance_pool.total_citizens / CitizenIndex::MAX_CITIZENS_PER_INDEX as u32).to_le_bytes()],
         bump
     )]
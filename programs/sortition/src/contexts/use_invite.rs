@@ -5,6 +5,7 @@ use crate::states::citizen::Citizen;
 use crate::states::citizen_index::CitizenIndex;
 use crate::states::invite::GovernanceInvite;
 use crate::error::GovernanceError;
+use standard::{check_reclaim_requirement, VerificationResult};
 
 #[derive(Accounts)]
 #[instruction(name: String, region: u8, age_group: u8, other_demographic: u8)]
@@ -32,8 +33,13 @@ pub struct UseGovernanceInvite<'info> {
     #[account(mut)]
     pub new_member: Signer<'info>,
 
+    // Required when the governance pool has a Reclaim requirement
+    pub verification: Option<Account<'info, VerificationResult>>,
+
     #[account(
-        mut,
+        init_if_needed,
+        payer = new_member,
+        space = CitizenIndex::SPACE,
         seeds = [b"citizen_index", governance_pool.key().as_ref(), &(governance_pool.total_citizens / CitizenIndex::MAX_CITIZENS_PER_INDEX as u32).to_le_bytes()],
         bump
     )]
@@ -71,6 +77,13 @@ pub fn use_governance_invite(
     require!(age_group < 5, GovernanceError::InvalidDemographic);
     require!(other_demographic < 4, GovernanceError::InvalidDemographic);
 
+    // Check the required Reclaim credential, if any
+    check_reclaim_requirement(
+        governance_pool.reclaim_requirement.as_ref(),
+        ctx.accounts.verification.as_deref(),
+        &new_member.key(),
+    )?;
+
     // Initialize citizen account
     citizen_account.name = name;
     citizen_account.governance_pool = governance_pool.key();
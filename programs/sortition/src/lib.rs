@@ -6,6 +6,7 @@ use the_ark_program::state::analytics::ArkAnalytics;
 use the_ark_program::cpi::register_government;
 use the_ark_program::instructions::register_state::StateInfo;
 use the_ark_program::instructions::register_state::GovernmentType;
+use standard::ReclaimRequirement;
 
 
 pub mod error;
@@ -56,6 +57,13 @@ pub mod sortition {
         use_invite::use_governance_invite(ctx, name, region, age_group, other_demographic)
     }
 
+    pub fn set_reclaim_requirement(
+        ctx: Context<SetReclaimRequirement>,
+        reclaim_requirement: Option<ReclaimRequirement>,
+    ) -> Result<()> {
+        set_reclaim_requirement::update_reclaim_requirement(ctx, reclaim_requirement)
+    }
+
     pub fn select_sortition_assembly(ctx: Context<SelectAssembly>, term_length: i64) -> Result<()> {
         select_assembly(ctx, term_length)
     }
@@ -12,7 +12,8 @@ pub struct Assembly {
 
 impl Assembly {
     pub const MAX_MEMBERS: usize = 100;
-    pub const MAX_PROPOSALS: usize = 1000;
+    // Keeps the account within the 10KiB an `init` can allocate
+    pub const MAX_PROPOSALS: usize = 200;
 
     pub const SPACE: usize = 8 + // discriminator
         32 + // governance_pool
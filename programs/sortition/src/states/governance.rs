@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use standard::ReclaimRequirement;
 
 #[account]
 pub struct GovernancePool {
@@ -26,6 +27,8 @@ pub struct GovernancePool {
     pub sbt_mint: Option<Pubkey>,
     pub governance_token_mint: Pubkey,
     pub bump: u8,
+    // Credential new citizens must hold, if any
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -44,6 +47,7 @@ pub struct InitializeGovernmentArgs {
     pub spl_supply: u64,
     pub collection_price: u64,
     pub primary_governance_token: PrimaryGovernanceToken,
+    pub reclaim_requirement: Option<ReclaimRequirement>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -105,7 +109,8 @@ impl GovernancePool {
         33 +  // nft_mint: Option<Pubkey>
         33 +  // sbt_mint: Option<Pubkey>
         32 +  // governance_token_mint: Pubkey
-        1;    // bump: u8
+        1 +  // bump: u8
+        1 + ReclaimRequirement::SIZE;  // reclaim_requirement: Option<ReclaimRequirement>
 }
 
 impl DemographicQuotas {
@@ -18,6 +18,6 @@ impl PaginationState {
         4 + // current_index
         4 + // current_citizen_in_index
         4 + (Self::MAX_ASSEMBLY_SIZE * 32) + // selected_citizens
-        (8 * 1) + (5 * 1) + (4 * 1) + // demographic_counts
+        (10 * 4) + (5 * 4) + (3 * 4) + // demographic_counts
         1;
 }
\ No newline at end of file
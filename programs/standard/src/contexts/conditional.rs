@@ -1,11 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use reclaim::program::Reclaim;
+use reclaim::cpi::accounts::VerifyProof;
+use reclaim::state::{Epoch, EpochConfig, Group};
+use the_ark_program::{instructions_hash, Decision, DecisionRecord};
 use crate::states::escrow::*;
+use crate::states::router::{find_executor_address, RouterState};
+use crate::oracle::BPS_PRECISION;
+use crate::contexts::reclaim::{verify_reclaim_proof, VerifyArgs};
 use crate::errors::RouterError;
 
 #[derive(Accounts)]
+#[instruction(escrow_id: u64, milestones: Vec<MilestoneArgs>)]
 pub struct CreateEscrow<'info> {
-    #[account(init, payer = sender, space = 8 + 32 + 32 + 32 + 8 + 200 + 1 + 8)]
+    #[account(
+        init,
+        payer = sender,
+        space = Escrow::size(milestones.len()),
+        seeds = [SEED_ESCROW, sender.key().as_ref(), &escrow_id.to_le_bytes()],
+        bump
+    )]
     pub escrow: Account<'info, Escrow>,
     #[account(mut)]
     pub sender: Signer<'info>,
@@ -17,10 +31,14 @@ pub struct CreateEscrow<'info> {
     #[account(
         init,
         payer = sender,
+        seeds = [SEED_ESCROW_VAULT, escrow.key().as_ref()],
+        bump,
         token::mint = mint,
         token::authority = escrow
     )]
     pub escrow_token_account: Account<'info, TokenAccount>,
+    // Required when an arbiter government is designated
+    pub router_state: Option<Account<'info, RouterState>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
@@ -30,51 +48,138 @@ pub struct CreateEscrow<'info> {
 pub struct FulfillCondition<'info> {
     #[account(mut, has_one = recipient)]
     pub escrow: Account<'info, Escrow>,
+    // Pays for the Reclaim claim nullifier
+    #[account(mut)]
     pub recipient: Signer<'info>,
+    // Required by `ArbiterSignature` conditions
+    pub arbiter: Option<Signer<'info>>,
+    /// CHECK: Required by `GovernmentDecision` conditions, checked against the condition in the handler
+    pub decision_account: Option<UncheckedAccount<'info>>,
+    // Required by `ReclaimProof` conditions
+    pub epoch_config: Option<Account<'info, EpochConfig>>,
+    pub epoch: Option<Account<'info, Epoch>>,
+    pub reclaim_group: Option<Account<'info, Group>>,
+    /// CHECK: Created by Reclaim, which checks its address
+    #[account(mut)]
+    pub claim_nullifier: Option<UncheckedAccount<'info>>,
+    pub reclaim_program: Option<Program<'info, Reclaim>>,
+    pub system_program: Option<Program<'info, System>>,
 }
 
 #[derive(Accounts)]
 pub struct ReleasePayment<'info> {
-    #[account(mut, has_one = recipient, close = sender)]
+    #[account(mut, has_one = recipient, has_one = sender)]
     pub escrow: Account<'info, Escrow>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: Receives the rent once every milestone is settled
+    #[account(mut)]
     pub sender: UncheckedAccount<'info>,
     #[account(mut)]
     pub recipient: Signer<'info>,
-    #[account(mut)]
+    #[account(mut, seeds = [SEED_ESCROW_VAULT, escrow.key().as_ref()], bump)]
     pub escrow_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(mut, token::mint = escrow.mint)]
     pub recipient_token_account: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
 pub struct Refund<'info> {
-    #[account(mut, has_one = sender, close = sender)]
+    #[account(mut, has_one = sender)]
     pub escrow: Account<'info, Escrow>,
     #[account(mut)]
     pub sender: Signer<'info>,
-    #[account(mut)]
+    #[account(mut, seeds = [SEED_ESCROW_VAULT, escrow.key().as_ref()], bump)]
     pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = escrow.mint)]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RaiseDispute<'info> {
+    #[account(
+        mut,
+        constraint = party.key() == escrow.sender || party.key() == escrow.recipient @ RouterError::Unauthorized
+    )]
+    pub escrow: Account<'info, Escrow>,
+    pub party: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut, has_one = sender)]
+    pub escrow: Account<'info, Escrow>,
+    pub router_state: Account<'info, RouterState>,
+    // Executor PDA of the arbiter government, signing through `route_instruction`
+    pub executor: Signer<'info>,
+    /// CHECK: Receives the rent once the escrow is settled
     #[account(mut)]
+    pub sender: UncheckedAccount<'info>,
+    #[account(mut, seeds = [SEED_ESCROW_VAULT, escrow.key().as_ref()], bump)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = escrow.mint, token::authority = escrow.sender)]
     pub sender_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = escrow.mint, token::authority = escrow.recipient)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
 }
 
 pub fn create_escrow(
     ctx: Context<CreateEscrow>,
-    amount: u64,
-    condition: String,
+    escrow_id: u64,
+    milestones: Vec<MilestoneArgs>,
     expiry_time: i64,
+    arbiter_government_state: Option<Pubkey>,
 ) -> Result<()> {
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES,
+        RouterError::InvalidMilestone
+    );
+
+    let mut amount = 0u64;
+    for milestone in &milestones {
+        require!(milestone.amount > 0, RouterError::InvalidMilestone);
+        require!(milestone.release_time <= expiry_time, RouterError::InvalidMilestone);
+        validate_condition(&milestone.condition)?;
+        amount = amount.checked_add(milestone.amount).ok_or(RouterError::ArithmeticOverflow)?;
+    }
+
+    // The arbiter must be a government instance registered in the router
+    let arbiter = match arbiter_government_state {
+        Some(government_state) => {
+            let router_state = ctx.accounts.router_state.as_ref()
+                .ok_or(RouterError::GovernmentNotFound)?;
+            require!(
+                router_state.governments.iter().any(|g| g.government_state == government_state),
+                RouterError::GovernmentNotFound
+            );
+            Some(EscrowArbiter {
+                router_state: router_state.key(),
+                government_state,
+            })
+        }
+        None => None,
+    };
+
     let escrow = &mut ctx.accounts.escrow;
+    escrow.bump = ctx.bumps.escrow;
+    escrow.escrow_id = escrow_id;
     escrow.sender = ctx.accounts.sender.key();
     escrow.recipient = ctx.accounts.recipient.key();
     escrow.mint = ctx.accounts.mint.key();
     escrow.amount = amount;
-    escrow.condition = condition;
-    escrow.is_fulfilled = false;
+    escrow.milestones = milestones
+        .into_iter()
+        .map(|m| Milestone {
+            amount: m.amount,
+            condition: m.condition,
+            release_time: m.release_time,
+            status: MilestoneStatus::Pending,
+        })
+        .collect();
     escrow.expiry_time = expiry_time;
+    escrow.arbiter = arbiter;
+    escrow.dispute = None;
 
     // Transfer tokens from sender to escrow account
     let cpi_accounts = Transfer {
@@ -89,56 +194,297 @@ pub fn create_escrow(
     Ok(())
 }
 
-pub fn fulfill_condition(ctx: Context<FulfillCondition>) -> Result<()> {
+/// Check a milestone's condition on-chain and mark it fulfilled.
+/// `proof` is only read for `ReclaimProof` conditions.
+pub fn fulfill_condition(
+    ctx: Context<FulfillCondition>,
+    milestone_index: u8,
+    proof: Option<VerifyArgs>,
+) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let milestone = escrow.milestones
+        .get(usize::from(milestone_index))
+        .ok_or(RouterError::InvalidMilestone)?;
+    require!(milestone.status == MilestoneStatus::Pending, RouterError::AlreadyFulfilled);
+    let now = Clock::get()?.unix_timestamp;
+    require!(!escrow.is_disputed(now), RouterError::EscrowDisputed);
+    require!(now <= escrow.fulfillment_deadline(), RouterError::Expired);
+
+    match &milestone.condition {
+        EscrowCondition::ReclaimProof { epoch_config, provider } => {
+            let proof = proof.ok_or(RouterError::MissingConditionAccount)?;
+            require!(proof.claim_info.provider == *provider, RouterError::ConditionNotMet);
+
+            let epoch_config_account = ctx.accounts.epoch_config.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            require_keys_eq!(epoch_config_account.key(), *epoch_config, RouterError::ConditionNotMet);
+            let epoch = ctx.accounts.epoch.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            let reclaim_group = ctx.accounts.reclaim_group.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            let claim_nullifier = ctx.accounts.claim_nullifier.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            let reclaim_program = ctx.accounts.reclaim_program.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            let system_program = ctx.accounts.system_program.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+
+            // Reclaim requires the claim's context address to sign, so the proof is bound to the recipient
+            verify_reclaim_proof(
+                reclaim_program.to_account_info(),
+                VerifyProof {
+                    group: reclaim_group.to_account_info(),
+                    nullifier: claim_nullifier.to_account_info(),
+                    epoch: epoch.to_account_info(),
+                    epoch_config: epoch_config_account.to_account_info(),
+                    signer: ctx.accounts.recipient.to_account_info(),
+                    system_program: system_program.to_account_info(),
+                    dapp: None,
+                    fee_recipient: None,
+                },
+                proof,
+            )?;
+        }
+        EscrowCondition::GovernmentDecision { government_program, decision_account } => {
+            let account = ctx.accounts.decision_account.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            require_keys_eq!(account.key(), *decision_account, RouterError::ConditionNotMet);
+            require_keys_eq!(*account.owner, *government_program, RouterError::ConditionNotMet);
+
+            let record = DecisionRecord::try_deserialize(&mut &account.try_borrow_data()?[..])
+                .map_err(|_| RouterError::ConditionNotMet)?;
+            require!(record.decision == Decision::Approve, RouterError::ConditionNotMet);
+
+            // The approval must name this milestone, which it fulfills once
+            let request = escrow.milestone_request(escrow.key(), milestone_index);
+            require!(
+                record.instructions_hash == instructions_hash(&[request])?,
+                RouterError::ConditionNotMet
+            );
+        }
+        EscrowCondition::Timestamp { unlock_at } => {
+            require!(now >= *unlock_at, RouterError::ConditionNotMet);
+        }
+        EscrowCondition::ArbiterSignature { arbiter } => {
+            let signer = ctx.accounts.arbiter.as_ref()
+                .ok_or(RouterError::MissingConditionAccount)?;
+            require_keys_eq!(signer.key(), *arbiter, RouterError::ConditionNotMet);
+        }
+    }
+
+    ctx.accounts.escrow.milestones[usize::from(milestone_index)].status = MilestoneStatus::Fulfilled;
+    Ok(())
+}
+
+/// Pay a fulfilled milestone out to the recipient once its release time is reached.
+/// Disputes only cover pending milestones, so they don't hold this up.
+pub fn release_payment(ctx: Context<ReleasePayment>, milestone_index: u8) -> Result<()> {
     let escrow = &mut ctx.accounts.escrow;
-    require!(!escrow.is_fulfilled, RouterError::AlreadyFulfilled);
+    let milestone = escrow.milestones
+        .get_mut(usize::from(milestone_index))
+        .ok_or(RouterError::InvalidMilestone)?;
+    require!(milestone.status == MilestoneStatus::Fulfilled, RouterError::ConditionNotFulfilled);
     require!(
-        Clock::get()?.unix_timestamp <= escrow.expiry_time,
-        RouterError::Expired
+        Clock::get()?.unix_timestamp >= milestone.release_time,
+        RouterError::MilestoneLocked
     );
 
-    escrow.is_fulfilled = true;
-    Ok(())
+    milestone.status = MilestoneStatus::Released;
+    let amount = milestone.amount;
+
+    transfer_from_vault(
+        escrow,
+        &ctx.accounts.escrow_token_account,
+        ctx.accounts.recipient_token_account.to_account_info(),
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    close_if_settled(
+        escrow,
+        &ctx.accounts.escrow_token_account,
+        ctx.accounts.sender.to_account_info(),
+        &ctx.accounts.token_program,
+    )
 }
 
-pub fn release_payment(ctx: Context<ReleasePayment>) -> Result<()> {
-    let escrow = &ctx.accounts.escrow;
-    require!(escrow.is_fulfilled, RouterError::ConditionNotFulfilled);
+/// Return every pending milestone to the sender once the escrow has expired.
+/// Fulfilled milestones stay releasable to the recipient.
+pub fn refund(ctx: Context<Refund>) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let now = Clock::get()?.unix_timestamp;
+    require!(!escrow.is_disputed(now), RouterError::EscrowDisputed);
+    require!(now > escrow.fulfillment_deadline(), RouterError::NotExpired);
+
+    let mut amount = 0u64;
+    for milestone in escrow.milestones.iter_mut() {
+        if milestone.status == MilestoneStatus::Pending {
+            milestone.status = MilestoneStatus::Refunded;
+            amount = amount.checked_add(milestone.amount).ok_or(RouterError::ArithmeticOverflow)?;
+        }
+    }
+    require!(amount > 0, RouterError::AlreadyFulfilled);
+
+    transfer_from_vault(
+        escrow,
+        &ctx.accounts.escrow_token_account,
+        ctx.accounts.sender_token_account.to_account_info(),
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    close_if_settled(
+        escrow,
+        &ctx.accounts.escrow_token_account,
+        ctx.accounts.sender.to_account_info(),
+        &ctx.accounts.token_program,
+    )
+}
+
+/// Freeze the pending milestones until the arbiter government resolves the dispute,
+/// or for `DISPUTE_PERIOD` if it doesn't. The escrow then resumes on its own terms.
+pub fn raise_dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    require!(escrow.arbiter.is_some(), RouterError::NoArbiter);
+    require!(escrow.dispute.is_none(), RouterError::EscrowDisputed);
     require!(
-        Clock::get()?.unix_timestamp <= escrow.expiry_time,
-        RouterError::Expired
+        escrow.milestones.iter().any(|m| m.status == MilestoneStatus::Pending),
+        RouterError::AlreadyFulfilled
     );
 
-    // Transfer tokens from escrow to recipient
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.escrow_token_account.to_account_info(),
-        to: ctx.accounts.recipient_token_account.to_account_info(),
-        authority: ctx.accounts.escrow.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, escrow.amount)?;
+    escrow.dispute = Some(Dispute {
+        raised_by: ctx.accounts.party.key(),
+        raised_at: Clock::get()?.unix_timestamp,
+        resolved: false,
+    });
 
     Ok(())
 }
 
-pub fn refund(ctx: Context<Refund>) -> Result<()> {
-    let escrow = &ctx.accounts.escrow;
-    require!(!escrow.is_fulfilled, RouterError::AlreadyFulfilled);
+/// Settle the pending milestones of a disputed escrow as decided by the arbiter government.
+/// `recipient_share_bps` of their funds go to the recipient and the rest back to the sender;
+/// fulfilled milestones stay releasable to the recipient.
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, recipient_share_bps: u64) -> Result<()> {
+    require!(recipient_share_bps <= BPS_PRECISION, RouterError::InvalidDisputeShare);
+
+    let escrow = &mut ctx.accounts.escrow;
+    let dispute = escrow.dispute.as_ref().ok_or(RouterError::NotDisputed)?;
+    require!(dispute.is_open(Clock::get()?.unix_timestamp), RouterError::DisputeExpired);
+    let arbiter = escrow.arbiter.as_ref().ok_or(RouterError::NoArbiter)?;
+
+    // Only the arbiter instance's executor can sign, and only after it approved the call
+    let router_state = &ctx.accounts.router_state;
+    require_keys_eq!(router_state.key(), arbiter.router_state, RouterError::Unauthorized);
     require!(
-        Clock::get()?.unix_timestamp > escrow.expiry_time,
-        RouterError::NotExpired
+        router_state.governments.iter().any(|g| g.government_state == arbiter.government_state),
+        RouterError::GovernmentNotFound
     );
+    let (executor, _) = find_executor_address(&arbiter.router_state, &arbiter.government_state);
+    require_keys_eq!(ctx.accounts.executor.key(), executor, RouterError::Unauthorized);
 
-    // Transfer tokens from escrow back to sender
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.escrow_token_account.to_account_info(),
-        to: ctx.accounts.sender_token_account.to_account_info(),
-        authority: ctx.accounts.escrow.to_account_info(),
+    let pending = escrow.pending_amount()?;
+    let recipient_amount = u64::try_from(
+        u128::from(pending) * u128::from(recipient_share_bps) / u128::from(BPS_PRECISION),
+    )
+    .map_err(|_| RouterError::ArithmeticOverflow)?;
+    let sender_amount = pending - recipient_amount;
+
+    let resolved_status = if recipient_share_bps == 0 {
+        MilestoneStatus::Refunded
+    } else {
+        MilestoneStatus::Released
     };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, escrow.amount)?;
+    for milestone in escrow.milestones.iter_mut().filter(|m| m.status == MilestoneStatus::Pending) {
+        milestone.status = resolved_status;
+    }
+    if let Some(dispute) = escrow.dispute.as_mut() {
+        dispute.resolved = true;
+    }
 
+    if recipient_amount > 0 {
+        transfer_from_vault(
+            escrow,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.recipient_token_account.to_account_info(),
+            &ctx.accounts.token_program,
+            recipient_amount,
+        )?;
+    }
+    if sender_amount > 0 {
+        transfer_from_vault(
+            escrow,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.sender_token_account.to_account_info(),
+            &ctx.accounts.token_program,
+            sender_amount,
+        )?;
+    }
+
+    close_if_settled(
+        escrow,
+        &ctx.accounts.escrow_token_account,
+        ctx.accounts.sender.to_account_info(),
+        &ctx.accounts.token_program,
+    )
+}
+
+fn transfer_from_vault<'info>(
+    escrow: &Account<'info, Escrow>,
+    vault: &Account<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let escrow_id = escrow.escrow_id.to_le_bytes();
+    let seeds = &[SEED_ESCROW, escrow.sender.as_ref(), &escrow_id, &[escrow.bump]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: destination,
+                authority: escrow.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )
+}
+
+/// Close the vault and the escrow, returning their rent to the sender, once nothing is left to pay out
+fn close_if_settled<'info>(
+    escrow: &mut Account<'info, Escrow>,
+    vault: &Account<'info, TokenAccount>,
+    sender: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    if !escrow.is_settled() {
+        return Ok(());
+    }
+
+    let escrow_id = escrow.escrow_id.to_le_bytes();
+    let seeds = &[SEED_ESCROW, escrow.sender.as_ref(), &escrow_id, &[escrow.bump]];
+
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault.to_account_info(),
+            destination: sender.clone(),
+            authority: escrow.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    escrow.close(sender)
+}
+
+fn validate_condition(condition: &EscrowCondition) -> Result<()> {
+    if let EscrowCondition::ReclaimProof { provider, .. } = condition {
+        require!(
+            !provider.is_empty() && provider.len() <= MAX_PROVIDER_LEN,
+            RouterError::InvalidEscrowCondition
+        );
+    }
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,593 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::states::market::*;
+use crate::errors::RouterError;
+use crate::oracle::OracleConfig;
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(init, payer = authority, space = Market::SPACE)]
+    pub market: Box<Account<'info, Market>>,
+    /// CHECK: PDA that owns the market vaults, only used as a signer
+    #[account(seeds = [SEED_MARKET_AUTHORITY, market.key().as_ref()], bump)]
+    pub market_authority: UncheckedAccount<'info>,
+    pub base_mint: Box<Account<'info, Mint>>,
+    pub quote_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = authority,
+        token::mint = base_mint,
+        token::authority = market_authority,
+        seeds = [SEED_BASE_VAULT, market.key().as_ref()],
+        bump
+    )]
+    pub base_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        token::mint = quote_mint,
+        token::authority = market_authority,
+        seeds = [SEED_QUOTE_VAULT, market.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        space = OrderSlab::SPACE,
+        seeds = [SEED_BIDS, market.key().as_ref()],
+        bump
+    )]
+    pub bids: Box<Account<'info, OrderSlab>>,
+    #[account(
+        init,
+        payer = authority,
+        space = OrderSlab::SPACE,
+        seeds = [SEED_ASKS, market.key().as_ref()],
+        bump
+    )]
+    pub asks: Box<Account<'info, OrderSlab>>,
+    #[account(
+        init,
+        payer = authority,
+        space = EventQueue::SPACE,
+        seeds = [SEED_EVENT_QUEUE, market.key().as_ref()],
+        bump
+    )]
+    pub event_queue: Box<Account<'info, EventQueue>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CreateOpenOrders<'info> {
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        init,
+        payer = owner,
+        space = OpenOrders::SPACE,
+        seeds = [SEED_OPEN_ORDERS, market.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub open_orders: Account<'info, OpenOrders>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlaceOrderArgs {
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub amount: u64,
+    pub price: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+}
+
+#[derive(Accounts)]
+#[instruction(args: PlaceOrderArgs)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(mut, address = market.bids)]
+    pub bids: Box<Account<'info, OrderSlab>>,
+    #[account(mut, address = market.asks)]
+    pub asks: Box<Account<'info, OrderSlab>>,
+    #[account(mut, address = market.event_queue)]
+    pub event_queue: Box<Account<'info, EventQueue>>,
+    #[account(
+        mut,
+        seeds = [SEED_OPEN_ORDERS, market.key().as_ref(), owner.key().as_ref()],
+        bump = open_orders.bump,
+        has_one = owner,
+    )]
+    pub open_orders: Account<'info, OpenOrders>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut, token::mint = market.mint_for(args.side))]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = market.vault_for(args.side) @ RouterError::InvalidMarketAccount
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// CHECK: Validated against the market's oracle config when it has one
+    pub price_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    pub market: Box<Account<'info, Market>>,
+    #[account(mut, address = market.bids)]
+    pub bids: Box<Account<'info, OrderSlab>>,
+    #[account(mut, address = market.asks)]
+    pub asks: Box<Account<'info, OrderSlab>>,
+    #[account(
+        mut,
+        seeds = [SEED_OPEN_ORDERS, market.key().as_ref(), owner.key().as_ref()],
+        bump = open_orders.bump,
+        has_one = owner,
+    )]
+    pub open_orders: Account<'info, OpenOrders>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessOrders<'info> {
+    pub market: Box<Account<'info, Market>>,
+    #[account(mut, address = market.bids)]
+    pub bids: Box<Account<'info, OrderSlab>>,
+    #[account(mut, address = market.asks)]
+    pub asks: Box<Account<'info, OrderSlab>>,
+    #[account(mut, address = market.event_queue)]
+    pub event_queue: Box<Account<'info, EventQueue>>,
+}
+
+/// The open orders accounts of every user referenced by the consumed events
+/// are passed as writable remaining accounts.
+#[derive(Accounts)]
+pub struct ConsumeEvents<'info> {
+    pub market: Box<Account<'info, Market>>,
+    #[account(mut, address = market.event_queue)]
+    pub event_queue: Box<Account<'info, EventQueue>>,
+}
+
+#[derive(Accounts)]
+pub struct SettleFunds<'info> {
+    pub market: Box<Account<'info, Market>>,
+    /// CHECK: PDA that owns the market vaults, only used as a signer
+    #[account(seeds = [SEED_MARKET_AUTHORITY, market.key().as_ref()], bump = market.authority_bump)]
+    pub market_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [SEED_OPEN_ORDERS, market.key().as_ref(), owner.key().as_ref()],
+        bump = open_orders.bump,
+        has_one = owner,
+    )]
+    pub open_orders: Account<'info, OpenOrders>,
+    pub owner: Signer<'info>,
+    #[account(mut, address = market.base_vault @ RouterError::InvalidMarketAccount)]
+    pub base_vault: Account<'info, TokenAccount>,
+    #[account(mut, address = market.quote_vault @ RouterError::InvalidMarketAccount)]
+    pub quote_vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = market.base_mint)]
+    pub owner_base_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = market.quote_mint)]
+    pub owner_quote_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn create_market(
+    ctx: Context<InitializeMarket>,
+    base_lot_size: u64,
+    tick_size: u64,
+    oracle: Option<OracleConfig>,
+) -> Result<()> {
+    require!(base_lot_size > 0 && tick_size > 0, RouterError::InvalidLotSize);
+    if let Some(oracle) = &oracle {
+        oracle.validate(ctx.accounts.base_mint.decimals, ctx.accounts.quote_mint.decimals)?;
+    }
+
+    let market_key = ctx.accounts.market.key();
+
+    let bids = &mut ctx.accounts.bids;
+    bids.market = market_key;
+    bids.side = OrderSide::Bid;
+    bids.orders = vec![];
+
+    let asks = &mut ctx.accounts.asks;
+    asks.market = market_key;
+    asks.side = OrderSide::Ask;
+    asks.orders = vec![];
+
+    let event_queue = &mut ctx.accounts.event_queue;
+    event_queue.market = market_key;
+    event_queue.seq_num = 0;
+    event_queue.events = vec![];
+
+    let market = &mut ctx.accounts.market;
+    market.base_mint = ctx.accounts.base_mint.key();
+    market.quote_mint = ctx.accounts.quote_mint.key();
+    market.base_vault = ctx.accounts.base_vault.key();
+    market.quote_vault = ctx.accounts.quote_vault.key();
+    market.authority_bump = ctx.bumps.market_authority;
+    market.base_lot_size = base_lot_size;
+    market.tick_size = tick_size;
+    market.bids = ctx.accounts.bids.key();
+    market.asks = ctx.accounts.asks.key();
+    market.event_queue = ctx.accounts.event_queue.key();
+    market.next_order_id = 1;
+    market.oracle = oracle;
+    Ok(())
+}
+
+pub fn register_trader(ctx: Context<CreateOpenOrders>) -> Result<()> {
+    let open_orders = &mut ctx.accounts.open_orders;
+    open_orders.bump = ctx.bumps.open_orders;
+    open_orders.market = ctx.accounts.market.key();
+    open_orders.owner = ctx.accounts.owner.key();
+    open_orders.base_free = 0;
+    open_orders.base_locked = 0;
+    open_orders.quote_free = 0;
+    open_orders.quote_locked = 0;
+    Ok(())
+}
+
+pub fn submit_order(ctx: Context<PlaceOrder>, args: PlaceOrderArgs) -> Result<()> {
+    let PlaceOrderArgs {
+        side,
+        order_type,
+        amount,
+        price,
+        self_trade_behavior,
+    } = args;
+
+    let market = &mut ctx.accounts.market;
+
+    // Market asks sweep at any price, so they don't need one
+    let is_market_ask = order_type == OrderType::Market && side == OrderSide::Ask;
+    require!(amount > 0 && (price > 0 || is_market_ask), RouterError::InvalidOrder);
+    require!(
+        amount % market.base_lot_size == 0 && price % market.tick_size == 0,
+        RouterError::InvalidOrderSize
+    );
+
+    let mut order = Order {
+        id: market.next_order_id,
+        owner: ctx.accounts.owner.key(),
+        side,
+        amount,
+        price,
+    };
+    market.next_order_id += 1;
+
+    let transfer_amount = locked_amount(side, amount, price)?;
+
+    // Escrowed funds stay locked until the order fills or is cancelled
+    ctx.accounts.open_orders.lock(side, transfer_amount)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        transfer_amount,
+    )?;
+
+    let (own_slab, opposite_slab) = match side {
+        OrderSide::Bid => (&mut ctx.accounts.bids, &mut ctx.accounts.asks),
+        OrderSide::Ask => (&mut ctx.accounts.asks, &mut ctx.accounts.bids),
+    };
+
+    if order_type == OrderType::PostOnly {
+        require!(
+            !opposite_slab.best().is_some_and(|best| crosses(side, price, best.price)),
+            RouterError::PostOnlyWouldCross
+        );
+    } else {
+        let limit_price = if is_market_ask { 0 } else { price };
+        let (filled_base, filled_quote) = match_order(
+            &mut order,
+            limit_price,
+            self_trade_behavior,
+            opposite_slab,
+            &mut ctx.accounts.event_queue,
+            &mut ctx.accounts.open_orders,
+        )?;
+
+        // Market orders have no meaningful limit, so check their average price against the oracle
+        if let (OrderType::Market, Some(oracle)) = (order_type, &ctx.accounts.market.oracle) {
+            let price_account = ctx.accounts.price_account.as_ref().ok_or(RouterError::InvalidOracleAccount)?;
+            oracle.check_execution_price(price_account, filled_base, filled_quote)?;
+        }
+    }
+
+    if order.amount > 0 {
+        match order_type {
+            OrderType::Limit | OrderType::PostOnly => own_slab.insert(order)?,
+            OrderType::ImmediateOrCancel | OrderType::Market => {
+                let unfilled = locked_amount(side, order.amount, order.price)?;
+                ctx.accounts.open_orders.unlock(side, unfilled)?;
+            }
+            OrderType::FillOrKill => return err!(RouterError::FillOrKillNotFilled),
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove a resting order and unlock its funds right away, so cancelling never waits on the crank
+pub fn cancel_resting_order(ctx: Context<CancelOrder>, side: OrderSide, order_id: u64) -> Result<()> {
+    let slab = match side {
+        OrderSide::Bid => &mut ctx.accounts.bids,
+        OrderSide::Ask => &mut ctx.accounts.asks,
+    };
+
+    let order = slab.orders
+        .iter()
+        .find(|o| o.id == order_id)
+        .ok_or(RouterError::OrderNotFound)?;
+    require_keys_eq!(order.owner, ctx.accounts.owner.key(), RouterError::NotOrderOwner);
+
+    let order = slab.remove(order_id).ok_or(RouterError::OrderNotFound)?;
+    ctx.accounts.open_orders.unlock(order.side, locked_amount(order.side, order.amount, order.price)?)
+}
+
+/// Match crossing orders with price-time priority, recording each fill on the event queue.
+/// Stops early once the queue is full.
+pub fn match_orders(ctx: Context<ProcessOrders>, limit: u16) -> Result<()> {
+    let bids = &mut ctx.accounts.bids;
+    let asks = &mut ctx.accounts.asks;
+    let event_queue = &mut ctx.accounts.event_queue;
+
+    for _ in 0..limit {
+        if event_queue.is_full() {
+            break;
+        }
+        let (Some(bid), Some(ask)) = (bids.best(), asks.best()) else {
+            break;
+        };
+        if bid.price < ask.price {
+            break;
+        }
+
+        // The order that rested first is the maker and sets the price
+        let (maker, taker) = if bid.id < ask.id { (bid, ask) } else { (ask, bid) };
+        let amount = bid.amount.min(ask.amount);
+
+        event_queue.push(MarketEvent::Fill {
+            maker: maker.owner,
+            taker: taker.owner,
+            maker_order_id: maker.id,
+            taker_order_id: taker.id,
+            taker_side: taker.side,
+            price: maker.price,
+            taker_price: taker.price,
+            amount,
+        })?;
+
+        fill_best(bids, amount);
+        fill_best(asks, amount);
+    }
+
+    Ok(())
+}
+
+/// Apply up to `limit` events from the front of the queue to the users' open orders.
+/// Stops early at the first event whose open orders accounts were not provided.
+pub fn apply_events<'info>(ctx: Context<'_, '_, 'info, 'info, ConsumeEvents<'info>>, limit: u16) -> Result<()> {
+    let market_key = ctx.accounts.market.key();
+
+    let mut open_orders_accounts = Vec::with_capacity(ctx.remaining_accounts.len());
+    for account_info in ctx.remaining_accounts {
+        if open_orders_accounts.iter().any(|o: &Account<OpenOrders>| o.key() == account_info.key()) {
+            continue;
+        }
+        let open_orders = Account::<OpenOrders>::try_from(account_info)?;
+        require_keys_eq!(open_orders.market, market_key, RouterError::InvalidMarketAccount);
+        open_orders_accounts.push(open_orders);
+    }
+
+    let event_queue = &mut ctx.accounts.event_queue;
+    let mut consumed = 0;
+
+    for event in event_queue.events.iter().take(usize::from(limit)) {
+        let MarketEvent::Fill { maker, taker, taker_side, price, taker_price, amount, .. } = *event;
+        let maker_side = match taker_side {
+            OrderSide::Bid => OrderSide::Ask,
+            OrderSide::Ask => OrderSide::Bid,
+        };
+        let maker_index = open_orders_accounts.iter().position(|o| o.owner == maker);
+        let taker_index = open_orders_accounts.iter().position(|o| o.owner == taker);
+        let (Some(maker_index), Some(taker_index)) = (maker_index, taker_index) else {
+            break;
+        };
+
+        open_orders_accounts[maker_index].apply_fill(maker_side, amount, price, price)?;
+        open_orders_accounts[taker_index].apply_fill(taker_side, amount, price, taker_price)?;
+        emit!(MarketEventLog { event: event.clone() });
+        consumed += 1;
+    }
+
+    require!(
+        consumed > 0 || limit == 0 || event_queue.events.is_empty(),
+        RouterError::MissingOpenOrders
+    );
+    event_queue.events.drain(..consumed);
+
+    for open_orders in &open_orders_accounts {
+        open_orders.exit(ctx.program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Withdraw all free balances back to the owner
+pub fn settle_free_balances(ctx: Context<SettleFunds>) -> Result<()> {
+    let base_amount = ctx.accounts.open_orders.base_free;
+    let quote_amount = ctx.accounts.open_orders.quote_free;
+    transfer_free_balances(ctx, base_amount, quote_amount)
+}
+
+/// Withdraw part of the free balances back to the owner
+pub fn withdraw_free_balances(ctx: Context<SettleFunds>, base_amount: u64, quote_amount: u64) -> Result<()> {
+    transfer_free_balances(ctx, base_amount, quote_amount)
+}
+
+fn transfer_free_balances(ctx: Context<SettleFunds>, base_amount: u64, quote_amount: u64) -> Result<()> {
+    let open_orders = &mut ctx.accounts.open_orders;
+    open_orders.base_free = open_orders.base_free
+        .checked_sub(base_amount)
+        .ok_or(RouterError::InsufficientBalance)?;
+    open_orders.quote_free = open_orders.quote_free
+        .checked_sub(quote_amount)
+        .ok_or(RouterError::InsufficientBalance)?;
+
+    let market_key = ctx.accounts.market.key();
+    let seeds = &[
+        SEED_MARKET_AUTHORITY,
+        market_key.as_ref(),
+        &[ctx.accounts.market.authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if base_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.base_vault.to_account_info(),
+                    to: ctx.accounts.owner_base_account.to_account_info(),
+                    authority: ctx.accounts.market_authority.to_account_info(),
+                },
+                signer,
+            ),
+            base_amount,
+        )?;
+    }
+
+    if quote_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.quote_vault.to_account_info(),
+                    to: ctx.accounts.owner_quote_account.to_account_info(),
+                    authority: ctx.accounts.market_authority.to_account_info(),
+                },
+                signer,
+            ),
+            quote_amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Cross `taker` against the opposite side of the book until it is filled, the best
+/// resting price is worse than `limit_price` or the event queue is full. Self-trades
+/// only touch the taker's own open orders, so they are settled right away.
+/// Returns the filled base and quote amounts.
+fn match_order(
+    taker: &mut Order,
+    limit_price: u64,
+    self_trade_behavior: SelfTradeBehavior,
+    opposite_slab: &mut OrderSlab,
+    event_queue: &mut EventQueue,
+    taker_open_orders: &mut OpenOrders,
+) -> Result<(u64, u64)> {
+    let mut filled_base: u64 = 0;
+    let mut filled_quote: u64 = 0;
+
+    while taker.amount > 0 {
+        let Some(maker) = opposite_slab.best() else {
+            break;
+        };
+        if !crosses(taker.side, limit_price, maker.price) {
+            break;
+        }
+
+        let amount = taker.amount.min(maker.amount);
+
+        if maker.owner == taker.owner {
+            match self_trade_behavior {
+                SelfTradeBehavior::AbortTransaction => return err!(RouterError::SelfTrade),
+                SelfTradeBehavior::CancelProvide => {
+                    let maker = opposite_slab.pop_best().ok_or(RouterError::OrderNotFound)?;
+                    taker_open_orders.unlock(maker.side, locked_amount(maker.side, maker.amount, maker.price)?)?;
+                }
+                SelfTradeBehavior::DecrementTake => {
+                    taker_open_orders.unlock(maker.side, locked_amount(maker.side, amount, maker.price)?)?;
+                    fill_best(opposite_slab, amount);
+                    taker.amount -= amount;
+                    taker_open_orders.unlock(taker.side, locked_amount(taker.side, amount, taker.price)?)?;
+                }
+            }
+            continue;
+        }
+
+        // The rest is handled by the order type, as if the book had run out
+        if event_queue.is_full() {
+            break;
+        }
+        event_queue.push(MarketEvent::Fill {
+            maker: maker.owner,
+            taker: taker.owner,
+            maker_order_id: maker.id,
+            taker_order_id: taker.id,
+            taker_side: taker.side,
+            price: maker.price,
+            taker_price: taker.price,
+            amount,
+        })?;
+
+        filled_base = filled_base.checked_add(amount).ok_or(RouterError::ArithmeticOverflow)?;
+        filled_quote = amount
+            .checked_mul(maker.price)
+            .and_then(|quote| filled_quote.checked_add(quote))
+            .ok_or(RouterError::ArithmeticOverflow)?;
+
+        fill_best(opposite_slab, amount);
+        taker.amount -= amount;
+    }
+
+    Ok((filled_base, filled_quote))
+}
+
+/// Whether an order on `side` at `price` crosses a resting order at `resting_price`
+fn crosses(side: OrderSide, price: u64, resting_price: u64) -> bool {
+    match side {
+        OrderSide::Bid => resting_price <= price,
+        OrderSide::Ask => resting_price >= price,
+    }
+}
+
+/// Funds escrowed for an order: quote for bids, base for asks
+fn locked_amount(side: OrderSide, amount: u64, price: u64) -> Result<u64> {
+    match side {
+        OrderSide::Bid => amount.checked_mul(price).ok_or(error!(RouterError::ArithmeticOverflow)),
+        OrderSide::Ask => Ok(amount),
+    }
+}
+
+fn fill_best(slab: &mut OrderSlab, amount: u64) {
+    if let Some(order) = slab.best_mut() {
+        order.amount -= amount;
+        if order.amount == 0 {
+            slab.pop_best();
+        }
+    }
+}
+
+#[event]
+pub struct MarketEventLog {
+    pub event: MarketEvent,
+}
@@ -1,6 +1,13 @@
 pub mod swap;
 pub mod conditional;
 pub mod reclaim;
+mod market;
+pub mod oracle;
+mod stream;
 
 pub use conditional::*;
 pub use reclaim::*;
+pub use market::*;
+pub use swap::*;
+pub use oracle::*;
+pub use stream::*;
@@ -0,0 +1,39 @@
+//! Mock price accounts for local tests. `#[program]` can't leave instructions
+//! out, so these refuse to run unless built with the `test-oracle` feature.
+
+use anchor_lang::prelude::*;
+use crate::errors::RouterError;
+use crate::oracle::MockPriceAccount;
+
+#[derive(Accounts)]
+pub struct InitializeMockPrice<'info> {
+    #[account(init, payer = authority, space = MockPriceAccount::SPACE)]
+    pub price_account: Account<'info, MockPriceAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMockPrice<'info> {
+    #[account(mut, has_one = authority)]
+    pub price_account: Account<'info, MockPriceAccount>,
+    pub authority: Signer<'info>,
+}
+
+pub fn initialize_mock_price(ctx: Context<InitializeMockPrice>) -> Result<()> {
+    require!(cfg!(feature = "test-oracle"), RouterError::MockOracleDisabled);
+    ctx.accounts.price_account.authority = ctx.accounts.authority.key();
+    Ok(())
+}
+
+/// Publish a new mock price stamped with the current time
+pub fn set_mock_price(ctx: Context<SetMockPrice>, price: i64, conf: u64, expo: i32) -> Result<()> {
+    require!(cfg!(feature = "test-oracle"), RouterError::MockOracleDisabled);
+    let price_account = &mut ctx.accounts.price_account;
+    price_account.price = price;
+    price_account.conf = conf;
+    price_account.expo = expo;
+    price_account.publish_time = Clock::get()?.unix_timestamp;
+    Ok(())
+}
@@ -7,9 +7,18 @@ use reclaim::state::ClaimData as ReclaimClaimData;
 use reclaim::state::ClaimInfo as ReclaimClaimInfo;
 use reclaim::state::SignedClaim as ReclaimSignedClaim;
 use reclaim::state::Witness;
-use reclaim::state::{Epoch, EpochConfig};
+use reclaim::state::{Dapp, Epoch, EpochConfig, Group};
+use anchor_lang::solana_program::keccak;
+use crate::states::escrow::MAX_PROVIDER_LEN;
 use crate::errors::RouterError;
 
+pub const SEED_VERIFICATION: &[u8] = b"verification";
+pub const SEED_VERIFICATION_INDEX: &[u8] = b"verification_index";
+pub const MAX_INDEXED_VERIFICATIONS: usize = 16;
+// Entries per `list_verifications` page: 4 + 12 * 73 + 2 bytes, within the 1024-byte return data limit
+pub const MAX_LISTED_VERIFICATIONS: usize = 12;
+pub const VERIFICATION_TTL: i64 = 30 * 24 * 60 * 60; // 30 days
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VerifyArgs {
     pub claim_info: ClaimInfo,
@@ -42,27 +51,55 @@ pub struct ClaimData {
 }
 
 #[derive(Accounts)]
+#[instruction(args: VerifyArgs)]
 pub struct Verify<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
     pub epoch_config: Account<'info, EpochConfig>,
     pub epoch: Account<'info, Epoch>,
+    // Reclaim group of the claim's provider
+    pub reclaim_group: Account<'info, Group>,
+    /// CHECK: Created by Reclaim, which checks its address; marks the claim as used
+    #[account(mut)]
+    pub claim_nullifier: UncheckedAccount<'info>,
+    // Government dapp the verification is made for, if any; Reclaim applies its policy
+    #[account(mut)]
+    pub reclaim_dapp: Option<Account<'info, Dapp>>,
+    /// CHECK: Checked by Reclaim against the dapp fee recipient
+    #[account(mut)]
+    pub dapp_fee_recipient: Option<UncheckedAccount<'info>>,
     pub reclaim_program: Program<'info, Reclaim>,
     pub system_program: Program<'info, System>,
+    // Re-verifying the same provider refreshes the existing record
     #[account(
-        init,
+        init_if_needed,
         payer = signer,
-        space = 8 + 32 + 64 + 8 + 1, // Adjust space as needed
-        seeds = [b"verification", signer.key().as_ref()],
+        space = VerificationResult::SPACE,
+        seeds = [
+            SEED_VERIFICATION,
+            signer.key().as_ref(),
+            &provider_hash(&args.claim_info.provider),
+        ],
         bump
     )]
     pub verification_result: Account<'info, VerificationResult>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = VerificationIndex::SPACE,
+        seeds = [SEED_VERIFICATION_INDEX, signer.key().as_ref()],
+        bump
+    )]
+    pub verification_index: Account<'info, VerificationIndex>,
 }
 
 /// Accounts required for listing verifications
 #[derive(Accounts)]
 pub struct ListVerifications<'info> {
-    pub signer: Signer<'info>,
+    /// CHECK: Any user's verifications can be listed
+    pub user: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_VERIFICATION_INDEX, user.key().as_ref()], bump = verification_index.bump)]
+    pub verification_index: Account<'info, VerificationIndex>,
 }
 
 /// Accounts required for revoking a verification
@@ -72,54 +109,203 @@ pub struct RevokeVerification<'info> {
     pub signer: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"verification", signer.key().as_ref()],
-        bump,
+        seeds = [
+            SEED_VERIFICATION,
+            signer.key().as_ref(),
+            &verification_result.provider_hash,
+        ],
+        bump = verification_result.bump,
         constraint = verification_result.signer == signer.key() @ RouterError::Unauthorized
     )]
     pub verification_result: Account<'info, VerificationResult>,
+    #[account(mut, seeds = [SEED_VERIFICATION_INDEX, signer.key().as_ref()], bump = verification_index.bump)]
+    pub verification_index: Account<'info, VerificationIndex>,
 }
 
-/// Struct to store verification results
+/// Struct to store verification results, one per signer and provider
 #[account]
 pub struct VerificationResult {
+    pub bump: u8,
     pub signer: Pubkey,
     pub provider: String,
+    pub provider_hash: [u8; 32],
+    // Epoch config whose witnesses attested the claim
+    pub epoch_config: Pubkey,
+    pub epoch_index: u32,
+    pub claim_identifier: [u8; 32],
+    // Reclaim dapp whose policy the claim was verified under, if any
+    pub dapp: Option<Pubkey>,
     pub verified_at: i64,
+    pub expires_at: i64,
     pub is_active: bool,
 }
 
+impl VerificationResult {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // signer
+        4 + MAX_PROVIDER_LEN + // provider
+        32 + // provider_hash
+        32 + // epoch_config
+        4 + // epoch_index
+        32 + // claim_identifier
+        1 + 32 + // dapp
+        8 + // verified_at
+        8 + // expires_at
+        1; // is_active
+
+    /// Active and not yet expired at `now`
+    pub fn is_valid(&self, now: i64) -> bool {
+        self.is_active && now < self.expires_at
+    }
+}
 
-/// Verify a claim using the Reclaim protocol
-pub fn verify(ctx: Context<Verify>, args: VerifyArgs) -> Result<()> {
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerificationIndexEntry {
+    pub provider_hash: [u8; 32],
+    pub verification: Pubkey,
+    pub is_active: bool,
+    pub expires_at: i64,
+}
+
+impl VerificationIndexEntry {
+    pub const SIZE: usize = 32 + 32 + 1 + 8;
+}
+
+/// Every verification record held by a user, one entry per provider
+#[account]
+pub struct VerificationIndex {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub entries: Vec<VerificationIndexEntry>,
+}
+
+impl VerificationIndex {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // owner
+        4 + (MAX_INDEXED_VERIFICATIONS * VerificationIndexEntry::SIZE); // entries
+
+    /// Insert or refresh the entry for `result`'s provider.
+    /// A full index first drops the entries that expired by `now`.
+    pub fn upsert(&mut self, verification: Pubkey, result: &VerificationResult, now: i64) -> Result<()> {
+        let entry = VerificationIndexEntry {
+            provider_hash: result.provider_hash,
+            verification,
+            is_active: result.is_active,
+            expires_at: result.expires_at,
+        };
+
+        match self.entries.iter_mut().find(|e| e.provider_hash == result.provider_hash) {
+            Some(existing) => *existing = entry,
+            None => {
+                if self.entries.len() >= MAX_INDEXED_VERIFICATIONS {
+                    self.entries.retain(|e| e.is_active && now < e.expires_at);
+                }
+                require!(
+                    self.entries.len() < MAX_INDEXED_VERIFICATIONS,
+                    RouterError::VerificationIndexFull
+                );
+                self.entries.push(entry);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drop the entry for `provider_hash`, if any
+    pub fn remove(&mut self, provider_hash: &[u8; 32]) {
+        self.entries.retain(|e| e.provider_hash != *provider_hash);
+    }
+}
+
+/// One page of `list_verifications`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerificationPage {
+    pub entries: Vec<VerificationIndexEntry>,
+    // Offset of the next page, if there are more entries
+    pub next_offset: Option<u8>,
+}
+
+/// A Reclaim credential a government requires before someone joins.
+/// The government registers a Reclaim dapp for the provider with `create_dapp`, and only
+/// claims verified under that dapp's policy count.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ReclaimRequirement {
+    pub provider_hash: [u8; 32],
+    // Epoch config whose witnesses must have attested the claim
+    pub epoch_config: Pubkey,
+    // Reclaim dapp of the government, whose policy the claim must have been verified under
+    pub dapp: Pubkey,
+}
+
+impl ReclaimRequirement {
+    pub const SIZE: usize = 32 + 32 + 32;
+
+    /// Fail unless `verification` is a valid credential of `user` satisfying this requirement
+    pub fn check(&self, verification: Option<&VerificationResult>, user: &Pubkey) -> Result<()> {
+        let verification = verification.ok_or(RouterError::ReclaimVerificationRequired)?;
+
+        require_keys_eq!(verification.signer, *user, RouterError::InvalidReclaimVerification);
+        require!(
+            verification.provider_hash == self.provider_hash
+                && verification.epoch_config == self.epoch_config
+                && verification.dapp == Some(self.dapp),
+            RouterError::InvalidReclaimVerification
+        );
+        require!(
+            verification.is_valid(Clock::get()?.unix_timestamp),
+            RouterError::InvalidReclaimVerification
+        );
+
+        Ok(())
+    }
+}
+
+/// Check a government's optional Reclaim requirement during invite redemption
+pub fn check_reclaim_requirement(
+    requirement: Option<&ReclaimRequirement>,
+    verification: Option<&VerificationResult>,
+    user: &Pubkey,
+) -> Result<()> {
+    match requirement {
+        Some(requirement) => requirement.check(verification, user),
+        None => Ok(()),
+    }
+}
+
+/// Hash of a provider name, used to key verification records
+pub fn provider_hash(provider: &str) -> [u8; 32] {
+    keccak::hash(provider.as_bytes()).to_bytes()
+}
+
+pub fn find_verification_address(signer: &Pubkey, provider: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_VERIFICATION, signer.as_ref(), &provider_hash(provider)],
+        &crate::ID,
+    )
+}
+
+
+/// Verify a claim through Reclaim's `verify_proof`. The signer must be the claim's context address
+/// and pays for the nullifier that stops the claim from being replayed.
+pub fn verify_reclaim_proof<'info>(
+    reclaim_program: AccountInfo<'info>,
+    accounts: VerifyProof<'info>,
+    args: VerifyArgs,
+) -> Result<()> {
     let VerifyArgs {
         claim_info,
         signed_claim,
     } = args;
 
-    // Signer and rent payer
-    let signer_account_info = ctx.accounts.signer.to_account_info();
-    // Program Account infos
-    let reclaim_program_info = ctx.accounts.reclaim_program.to_account_info();
-    
-    // Proof verification
-    let epoch_config_account_info = ctx.accounts.epoch_config.to_account_info();
-    let epoch_account_info = ctx.accounts.epoch.to_account_info();
-
-    // Perform the verification using Reclaim's CPI
     verify_proof(
-        CpiContext::new(
-            reclaim_program_info,
-            VerifyProof {
-                epoch_config: epoch_config_account_info,
-                epoch: epoch_account_info,
-                signer: signer_account_info,
-            },
-        ),
+        CpiContext::new(reclaim_program, accounts),
         VerifyProofArgs {
             claim_info: ReclaimClaimInfo {
                 parameters: claim_info.parameters,
                 context_message: claim_info.context_message,
-                provider: claim_info.provider.clone(),
+                provider: claim_info.provider,
                 context_address: claim_info.context_address,
             },
             signed_claim: ReclaimSignedClaim {
@@ -132,15 +318,57 @@ pub fn verify(ctx: Context<Verify>, args: VerifyArgs) -> Result<()> {
                 signatures: signed_claim.signatures,
             },
         },
+    )
+}
+
+/// Verify a claim using the Reclaim protocol
+pub fn verify(ctx: Context<Verify>, args: VerifyArgs) -> Result<()> {
+    let provider = args.claim_info.provider.clone();
+    require!(
+        !provider.is_empty() && provider.len() <= MAX_PROVIDER_LEN,
+        RouterError::InvalidProvider
+    );
+    let epoch_index = args.signed_claim.claim_data.epoch_index;
+    let claim_identifier = args.signed_claim.claim_data.identifier;
+
+    // Perform the verification using Reclaim's CPI
+    verify_reclaim_proof(
+        ctx.accounts.reclaim_program.to_account_info(),
+        VerifyProof {
+            group: ctx.accounts.reclaim_group.to_account_info(),
+            nullifier: ctx.accounts.claim_nullifier.to_account_info(),
+            epoch: ctx.accounts.epoch.to_account_info(),
+            epoch_config: ctx.accounts.epoch_config.to_account_info(),
+            signer: ctx.accounts.signer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            dapp: ctx.accounts.reclaim_dapp.as_ref().map(|dapp| dapp.to_account_info()),
+            fee_recipient: ctx.accounts.dapp_fee_recipient.as_ref().map(|account| account.to_account_info()),
+        },
+        args,
     )?;
 
     // Store the verification result
+    let verified_at = Clock::get()?.unix_timestamp;
     let verification_result = &mut ctx.accounts.verification_result;
+    verification_result.bump = ctx.bumps.verification_result;
     verification_result.signer = ctx.accounts.signer.key();
-    verification_result.provider = claim_info.provider;
-    verification_result.verified_at = Clock::get()?.unix_timestamp;
+    verification_result.provider_hash = provider_hash(&provider);
+    verification_result.provider = provider;
+    verification_result.epoch_config = ctx.accounts.epoch_config.key();
+    verification_result.epoch_index = epoch_index;
+    verification_result.claim_identifier = claim_identifier;
+    verification_result.dapp = ctx.accounts.reclaim_dapp.as_ref().map(|dapp| dapp.key());
+    verification_result.verified_at = verified_at;
+    verification_result.expires_at = verified_at
+        .checked_add(VERIFICATION_TTL)
+        .ok_or(RouterError::ArithmeticOverflow)?;
     verification_result.is_active = true;
 
+    let verification_index = &mut ctx.accounts.verification_index;
+    verification_index.bump = ctx.bumps.verification_index;
+    verification_index.owner = ctx.accounts.signer.key();
+    verification_index.upsert(verification_result.key(), verification_result, verified_at)?;
+
     // Emit the verification event
     emit!(VerificationCompleteEvent {
         signer: ctx.accounts.signer.key(),
@@ -151,11 +379,32 @@ pub fn verify(ctx: Context<Verify>, args: VerifyArgs) -> Result<()> {
     Ok(())
 }
 
-/// List all verifications for a specific signer
-pub fn list_verifications(ctx: Context<ListVerifications>) -> Result<()> {
-    // The actual listing is done client-side by fetching VerificationResult accounts
-    // So this particular instruction is a no-op. It is just used as a namespace for fetching accounts
-    Ok(())
+/// List a user's verifications, optionally only those of `provider` or only valid ones,
+/// starting at the `offset`-th match. Returned through the transaction's return data, so
+/// it can be simulated, at most `MAX_LISTED_VERIFICATIONS` entries at a time.
+pub fn list_verifications(
+    ctx: Context<ListVerifications>,
+    provider: Option<String>,
+    active_only: bool,
+    offset: u8,
+) -> Result<VerificationPage> {
+    let now = Clock::get()?.unix_timestamp;
+    let provider_hash = provider.as_deref().map(provider_hash);
+
+    let mut matches = ctx.accounts.verification_index.entries
+        .iter()
+        .filter(|e| match provider_hash {
+            Some(hash) => e.provider_hash == hash,
+            None => true,
+        })
+        .filter(|e| !active_only || (e.is_active && now < e.expires_at))
+        .skip(usize::from(offset));
+
+    let entries: Vec<_> = matches.by_ref().take(MAX_LISTED_VERIFICATIONS).cloned().collect();
+    // Offsets stay below MAX_INDEXED_VERIFICATIONS
+    let next_offset = matches.next().map(|_| offset + entries.len() as u8);
+
+    Ok(VerificationPage { entries, next_offset })
 }
 
 /// Revoke a specific verification
@@ -169,6 +418,9 @@ pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
 
     verification_result.is_active = false;
 
+    // Free the slot, so revoked credentials never fill the index
+    ctx.accounts.verification_index.remove(&verification_result.provider_hash);
+
     emit!(VerificationRevokedEvent {
         signer: verification_result.signer,
         provider: verification_result.provider.clone(),
@@ -189,7 +441,7 @@ pub struct CreateEpochConfig<'info> {
     #[account(
         init,
         payer = authority,
-        space =  8 + 1 + 32 + 32 + 8 + 4 + 4 + (32 * MAX_EPOCHS),
+        space =  8 + 1 + 32 + 32 + 8 + 8 + 8 + (1 + 32) + 4 + 4 + (32 * MAX_EPOCHS),
         seeds = [b"epoch_config", authority.key().as_ref()],
         bump
     )]
@@ -228,6 +480,9 @@ pub fn create_epoch_config(ctx: Context<CreateEpochConfig>) -> Result<()> {
     epoch_config.create_key = ctx.accounts.authority.key();
     epoch_config.deployer = ctx.accounts.authority.key();
     epoch_config.epoch_duration_seconds = 86400; // 1 day, adjust as needed
+    epoch_config.grace_period_seconds = 3600; // 1 hour for in-flight proofs
+    epoch_config.max_claim_age_seconds = 86400; // 1 day
+    epoch_config.witness_authority = None;
     epoch_config.epoch_index = 0;
     epoch_config.epochs = vec![];
     Ok(())
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::states::stream::*;
+use crate::errors::RouterError;
+
+#[derive(Accounts)]
+#[instruction(stream_id: u64)]
+pub struct CreatePaymentStream<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PaymentStream::SPACE,
+        seeds = [SEED_STREAM, authority.key().as_ref(), &stream_id.to_le_bytes()],
+        bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+    // The paying government, e.g. its router executor
+    pub authority: Signer<'info>,
+    // Owner or approved delegate of the treasury token account
+    pub funder: Signer<'info>,
+    #[account(mut, token::mint = mint, token::token_program = token_program)]
+    pub funding_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub recipient: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SEED_STREAM_VAULT, stream.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = stream,
+        token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimStream<'info> {
+    #[account(mut, has_one = recipient, has_one = mint, has_one = rent_payer)]
+    pub stream: Account<'info, PaymentStream>,
+    pub recipient: Signer<'info>,
+    #[account(mut, token::mint = mint, token::token_program = token_program)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [SEED_STREAM_VAULT, stream.key().as_ref()], bump)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Checked against the stream; receives the rent once the stream is paid out
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(mut, has_one = authority, has_one = mint, has_one = rent_payer)]
+    pub stream: Account<'info, PaymentStream>,
+    pub authority: Signer<'info>,
+    #[account(mut, address = stream.refund_account)]
+    pub refund_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [SEED_STREAM_VAULT, stream.key().as_ref()], bump)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Checked against the stream; receives the rent
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn create_stream(
+    ctx: Context<CreatePaymentStream>,
+    stream_id: u64,
+    rate: StreamRate,
+    deposit: u64,
+) -> Result<()> {
+    let rate_amount = match rate {
+        StreamRate::PerSecond(amount) | StreamRate::PerEpoch(amount) => amount,
+    };
+    require!(rate_amount > 0 && deposit > 0, RouterError::InvalidStreamRate);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.funding_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        deposit,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Token-2022 transfer fees may shrink the deposit, so stream what actually arrived
+    ctx.accounts.vault.reload()?;
+
+    let clock = Clock::get()?;
+    let stream = &mut ctx.accounts.stream;
+    stream.bump = ctx.bumps.stream;
+    stream.stream_id = stream_id;
+    stream.authority = ctx.accounts.authority.key();
+    stream.recipient = ctx.accounts.recipient.key();
+    stream.mint = ctx.accounts.mint.key();
+    stream.refund_account = ctx.accounts.funding_token_account.key();
+    stream.rent_payer = ctx.accounts.payer.key();
+    stream.rate = rate;
+    stream.start_time = clock.unix_timestamp;
+    stream.start_epoch = clock.epoch;
+    stream.deposited = ctx.accounts.vault.amount;
+    stream.withdrawn = 0;
+
+    Ok(())
+}
+
+/// Withdraw everything streamed so far to the recipient, closing the stream
+/// once it is paid out
+pub fn claim_stream(ctx: Context<ClaimStream>) -> Result<()> {
+    let clock = Clock::get()?;
+    let stream = &mut ctx.accounts.stream;
+    let amount = stream.claimable_amount(clock.unix_timestamp, clock.epoch)?;
+    require!(amount > 0, RouterError::NothingToClaim);
+
+    stream.withdrawn = stream.withdrawn.checked_add(amount).ok_or(RouterError::ArithmeticOverflow)?;
+    let paid_out = stream.withdrawn == stream.deposited;
+
+    // Anything sent to the vault outside the stream goes out with the last claim
+    let amount = if paid_out { ctx.accounts.vault.amount } else { amount };
+    transfer_from_vault(
+        stream,
+        &ctx.accounts.vault,
+        ctx.accounts.recipient_token_account.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    if paid_out {
+        close_stream(stream, &ctx.accounts.vault, &ctx.accounts.rent_payer, &ctx.accounts.token_program)?;
+    }
+    Ok(())
+}
+
+/// Stop the stream, returning what has not streamed yet to the funding treasury account.
+/// What has streamed stays in the vault for the recipient to claim, so cancelling
+/// never depends on the recipient's token account.
+pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+    let clock = Clock::get()?;
+    let stream = &mut ctx.accounts.stream;
+    let recipient_amount = stream.claimable_amount(clock.unix_timestamp, clock.epoch)?;
+    let refund_amount = ctx.accounts.vault.amount
+        .checked_sub(recipient_amount)
+        .ok_or(RouterError::ArithmeticOverflow)?;
+
+    if refund_amount > 0 {
+        transfer_from_vault(
+            stream,
+            &ctx.accounts.vault,
+            ctx.accounts.refund_token_account.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            refund_amount,
+        )?;
+    }
+
+    // Nothing more streams past what the recipient is owed now
+    stream.deposited = stream.withdrawn
+        .checked_add(recipient_amount)
+        .ok_or(RouterError::ArithmeticOverflow)?;
+
+    if recipient_amount == 0 {
+        close_stream(stream, &ctx.accounts.vault, &ctx.accounts.rent_payer, &ctx.accounts.token_program)?;
+    }
+    Ok(())
+}
+
+/// Close the emptied vault and the stream, returning their rent to the stream's payer
+fn close_stream<'info>(
+    stream: &mut Account<'info, PaymentStream>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    rent_payer: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    let stream_id = stream.stream_id.to_le_bytes();
+    let seeds = &[SEED_STREAM, stream.authority.as_ref(), &stream_id, &[stream.bump]];
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault.to_account_info(),
+            destination: rent_payer.to_account_info(),
+            authority: stream.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    stream.close(rent_payer.to_account_info())
+}
+
+fn transfer_from_vault<'info>(
+    stream: &Account<'info, PaymentStream>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let stream_id = stream.stream_id.to_le_bytes();
+    let seeds = &[SEED_STREAM, stream.authority.as_ref(), &stream_id, &[stream.bump]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: destination,
+                authority: stream.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+        mint.decimals,
+    )
+}
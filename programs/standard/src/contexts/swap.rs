@@ -1,268 +1,413 @@
-// use anchor_lang::prelude::*;
-// use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
-// use anchor_spl::associated_token::AssociatedToken;
-// use pyth_sdk_solana::state::SolanaPriceAccount;
-
-// // Constants
-// const RATE_PRECISION: u64 = 1_000_000;  // 6 decimal places
-// const FEE_PRECISION: u64 = 10_000;      // 4 decimal places
-// const MAX_RATE_AGE: i64 = 60;           // 60 seconds
-
-
-// pub fn initialize_pool(
-//         ctx: Context<InitializePool>,
-//         initial_liquidity_amount: u64,
-//         swap_fee: u64,
-//     ) -> Result<()> {
-//         let pool = &mut ctx.accounts.pool;
-//         pool.token_a_mint = ctx.accounts.token_a_mint.key();
-//         pool.token_b_mint = ctx.accounts.token_b_mint.key();
-//         pool.token_a_reserve = ctx.accounts.token_a_reserve.key();
-//         pool.token_b_reserve = ctx.accounts.token_b_reserve.key();
-//         pool.swap_fee = swap_fee;
-//         pool.total_liquidity = initial_liquidity_amount;
-
-//         // Transfer initial liquidity
-//         token::transfer(
-//             CpiContext::new(
-//                 ctx.accounts.token_program.to_account_info(),
-//                 Transfer {
-//                     from: ctx.accounts.user_token_a.to_account_info(),
-//                     to: ctx.accounts.token_a_reserve.to_account_info(),
-//                     authority: ctx.accounts.user.to_account_info(),
-//                 },
-//             ),
-//             initial_liquidity_amount,
-//         )?;
-
-//         token::transfer(
-//             CpiContext::new(
-//                 ctx.accounts.token_program.to_account_info(),
-//                 Transfer {
-//                     from: ctx.accounts.user_token_b.to_account_info(),
-//                     to: ctx.accounts.token_b_reserve.to_account_info(),
-//                     authority: ctx.accounts.user.to_account_info(),
-//                 },
-//             ),
-//             initial_liquidity_amount,
-//         )?;
-
-//         // Trying out something different
-//         // let cpi_accounts = anchor_spl::associated_token::Create {
-//         //     payer: ctx.accounts.user.to_account_info(),
-//         //     associated_token: ctx.accounts.user.to_account_info(),
-//         //     authority: ctx.accounts.user.to_account_info(),
-//         //     mint: ctx.accounts.lp_mint.to_account_info(),
-//         //     system_program: ctx.accounts.system_program.to_account_info(),
-//         //     token_program: ctx.accounts.token_program.to_account_info(),
-//         // };
-//         // let cpi_program = ctx.accounts.associated_token_program.to_account_info();
-//         // let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-//         // anchor_spl::associated_token::create(cpi_ctx)?;
-    
-//         // // Mint LP tokens to the user
-//         // let user_lp_token = anchor_spl::associated_token::get_associated_token_address(
-//         //     &ctx.accounts.user.key(),
-//         //     &ctx.accounts.lp_mint.key(),
-//         // );
-
-//         // Mint LP tokens to the user
-//         token::mint_to(
-//             CpiContext::new(
-//                 ctx.accounts.token_program.to_account_info(),
-//                 token::MintTo {
-//                     mint: ctx.accounts.lp_mint.to_account_info(),
-//                     to: ctx.accounts.user_lp_token.to_account_info(),
-//                     authority: pool.to_account_info(),
-//                 },
-//             ),
-//             initial_liquidity_amount,
-//         )?;
-
-//         Ok(())
-//     }
-
-
-// pub fn swap(
-//         ctx: Context<Swap>,
-//         amount_in: u64,
-//         minimum_amount_out: u64,
-//     ) -> Result<()> {
-//         let pool = &ctx.accounts.pool;
-
-//         // Check if the price feed is fresh
-//         let price_feed = SolanaPriceAccount::account_info_to_feed(&ctx.accounts.pyth_price_feed).unwrap();
-//         let current_timestamp = Clock::get()?.unix_timestamp;
-//         let price = price_feed.get_price_no_older_than(current_timestamp, MAX_RATE_AGE.try_into().unwrap()).unwrap();
-
-//         // Calculate the amount out based on constant product formula
-//         let reserve_a = ctx.accounts.token_a_reserve.amount;
-//         let reserve_b = ctx.accounts.token_b_reserve.amount;
-//         let amount_out = calculate_amount_out(amount_in, reserve_a, reserve_b, pool.swap_fee)?;
-
-//         // Check slippage
-//         if amount_out < minimum_amount_out {
-//             return Err(ErrorCode::ExcessiveSlippage.into());
-//         }
-
-//         // Transfer tokens from user to pool
-//         token::transfer(
-//             CpiContext::new(
-//                 ctx.accounts.token_program.to_account_info(),
-//                 Transfer {
-//                     from: ctx.accounts.user_token_a.to_account_info(),
-//                     to: ctx.accounts.token_a_reserve.to_account_info(),
-//                     authority: ctx.accounts.user.to_account_info(),
-//                 },
-//             ),
-//             amount_in,
-//         )?;
-
-//         // Transfer tokens from pool to user
-//         token::transfer(
-//             CpiContext::new_with_signer(
-//                 ctx.accounts.token_program.to_account_info(),
-//                 Transfer {
-//                     from: ctx.accounts.token_b_reserve.to_account_info(),
-//                     to: ctx.accounts.user_token_b.to_account_info(),
-//                     authority: pool.to_account_info(),
-//                 },
-//                 &[&[b"pool", &[ctx.bumps.pool]]],
-//             ),
-//             amount_out,
-//         )?;
-
-//         // Emit swap event
-//         emit!(SwapEvent {
-//             user: ctx.accounts.user.key(),
-//             amount_in,
-//             amount_out,
-//             fee: amount_in * pool.swap_fee / FEE_PRECISION,
-//         });
-
-//         Ok(())
-// }
-
-//     // Add more instructions for adding/removing liquidity, updating fees, etc.
-
-
-// #[derive(Accounts)]
-// pub struct InitializePool<'info> {
-//     #[account(mut)]
-//     pub user: Signer<'info>,
-//     #[account(
-//         init,
-//         payer = user,
-//         space = 8 + 32 + 32 + 32 + 32 + 8 + 8,
-//         seeds = [b"pool"],
-//         bump
-//     )]
-//     pub pool: Box<Account<'info, Pool>>,
-//     /// CHECK: This account is not read or written in the program
-//     pub token_a_mint: UncheckedAccount<'info>,
-//     /// CHECK: This account is not read or written in the program
-//     pub token_b_mint: UncheckedAccount<'info>,
-//     #[account(
-//         init,
-//         payer = user,
-//         associated_token::mint = token_a_mint,
-//         associated_token::authority = pool,
-//     )]
-//     pub token_a_reserve: Box<Account<'info, TokenAccount>>,
-//     #[account(
-//         init,
-//         payer = user,
-//         associated_token::mint = token_b_mint,
-//         associated_token::authority = pool,
-//     )]
-//     pub token_b_reserve: Box<Account<'info, TokenAccount>>,
-//     #[account(
-//         init,
-//         payer = user,
-//         mint::decimals = 6,
-//         mint::authority = pool,
-//     )]
-//     pub lp_mint: Box<Account<'info, Mint>>,
-//     #[account(
-//         init,
-//         payer = user,
-//         associated_token::mint = lp_mint,
-//         associated_token::authority = user,
-//     )]
-//     pub user_lp_token: Box<Account<'info, TokenAccount>>,
-//     #[account(mut)]
-//     pub user_token_a: Box<Account<'info, TokenAccount>>,
-//     #[account(mut)]
-//     pub user_token_b: Box<Account<'info, TokenAccount>>,
-//     pub token_program: Program<'info, Token>,
-//     pub associated_token_program: Program<'info, AssociatedToken>,
-//     pub system_program: Program<'info, System>,
-//     pub rent: Sysvar<'info, Rent>,
-// }
-
-// #[derive(Accounts)]
-// pub struct Swap<'info> {
-//     #[account(mut)]
-//     pub user: Signer<'info>,
-//     #[account(
-//         seeds = [b"pool"],
-//         bump,
-//     )]
-//     pub pool: Account<'info, Pool>,
-//     #[account(
-//         mut,
-//         constraint = token_a_reserve.key() == pool.token_a_reserve
-//     )]
-//     pub token_a_reserve: Account<'info, TokenAccount>,
-//     #[account(
-//         mut,
-//         constraint = token_b_reserve.key() == pool.token_b_reserve
-//     )]
-//     pub token_b_reserve: Account<'info, TokenAccount>,
-//     #[account(mut)]
-//     pub user_token_a: Account<'info, TokenAccount>,
-//     #[account(mut)]
-//     pub user_token_b: Account<'info, TokenAccount>,
-//     /// CHECK: This account is not read or written in the program
-//     pub pyth_price_feed: AccountInfo<'info>,
-//     pub token_program: Program<'info, Token>,
-// }
-
-// #[account]
-// pub struct Pool {
-//     pub token_a_mint: Pubkey,
-//     pub token_b_mint: Pubkey,
-//     pub token_a_reserve: Pubkey,
-//     pub token_b_reserve: Pubkey,
-//     pub swap_fee: u64,
-//     pub total_liquidity: u64,
-// }
-
-// #[error_code]
-// pub enum ErrorCode {
-//     #[msg("The provided slippage tolerance has been exceeded.")]
-//     ExcessiveSlippage,
-//     #[msg("Insufficient liquidity for this trade.")]
-//     InsufficientLiquidity,
-// }
-
-// #[event]
-// pub struct SwapEvent {
-//     pub user: Pubkey,
-//     pub amount_in: u64,
-//     pub amount_out: u64,
-//     pub fee: u64,
-// }
-
-// // Helper function to calculate the amount out based on constant product formula
-// fn calculate_amount_out(amount_in: u64, reserve_a: u64, reserve_b: u64, swap_fee: u64) -> Result<u64> {
-//     let amount_in_with_fee = amount_in * (FEE_PRECISION - swap_fee) / FEE_PRECISION;
-//     let numerator = amount_in_with_fee * reserve_b;
-//     let denominator = reserve_a + amount_in_with_fee;
-    
-//     if denominator == 0 {
-//         return Err(ErrorCode::InsufficientLiquidity.into());
-//     }
-    
-//     Ok(numerator / denominator)
-// }
\ No newline at end of file
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::states::pool::*;
+use crate::errors::RouterError;
+use crate::oracle::OracleConfig;
+
+#[derive(Accounts)]
+#[instruction(swap_fee: u64, oracle: Option<OracleConfig>)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::SPACE,
+        seeds = [
+            SEED_POOL,
+            token_a_mint.key().as_ref(),
+            token_b_mint.key().as_ref(),
+            &swap_fee.to_le_bytes(),
+            &oracle_seed(&oracle),
+        ],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    pub token_a_mint: Box<Account<'info, Mint>>,
+    #[account(constraint = token_b_mint.key() != token_a_mint.key() @ RouterError::InvalidPoolMints)]
+    pub token_b_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_a_mint,
+        token::authority = pool,
+        seeds = [SEED_POOL_RESERVE, pool.key().as_ref(), token_a_mint.key().as_ref()],
+        bump
+    )]
+    pub token_a_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = token_b_mint,
+        token::authority = pool,
+        seeds = [SEED_POOL_RESERVE, pool.key().as_ref(), token_b_mint.key().as_ref()],
+        bump
+    )]
+    pub token_b_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = LP_DECIMALS,
+        mint::authority = pool,
+        seeds = [SEED_LP_MINT, pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = pool,
+        seeds = [SEED_LOCKED_LP, pool.key().as_ref()],
+        bump
+    )]
+    pub locked_lp: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Liquidity can be provided by any token owner. A government `Treasury` provides
+/// it through `the_ark_program`, which signs for the treasury's own token accounts.
+#[derive(Accounts)]
+pub struct ModifyLiquidity<'info> {
+    pub provider: Signer<'info>,
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, address = pool.token_a_reserve @ RouterError::InvalidPoolAccount)]
+    pub token_a_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = pool.token_b_reserve @ RouterError::InvalidPoolAccount)]
+    pub token_b_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = pool.lp_mint @ RouterError::InvalidPoolAccount)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(mut, address = pool.locked_lp @ RouterError::InvalidPoolAccount)]
+    pub locked_lp: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = pool.token_a_mint)]
+    pub provider_token_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = pool.token_b_mint)]
+    pub provider_token_b: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = pool.lp_mint)]
+    pub provider_lp_token: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub user: Signer<'info>,
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, address = pool.token_a_reserve @ RouterError::InvalidPoolAccount)]
+    pub token_a_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(mut, address = pool.token_b_reserve @ RouterError::InvalidPoolAccount)]
+    pub token_b_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = pool.token_a_mint)]
+    pub user_token_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = pool.token_b_mint)]
+    pub user_token_b: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Validated against the pool's oracle config when it has one
+    pub price_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn create_pool(ctx: Context<InitializePool>, swap_fee: u64, oracle: Option<OracleConfig>) -> Result<()> {
+    require!(swap_fee <= MAX_SWAP_FEE, RouterError::InvalidSwapFee);
+    if let Some(oracle) = &oracle {
+        oracle.validate(ctx.accounts.token_a_mint.decimals, ctx.accounts.token_b_mint.decimals)?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.bump = ctx.bumps.pool;
+    pool.token_a_mint = ctx.accounts.token_a_mint.key();
+    pool.token_b_mint = ctx.accounts.token_b_mint.key();
+    pool.token_a_reserve = ctx.accounts.token_a_reserve.key();
+    pool.token_b_reserve = ctx.accounts.token_b_reserve.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.locked_lp = ctx.accounts.locked_lp.key();
+    pool.swap_fee = swap_fee;
+    pool.oracle = oracle;
+    Ok(())
+}
+
+/// Deposit at most `max_amount_a` and `max_amount_b` at the current reserve ratio
+pub fn deposit_liquidity(
+    ctx: Context<ModifyLiquidity>,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    minimum_lp_out: u64,
+) -> Result<()> {
+    let reserve_a = ctx.accounts.token_a_reserve.amount;
+    let reserve_b = ctx.accounts.token_b_reserve.amount;
+    let lp_supply = ctx.accounts.lp_mint.supply;
+
+    let (amount_a, amount_b, lp_amount) = if lp_supply == 0 {
+        // The first depositor also takes anything sent to the reserves before them,
+        // less MINIMUM_LIQUIDITY locked in the pool
+        let liquidity = isqrt(
+            (u128::from(reserve_a) + u128::from(max_amount_a))
+                .checked_mul(u128::from(reserve_b) + u128::from(max_amount_b))
+                .ok_or(RouterError::ArithmeticOverflow)?,
+        );
+        let lp_amount = u64::try_from(liquidity)
+            .map_err(|_| RouterError::ArithmeticOverflow)?
+            .checked_sub(MINIMUM_LIQUIDITY)
+            .ok_or(RouterError::InsufficientLiquidity)?;
+        (max_amount_a, max_amount_b, lp_amount)
+    } else {
+        require!(reserve_a > 0 && reserve_b > 0, RouterError::InsufficientLiquidity);
+        // Take all of A if B covers it at the current ratio, otherwise all of B
+        let amount_b_for_a = mul_div(max_amount_a, reserve_b, reserve_a)?;
+        let (amount_a, amount_b) = if amount_b_for_a <= max_amount_b {
+            (max_amount_a, amount_b_for_a)
+        } else {
+            (mul_div(max_amount_b, reserve_a, reserve_b)?, max_amount_b)
+        };
+        let lp_amount = mul_div(amount_a, lp_supply, reserve_a)?
+            .min(mul_div(amount_b, lp_supply, reserve_b)?);
+        (amount_a, amount_b, lp_amount)
+    };
+
+    require!(lp_amount > 0, RouterError::InsufficientLiquidity);
+    require!(lp_amount >= minimum_lp_out, RouterError::ExcessiveSlippage);
+
+    let accounts = &ctx.accounts;
+    transfer_in(accounts, &accounts.provider_token_a, &accounts.token_a_reserve, amount_a)?;
+    transfer_in(accounts, &accounts.provider_token_b, &accounts.token_b_reserve, amount_b)?;
+
+    let pool = &accounts.pool;
+    if lp_supply == 0 {
+        mint_lp(accounts, &accounts.locked_lp, MINIMUM_LIQUIDITY)?;
+    }
+    mint_lp(accounts, &accounts.provider_lp_token, lp_amount)?;
+
+    emit!(LiquidityEvent {
+        pool: pool.key(),
+        provider: accounts.provider.key(),
+        amount_a,
+        amount_b,
+        lp_amount,
+        is_deposit: true,
+    });
+
+    Ok(())
+}
+
+/// Burn `lp_amount` LP tokens for a pro-rata share of both reserves, including accrued fees
+pub fn withdraw_liquidity(
+    ctx: Context<ModifyLiquidity>,
+    lp_amount: u64,
+    minimum_amount_a: u64,
+    minimum_amount_b: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let lp_supply = accounts.lp_mint.supply;
+    require!(lp_amount > 0 && lp_amount <= lp_supply, RouterError::InsufficientLiquidity);
+
+    let amount_a = mul_div(lp_amount, accounts.token_a_reserve.amount, lp_supply)?;
+    let amount_b = mul_div(lp_amount, accounts.token_b_reserve.amount, lp_supply)?;
+    require!(
+        amount_a >= minimum_amount_a && amount_b >= minimum_amount_b,
+        RouterError::ExcessiveSlippage
+    );
+
+    token::burn(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Burn {
+                mint: accounts.lp_mint.to_account_info(),
+                from: accounts.provider_lp_token.to_account_info(),
+                authority: accounts.provider.to_account_info(),
+            },
+        ),
+        lp_amount,
+    )?;
+
+    let pool = &accounts.pool;
+    transfer_out(pool, &accounts.token_program, &accounts.token_a_reserve, &accounts.provider_token_a, amount_a)?;
+    transfer_out(pool, &accounts.token_program, &accounts.token_b_reserve, &accounts.provider_token_b, amount_b)?;
+
+    emit!(LiquidityEvent {
+        pool: pool.key(),
+        provider: accounts.provider.key(),
+        amount_a,
+        amount_b,
+        lp_amount,
+        is_deposit: false,
+    });
+
+    Ok(())
+}
+
+pub fn swap_tokens(
+    ctx: Context<Swap>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    a_to_b: bool,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let pool = &accounts.pool;
+
+    let (user_in, reserve_in, reserve_out, user_out) = if a_to_b {
+        (&accounts.user_token_a, &accounts.token_a_reserve, &accounts.token_b_reserve, &accounts.user_token_b)
+    } else {
+        (&accounts.user_token_b, &accounts.token_b_reserve, &accounts.token_a_reserve, &accounts.user_token_a)
+    };
+
+    let amount_out = calculate_amount_out(amount_in, reserve_in.amount, reserve_out.amount, pool.swap_fee)?;
+    require!(amount_out > 0, RouterError::InsufficientLiquidity);
+    require!(amount_out >= minimum_amount_out, RouterError::ExcessiveSlippage);
+
+    if let Some(oracle) = &pool.oracle {
+        let price_account = accounts.price_account.as_ref().ok_or(RouterError::InvalidOracleAccount)?;
+        let (base_amount, quote_amount) = if a_to_b { (amount_in, amount_out) } else { (amount_out, amount_in) };
+        oracle.check_execution_price(price_account, base_amount, quote_amount)?;
+    }
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_in.to_account_info(),
+                to: reserve_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+    transfer_out(pool, &accounts.token_program, reserve_out, user_out, amount_out)?;
+
+    emit!(SwapEvent {
+        user: accounts.user.key(),
+        amount_in,
+        amount_out,
+        fee: mul_div(amount_in, pool.swap_fee, FEE_PRECISION)?,
+    });
+
+    Ok(())
+}
+
+fn transfer_in<'info>(
+    accounts: &ModifyLiquidity<'info>,
+    from: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: accounts.provider.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+fn transfer_out<'info>(
+    pool: &Account<'info, Pool>,
+    token_program: &Program<'info, Token>,
+    from: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    with_pool_seeds(pool, |seeds| {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )
+    })
+}
+
+fn mint_lp<'info>(accounts: &ModifyLiquidity<'info>, to: &Account<'info, TokenAccount>, amount: u64) -> Result<()> {
+    let pool = &accounts.pool;
+    with_pool_seeds(pool, |seeds| {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: accounts.lp_mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )
+    })
+}
+
+/// Call `f` with the seeds the pool signs with
+fn with_pool_seeds<T>(pool: &Pool, f: impl FnOnce(&[&[u8]]) -> Result<T>) -> Result<T> {
+    let swap_fee = pool.swap_fee.to_le_bytes();
+    let oracle = oracle_seed(&pool.oracle);
+    f(&[
+        SEED_POOL,
+        pool.token_a_mint.as_ref(),
+        pool.token_b_mint.as_ref(),
+        &swap_fee,
+        &oracle,
+        &[pool.bump],
+    ])
+}
+
+/// Amount out of a constant product swap, charging `swap_fee` on the input
+pub fn calculate_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64, swap_fee: u64) -> Result<u64> {
+    let amount_in_with_fee = u128::from(amount_in) * u128::from(FEE_PRECISION - swap_fee);
+    let numerator = amount_in_with_fee
+        .checked_mul(u128::from(reserve_out))
+        .ok_or(RouterError::ArithmeticOverflow)?;
+    let denominator = u128::from(reserve_in)
+        .checked_mul(u128::from(FEE_PRECISION))
+        .and_then(|reserve| reserve.checked_add(amount_in_with_fee))
+        .ok_or(RouterError::ArithmeticOverflow)?;
+
+    if denominator == 0 {
+        return err!(RouterError::InsufficientLiquidity);
+    }
+
+    u64::try_from(numerator / denominator).map_err(|_| error!(RouterError::ArithmeticOverflow))
+}
+
+fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+    require!(c > 0, RouterError::InsufficientLiquidity);
+    u64::try_from(u128::from(a) * u128::from(b) / u128::from(c))
+        .map_err(|_| error!(RouterError::ArithmeticOverflow))
+}
+
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+#[event]
+pub struct SwapEvent {
+    pub user: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+}
+
+#[event]
+pub struct LiquidityEvent {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub lp_amount: u64,
+    pub is_deposit: bool,
+}
@@ -25,6 +25,103 @@ pub enum RouterError {
     #[msg("Invalid Epoch Index")]
     InvalidEpochIndex,
     #[msg("Max Epochs Reached")]
-    MaxEpochsReached
+    MaxEpochsReached,
+    #[msg("Instruction is not on the government's allowlist")]
+    InstructionNotAllowed,
+    #[msg("Account does not satisfy the allowlist constraint")]
+    AccountConstraintViolated,
+    #[msg("Too many allowlist entries")]
+    AllowlistTooLong,
+    #[msg("Instruction batch is empty, too long or its account slices are out of bounds")]
+    InvalidInstructionBatch,
+    #[msg("The order book side is full")]
+    OrderBookFull,
+    #[msg("The event queue is full, consume events first")]
+    EventQueueFull,
+    #[msg("Order not found")]
+    OrderNotFound,
+    #[msg("Only the order owner can cancel it")]
+    NotOrderOwner,
+    #[msg("Order amount and price must be greater than zero")]
+    InvalidOrder,
+    #[msg("Token account does not belong to this market")]
+    InvalidMarketAccount,
+    #[msg("Open orders account for an event was not provided")]
+    MissingOpenOrders,
+    #[msg("Insufficient free balance")]
+    InsufficientBalance,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Lot and tick sizes must be greater than zero")]
+    InvalidLotSize,
+    #[msg("Order amount or price is not a multiple of the lot or tick size")]
+    InvalidOrderSize,
+    #[msg("Post-only order would cross the book")]
+    PostOnlyWouldCross,
+    #[msg("Fill-or-kill order could not be filled completely")]
+    FillOrKillNotFilled,
+    #[msg("Order would trade against the owner's own order")]
+    SelfTrade,
+    #[msg("Pool mints must be different")]
+    InvalidPoolMints,
+    #[msg("Account does not belong to this pool")]
+    InvalidPoolAccount,
+    #[msg("Swap fee exceeds the maximum")]
+    InvalidSwapFee,
+    #[msg("The provided slippage tolerance has been exceeded")]
+    ExcessiveSlippage,
+    #[msg("Insufficient liquidity for this trade")]
+    InsufficientLiquidity,
+    #[msg("Oracle price account is missing or invalid")]
+    InvalidOracleAccount,
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+    #[msg("Execution price deviates too far from the oracle price")]
+    OraclePriceDeviation,
+    #[msg("Escrow condition is malformed")]
+    InvalidEscrowCondition,
+    #[msg("An account or argument required by the escrow condition is missing")]
+    MissingConditionAccount,
+    #[msg("The escrow condition is not met")]
+    ConditionNotMet,
+    #[msg("Milestone index is out of range or the milestone schedule is invalid")]
+    InvalidMilestone,
+    #[msg("The milestone release time has not been reached")]
+    MilestoneLocked,
+    #[msg("The escrow is under dispute")]
+    EscrowDisputed,
+    #[msg("The escrow is not under dispute")]
+    NotDisputed,
+    #[msg("The escrow has no arbiter government")]
+    NoArbiter,
+    #[msg("Dispute share exceeds 100%")]
+    InvalidDisputeShare,
+    #[msg("Stream rate and deposit must be greater than zero")]
+    InvalidStreamRate,
+    #[msg("Nothing has streamed since the last claim")]
+    NothingToClaim,
+    #[msg("Provider name is empty or too long")]
+    InvalidProvider,
+    #[msg("The verification index is full")]
+    VerificationIndexFull,
+    #[msg("A Reclaim verification is required to join")]
+    ReclaimVerificationRequired,
+    #[msg("The Reclaim verification does not satisfy the requirement or has expired")]
+    InvalidReclaimVerification,
+    #[msg("Decision account is not a decision record of the government")]
+    InvalidDecisionAccount,
+    #[msg("Executor address could not be derived from the registered bump")]
+    InvalidExecutor,
+    #[msg("Mock prices are only available with the test-oracle feature")]
+    MockOracleDisabled,
+    #[msg("The dispute period has ended")]
+    DisputeExpired,
+    #[msg("The government instance is already registered")]
+    GovernmentAlreadyRegistered,
+    #[msg("The decision record was made on a different request")]
+    DecisionMismatch,
+    #[msg("Oracle decimals do not match the traded mints")]
+    OracleDecimalsMismatch,
 }
-
@@ -1,21 +1,17 @@
 use anchor_lang::prelude::*;
-use the_ark_program::{GovernmentTypes, InstructionContext, Decision};
-use borsh::{BorshSerialize, BorshDeserialize};
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use the_ark_program::{GovernmentTypes, InstructionContext, Decision, DecisionRecord, instructions_hash, make_decision_data};
 
 pub mod contexts;
 pub use contexts::*;
-mod errors;
+pub mod errors;
 
 pub mod states;
 pub use states::*;
 
-declare_id!("7aQvq1fEiDXqK36H7mW8MSTGdnHn6XAHDd9pauZwZXGQ");
+pub mod oracle;
+pub use oracle::*;
 
-#[derive(BorshSerialize, BorshDeserialize)]
-pub enum GovernmentInstruction {
-    MakeDecision,
-}
+declare_id!("7aQvq1fEiDXqK36H7mW8MSTGdnHn6XAHDd9pauZwZXGQ");
 
 #[program]
 pub mod standard {
@@ -30,99 +26,176 @@ pub mod standard {
     pub fn register_government(
         ctx: Context<RegisterGovernment>, 
         government_type: GovernmentTypes,
-        government_program_id: Pubkey
+        government_program_id: Pubkey,
+        government_state: Pubkey,
+        allowlist: Vec<AllowedInstruction>,
     ) -> Result<()> {
         let router_state = &mut ctx.accounts.router_state;
         
-        if router_state.governments.len() >= 10 {
+        if router_state.governments.len() >= MAX_GOVERNMENTS {
             return Err(RouterError::TooManyGovernments.into());
         }
+        require!(
+            !router_state.governments.iter().any(|g| g.government_state == government_state),
+            RouterError::GovernmentAlreadyRegistered
+        );
+
+        validate_allowlist(&allowlist)?;
+
+        // Every government instance gets its own executor
+        let (_, executor_bump) = find_executor_address(&router_state.key(), &government_state);
 
         router_state.governments.push(GovernmentEntry {
             government_type,
             program_id: government_program_id,
+            government_state,
+            executor_bump,
+            allowlist,
         });
 
         Ok(())
     }
 
+    pub fn set_government_allowlist(
+        ctx: Context<SetGovernmentAllowlist>,
+        government_state: Pubkey,
+        allowlist: Vec<AllowedInstruction>,
+    ) -> Result<()> {
+        validate_allowlist(&allowlist)?;
+
+        let government_entry = ctx.accounts.router_state.governments
+            .iter_mut()
+            .find(|g| g.government_state == government_state)
+            .ok_or(RouterError::GovernmentNotFound)?;
+
+        government_entry.allowlist = allowlist;
+
+        Ok(())
+    }
+
     pub fn route_instruction(
         ctx: Context<RouteInstruction>,
-        instruction_data: Vec<u8>
+        instructions: Vec<RoutedInstruction>
     ) -> Result<()> {
         let router_state = &ctx.accounts.router_state;
         let government_account = &ctx.accounts.government_account;
+        let government_state = &ctx.accounts.government_state;
         let decision_account = &ctx.accounts.decision_account;
+
+        require!(
+            !instructions.is_empty() && instructions.len() <= MAX_ROUTED_INSTRUCTIONS,
+            RouterError::InvalidInstructionBatch
+        );
     
-        // Find the registered government program
+        // Find the registered government instance, which must belong to the given program
         let government_entry = router_state.governments
             .iter()
-            .find(|g| g.program_id == government_account.key())
+            .find(|g| g.government_state == government_state.key())
             .ok_or(RouterError::GovernmentNotFound)?;
+        require_keys_eq!(
+            government_entry.program_id,
+            government_account.key(),
+            RouterError::GovernmentNotFound
+        );
+
+        // Split the remaining accounts into one slice per instruction
+        let account_slices = split_account_slices(&instructions, ctx.remaining_accounts)?;
+
+        // Reject anything outside the allowlist before asking for a decision
+        for (routed, accounts) in instructions.iter().zip(&account_slices) {
+            check_allowlist(government_entry, &routed.program_id, &routed.data, accounts)?;
+        }
     
-        // Create the instruction context
-        let instruction_context = InstructionContext {
-            program_id: government_entry.program_id,
-            instruction_data: instruction_data.clone(),
-            signer: ctx.accounts.authority.key(),
-            accounts: ctx.remaining_accounts.iter().map(|a| *a.key).collect(),
-            block_time: Clock::get()?.unix_timestamp,
-            instruction_index: 0,
-        };
-    
-        // Serialize the instruction context
-        let mut instruction_context_data = Vec::new();
-        instruction_context.serialize(&mut instruction_context_data)?;
-    
-        // Prepare the instruction data for the government program
-        // let mut government_ix_data = Vec::new();
-        // GovernmentInstruction::MakeDecision.serialize(&mut government_ix_data)?;
-        // government_ix_data.extend_from_slice(&instruction_context_data);
-
-        // let mut government_ix_data = Vec::new();
-        // government_ix_data.push(0u8); 
-        // government_ix_data.extend_from_slice(&instruction_context_data);
-
-        let mut government_ix_data = GovernmentInstruction::MakeDecision.try_to_vec()?;
-        government_ix_data.extend_from_slice(&instruction_context_data);
-    
-        // Call the government program to make a decision
-        let make_decision_ix = anchor_lang::solana_program::instruction::Instruction {
-            program_id: government_entry.program_id,
-            accounts: vec![
-                AccountMeta::new(*government_account.key, false),
-                AccountMeta::new(*decision_account.key, false),
-                AccountMeta::new(ctx.accounts.authority.key(), true),
-            ],
-            data: government_ix_data,
-        };
+        // Create one instruction context per instruction in the batch
+        let block_time = Clock::get()?.unix_timestamp;
+        let instruction_contexts = instructions
+            .iter()
+            .zip(&account_slices)
+            .enumerate()
+            .map(|(index, (routed, accounts))| InstructionContext {
+                program_id: routed.program_id,
+                instruction_data: routed.data.clone(),
+                signer: ctx.accounts.authority.key(),
+                accounts: accounts.iter().map(|a| *a.key).collect(),
+                block_time,
+                instruction_index: index as u8,
+            })
+            .collect::<Vec<_>>();
     
-        let account_infos = &[
-            government_account.to_account_info(),
+        // Call the government's `make_decision` for a single decision on the whole batch
+        let mut make_decision_accounts = vec![
+            AccountMeta::new(*government_state.key, false),
+            AccountMeta::new(*decision_account.key, false),
+            AccountMeta::new(ctx.accounts.authority.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+        ];
+        let mut account_infos = vec![
+            government_state.to_account_info(),
             decision_account.to_account_info(),
             ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            government_account.to_account_info(),
         ];
+        if let Some(proposal) = &ctx.accounts.proposal {
+            make_decision_accounts.push(AccountMeta::new(proposal.key(), false));
+            account_infos.push(proposal.to_account_info());
+        }
+        let make_decision_ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: government_entry.program_id,
+            accounts: make_decision_accounts,
+            data: make_decision_data(&instruction_contexts)?,
+        };
     
         anchor_lang::solana_program::program::invoke(
             &make_decision_ix,
-            account_infos,
+            &account_infos,
         )?;
 
-        // Read the decision from the decision account
-        let decision = Decision::try_from_slice(&decision_account.data.borrow())?;
-
-        match decision {
+        // Only a record written by the government itself carries its decision
+        require_keys_eq!(
+            *decision_account.owner,
+            government_entry.program_id,
+            RouterError::InvalidDecisionAccount
+        );
+        let record = DecisionRecord::try_deserialize(&mut &decision_account.data.borrow()[..])
+            .map_err(|_| RouterError::InvalidDecisionAccount)?;
+        require!(
+            record.instructions_hash == instructions_hash(&instruction_contexts)?,
+            RouterError::DecisionMismatch
+        );
+
+        match record.decision {
             Decision::Approve => {
                 msg!("Decision approved by the government");
-                // Execute the actual instruction
-                let target_ix = anchor_lang::solana_program::instruction::Instruction {
-                    program_id: instruction_context.program_id,
-                    accounts: ctx.remaining_accounts.iter().map(|a| {
-                        AccountMeta::new(a.key(), a.is_signer)
-                    }).collect(),
-                    data: instruction_data,
-                };
-                anchor_lang::solana_program::program::invoke(&target_ix, ctx.remaining_accounts)?;
+
+                // The government's executor PDA signs every approved instruction
+                let router_state_key = router_state.key();
+                let executor_seeds: &[&[u8]] = &[
+                    SEED_EXECUTOR,
+                    router_state_key.as_ref(),
+                    government_entry.government_state.as_ref(),
+                    &[government_entry.executor_bump],
+                ];
+                let executor = Pubkey::create_program_address(executor_seeds, ctx.program_id)
+                    .map_err(|_| RouterError::InvalidExecutor)?;
+
+                // Execute every instruction; any failure reverts the whole batch
+                for (routed, accounts) in instructions.into_iter().zip(&account_slices) {
+                    let target_ix = anchor_lang::solana_program::instruction::Instruction {
+                        program_id: routed.program_id,
+                        accounts: accounts.iter().map(|a| AccountMeta {
+                            pubkey: a.key(),
+                            is_signer: a.is_signer || a.key() == executor,
+                            is_writable: a.is_writable,
+                        }).collect(),
+                        data: routed.data,
+                    };
+                    anchor_lang::solana_program::program::invoke_signed(
+                        &target_ix,
+                        ctx.remaining_accounts,
+                        &[executor_seeds],
+                    )?;
+                }
             }
             Decision::Reject => {
                 msg!("Decision rejected by the government");
@@ -133,128 +206,133 @@ pub mod standard {
         Ok(())
     }
 
-    pub fn initialize_market(ctx: Context<InitializeMarket>, base_mint: Pubkey, quote_mint: Pubkey) -> Result<()> {
-        let market = &mut ctx.accounts.market;
-        market.base_mint = base_mint;
-        market.quote_mint = quote_mint;
-        market.next_order_id = 1;
-        Ok(())
+    pub fn initialize_market(
+        ctx: Context<InitializeMarket>,
+        base_lot_size: u64,
+        tick_size: u64,
+        oracle: Option<OracleConfig>,
+    ) -> Result<()> {
+        create_market(ctx, base_lot_size, tick_size, oracle)
     }
 
-    pub fn place_order(
-        ctx: Context<PlaceOrder>,
-        side: OrderSide,
-        amount: u64,
-        price: u64,
-    ) -> Result<()> {
-        let market = &mut ctx.accounts.market;
-        
-        let order = Order {
-            id: market.next_order_id,
-            owner: ctx.accounts.owner.key(),
-            side,
-            amount,
-            price,
-        };
-    
-        market.orders.push(order);
-        market.next_order_id += 1;
-    
-        let transfer_amount = if side == OrderSide::Bid {
-            amount.checked_mul(price).ok_or(ProgramError::ArithmeticOverflow)?
-        } else {
-            amount
-        };
-    
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.owner_token_account.to_account_info(),
-                    to: ctx.accounts.escrow_token_account.to_account_info(),
-                    authority: ctx.accounts.owner.to_account_info(),
-                },
-            ),
-            transfer_amount,
-        )?;
-    
-        Ok(())
+    pub fn create_open_orders(ctx: Context<CreateOpenOrders>) -> Result<()> {
+        register_trader(ctx)
     }
-    
-    pub fn process_orders(ctx: Context<ProcessOrders>) -> Result<()> {
-        let market = &mut ctx.accounts.market;
-        
-        // Sort orders by price (descending for bids, ascending for asks)
-        market.orders.sort_by(|a, b| {
-            match a.side {
-                OrderSide::Bid => b.price.cmp(&a.price),
-                OrderSide::Ask => a.price.cmp(&b.price),
-            }
-        });
 
-        let mut i = 0;
-        while i < market.orders.len() {
-            let order = &market.orders[i];
-            let matching_order_index = market.orders.iter().skip(i + 1).position(|o| 
-                o.side != order.side && 
-                (order.side == OrderSide::Bid && order.price >= o.price ||
-                 order.side == OrderSide::Ask && order.price <= o.price)
-            );
-
-            if let Some(j) = matching_order_index {
-                let (order1, order2) = if i < j + i + 1 {
-                    (&market.orders[i], &market.orders[j + i + 1])
-                } else {
-                    (&market.orders[j + i + 1], &market.orders[i])
-                };
-
-                let trade_amount = order1.amount.min(order2.amount);
-                let trade_price = order2.price; // Use the ask price as the trade price
-
-                // Execute trade
-                execute_trade(
-                    trade_amount,
-                    trade_price,
-                    &ctx.accounts.base_vault,
-                    &ctx.accounts.quote_vault,
-                    &ctx.accounts.token_program,
-                )?;
-
-                // Update order amounts
-                market.orders[i].amount -= trade_amount;
-                market.orders[j + i + 1].amount -= trade_amount;
-
-                // Remove filled orders
-                market.orders.retain(|o| o.amount > 0);
-            } else {
-                i += 1;
-            }
-        }
+    pub fn place_order(ctx: Context<PlaceOrder>, args: PlaceOrderArgs) -> Result<()> {
+        submit_order(ctx, args)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>, side: OrderSide, order_id: u64) -> Result<()> {
+        cancel_resting_order(ctx, side, order_id)
+    }
     
-        Ok(())
+    pub fn process_orders(ctx: Context<ProcessOrders>, limit: u16) -> Result<()> {
+        match_orders(ctx, limit)
+    }
+
+    pub fn consume_events<'info>(ctx: Context<'_, '_, 'info, 'info, ConsumeEvents<'info>>, limit: u16) -> Result<()> {
+        apply_events(ctx, limit)
+    }
+
+    pub fn settle_funds(ctx: Context<SettleFunds>) -> Result<()> {
+        settle_free_balances(ctx)
+    }
+
+    pub fn withdraw(ctx: Context<SettleFunds>, base_amount: u64, quote_amount: u64) -> Result<()> {
+        withdraw_free_balances(ctx, base_amount, quote_amount)
+    }
+
+    pub fn initialize_pool(ctx: Context<InitializePool>, swap_fee: u64, oracle: Option<OracleConfig>) -> Result<()> {
+        create_pool(ctx, swap_fee, oracle)
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<ModifyLiquidity>,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        minimum_lp_out: u64,
+    ) -> Result<()> {
+        deposit_liquidity(ctx, max_amount_a, max_amount_b, minimum_lp_out)
+    }
+
+    pub fn remove_liquidity(
+        ctx: Context<ModifyLiquidity>,
+        lp_amount: u64,
+        minimum_amount_a: u64,
+        minimum_amount_b: u64,
+    ) -> Result<()> {
+        withdraw_liquidity(ctx, lp_amount, minimum_amount_a, minimum_amount_b)
+    }
+
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<()> {
+        swap_tokens(ctx, amount_in, minimum_amount_out, a_to_b)
+    }
+
+    pub fn initialize_mock_price_account(ctx: Context<InitializeMockPrice>) -> Result<()> {
+        contexts::oracle::initialize_mock_price(ctx)
+    }
+
+    pub fn update_mock_price(ctx: Context<SetMockPrice>, price: i64, conf: u64, expo: i32) -> Result<()> {
+        contexts::oracle::set_mock_price(ctx, price, conf, expo)
     }
 
     pub fn create_conditional_escrow(
         ctx: Context<CreateEscrow>,
-        amount: u64,
-        condition: String,
+        escrow_id: u64,
+        milestones: Vec<MilestoneArgs>,
         expiry_time: i64,
+        arbiter_government_state: Option<Pubkey>,
     ) -> Result<()> {
-        create_escrow(ctx, amount, condition, expiry_time)
+        create_escrow(ctx, escrow_id, milestones, expiry_time, arbiter_government_state)
     }
 
-    pub fn fulfill_payment_condition(ctx: Context<FulfillCondition>) -> Result<()> {
-        fulfill_condition(ctx)
+    pub fn fulfill_payment_condition(
+        ctx: Context<FulfillCondition>,
+        milestone_index: u8,
+        proof: Option<VerifyArgs>,
+    ) -> Result<()> {
+        fulfill_condition(ctx, milestone_index, proof)
     }
 
-    pub fn release_payment_for_condition(ctx: Context<ReleasePayment>) -> Result<()> {
-        release_payment(ctx)
+    pub fn release_payment_for_condition(ctx: Context<ReleasePayment>, milestone_index: u8) -> Result<()> {
+        release_payment(ctx, milestone_index)
     }
 
     pub fn refund_payment(ctx: Context<Refund>) -> Result<()> {
         refund(ctx)
     }
 
+    pub fn raise_escrow_dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+        raise_dispute(ctx)
+    }
+
+    pub fn resolve_escrow_dispute(ctx: Context<ResolveDispute>, recipient_share_bps: u64) -> Result<()> {
+        resolve_dispute(ctx, recipient_share_bps)
+    }
+
+    pub fn create_payment_stream(
+        ctx: Context<CreatePaymentStream>,
+        stream_id: u64,
+        rate: StreamRate,
+        deposit: u64,
+    ) -> Result<()> {
+        create_stream(ctx, stream_id, rate, deposit)
+    }
+
+    pub fn claim_payment_stream(ctx: Context<ClaimStream>) -> Result<()> {
+        claim_stream(ctx)
+    }
+
+    pub fn cancel_payment_stream(ctx: Context<CancelStream>) -> Result<()> {
+        cancel_stream(ctx)
+    }
+
     pub fn reclaim_verify(ctx: Context<Verify>, args: VerifyArgs) -> Result<()> {
         verify(ctx, args)
     }
@@ -263,52 +341,86 @@ pub mod standard {
     revoke_verification(ctx)
     }
 
-    pub fn list_reclaim_verifications(ctx: Context<ListVerifications>) -> Result<()> {
-        list_verifications(ctx)
+    pub fn list_reclaim_verifications(
+        ctx: Context<ListVerifications>,
+        provider: Option<String>,
+        active_only: bool,
+        offset: u8,
+    ) -> Result<VerificationPage> {
+        list_verifications(ctx, provider, active_only, offset)
     }
 }
 
-fn execute_trade<'info>(
-    amount: u64,
-    price: u64,
-    base_vault: &Account<'info, TokenAccount>,
-    quote_vault: &Account<'info, TokenAccount>,
-    token_program: &Program<'info, Token>,
+fn validate_allowlist(allowlist: &[AllowedInstruction]) -> Result<()> {
+    require!(
+        allowlist.len() <= MAX_ALLOWED_INSTRUCTIONS,
+        errors::RouterError::AllowlistTooLong
+    );
+    require!(
+        allowlist.iter().all(|a| a.account_constraints.len() <= MAX_ACCOUNT_CONSTRAINTS),
+        errors::RouterError::AllowlistTooLong
+    );
+    Ok(())
+}
+
+fn split_account_slices<'a, 'info>(
+    instructions: &[RoutedInstruction],
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<&'a [AccountInfo<'info>]>> {
+    let mut offset = 0usize;
+    let mut slices = Vec::with_capacity(instructions.len());
+
+    for routed in instructions {
+        let end = offset
+            .checked_add(usize::from(routed.accounts_len))
+            .ok_or(errors::RouterError::InvalidInstructionBatch)?;
+        let accounts = remaining_accounts
+            .get(offset..end)
+            .ok_or(errors::RouterError::InvalidInstructionBatch)?;
+        slices.push(accounts);
+        offset = end;
+    }
+
+    Ok(slices)
+}
+
+fn check_allowlist(
+    government_entry: &GovernmentEntry,
+    target_program: &Pubkey,
+    instruction_data: &[u8],
+    accounts: &[AccountInfo],
 ) -> Result<()> {
-    let base_amount = amount;
-    let quote_amount = amount.checked_mul(price).ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Transfer base tokens
-    token::transfer(
-        CpiContext::new(
-            token_program.to_account_info(),
-            Transfer {
-                from: base_vault.to_account_info(),
-                to: quote_vault.to_account_info(),
-                authority: base_vault.to_account_info(),
-            },
-        ),
-        base_amount,
-    )?;
-
-    // Transfer quote tokens
-    token::transfer(
-        CpiContext::new(
-            token_program.to_account_info(),
-            Transfer {
-                from: quote_vault.to_account_info(),
-                to: base_vault.to_account_info(),
-                authority: quote_vault.to_account_info(),
-            },
-        ),
-        quote_amount,
-    )?;
+    let discriminator = instruction_data
+        .get(..8)
+        .ok_or(errors::RouterError::InstructionNotAllowed)?;
+
+    let allowed = government_entry.allowlist
+        .iter()
+        .find(|a| a.program_id == *target_program && a.discriminator == discriminator)
+        .ok_or(errors::RouterError::InstructionNotAllowed)?;
+
+    for constraint in &allowed.account_constraints {
+        let account = accounts
+            .get(usize::from(constraint.index))
+            .ok_or(errors::RouterError::AccountConstraintViolated)?;
+
+        require_keys_eq!(
+            account.key(),
+            constraint.key,
+            errors::RouterError::AccountConstraintViolated
+        );
+        require!(
+            constraint.is_writable || !account.is_writable,
+            errors::RouterError::AccountConstraintViolated
+        );
+    }
 
     Ok(())
 }
+
 #[derive(Accounts)]
 pub struct Initialize<'info> {
-    #[account(init, payer = authority, space = 8 + 32 + 4 + 10 * (32 + 1))]
+    #[account(init, payer = authority, space = RouterState::size(&[]))]
     pub router_state: Account<'info, RouterState>,
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -316,76 +428,66 @@ pub struct Initialize<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(
+    government_type: GovernmentTypes,
+    government_program_id: Pubkey,
+    government_state: Pubkey,
+    allowlist: Vec<AllowedInstruction>
+)]
 pub struct RegisterGovernment<'info> {
-    #[account(mut, has_one = authority)]
+    #[account(
+        mut,
+        has_one = authority,
+        realloc = RouterState::size(&router_state.governments) + GovernmentEntry::size(&allowlist),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
     pub router_state: Account<'info, RouterState>,
-    pub authority: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct RouteInstruction<'info> {
     #[account(mut)]
-    pub router_state: Account<'info, RouterState>,
-    /// CHECK: This account is not written to, and is checked in the instruction handler
-    pub government_account: UncheckedAccount<'info>,
-    /// CHECK: This account is used to store the decision from the government program
-    pub decision_account: UncheckedAccount<'info>,
     pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeMarket<'info> {
-    #[account(init, payer = authority, space = 8 + 32 + 32 + 8 + 1000)]
-    pub market: Account<'info, Market>,
+#[instruction(government_state: Pubkey, allowlist: Vec<AllowedInstruction>)]
+pub struct SetGovernmentAllowlist<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        realloc = RouterState::size(&router_state.governments)
+            + GovernmentEntry::size(&allowlist)
+            - router_state.governments
+                .iter()
+                .find(|g| g.government_state == government_state)
+                .map_or(0, |g| GovernmentEntry::size(&g.allowlist)),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub router_state: Account<'info, RouterState>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PlaceOrder<'info> {
-    #[account(mut)]
-    pub market: Account<'info, Market>,
-    #[account(mut)]
-    pub owner: Signer<'info>,
+pub struct RouteInstruction<'info> {
     #[account(mut)]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub router_state: Account<'info, RouterState>,
+    /// CHECK: Government program, checked against the registered governments
+    pub government_account: UncheckedAccount<'info>,
+    /// CHECK: Government instance the decision is made on, checked against the registered
+    /// governments and by the government program
     #[account(mut)]
-    pub escrow_token_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
-
-#[derive(Accounts)]
-pub struct ProcessOrders<'info> {
+    pub government_state: UncheckedAccount<'info>,
+    /// CHECK: Decision record, checked for the government's ownership after it decides
     #[account(mut)]
-    pub market: Account<'info, Market>,
+    pub decision_account: UncheckedAccount<'info>,
+    // Pays for the decision record the first time the government creates it
     #[account(mut)]
-    pub base_vault: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Proposal the decision is made on, for governments that vote on proposals;
+    /// forwarded to the government program, which checks it
     #[account(mut)]
-    pub quote_vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub proposal: Option<UncheckedAccount<'info>>,
 }
-
-#[account]
-pub struct Market {
-    pub base_mint: Pubkey,
-    pub quote_mint: Pubkey,
-    pub orders: Vec<Order>,
-    pub next_order_id: u64,
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy)]
-pub enum OrderSide {
-    Bid,
-    Ask,
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct Order {
-    pub id: u64,
-    pub owner: Pubkey,
-    pub side: OrderSide,
-    pub amount: u64,
-    pub price: u64,
-}
-
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::state::SolanaPriceAccount;
+use crate::errors::RouterError;
+
+pub const MAX_RATE_AGE: i64 = 60; // 60 seconds
+pub const BPS_PRECISION: u64 = 10_000;
+
+/// A price read from an oracle: `price * 10^expo` whole quote tokens per whole base token
+#[derive(Clone, Copy, Debug)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+/// Adapter over a price account format
+pub trait PriceOracle {
+    fn load_price(price_account: &AccountInfo) -> Result<OraclePrice>;
+}
+
+pub struct PythOracle;
+
+impl PriceOracle for PythOracle {
+    fn load_price(price_account: &AccountInfo) -> Result<OraclePrice> {
+        let price_feed = SolanaPriceAccount::account_info_to_feed(price_account)
+            .map_err(|_| RouterError::InvalidOracleAccount)?;
+        let price = price_feed.get_price_unchecked();
+
+        Ok(OraclePrice {
+            price: price.price,
+            conf: price.conf,
+            expo: price.expo,
+            publish_time: price.publish_time,
+        })
+    }
+}
+
+/// Reads a `MockPriceAccount` owned by this program, for local tests
+pub struct MockOracle;
+
+impl PriceOracle for MockOracle {
+    fn load_price(price_account: &AccountInfo) -> Result<OraclePrice> {
+        require_keys_eq!(*price_account.owner, crate::ID, RouterError::InvalidOracleAccount);
+        let data = price_account.try_borrow_data()?;
+        let mock = MockPriceAccount::try_deserialize(&mut &data[..])?;
+
+        Ok(OraclePrice {
+            price: mock.price,
+            conf: mock.conf,
+            expo: mock.expo,
+            publish_time: mock.publish_time,
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OracleSource {
+    Pyth,
+    // Only accepted when built with the `test-oracle` feature
+    Mock,
+}
+
+/// Guards trades against deviating from an oracle price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OracleConfig {
+    pub source: OracleSource,
+    pub price_account: Pubkey,
+    // Oldest acceptable publish time, in seconds
+    pub max_age: i64,
+    // Widest acceptable confidence interval, in basis points of the price
+    pub max_confidence_bps: u64,
+    // Largest acceptable gap between execution and oracle price, in basis points
+    pub max_deviation_bps: u64,
+    // Decimals of the base and quote mints, as the oracle prices whole tokens
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+}
+
+impl OracleConfig {
+    pub const SIZE: usize = 1 + 32 + 8 + 8 + 8 + 1 + 1;
+
+    /// Check the config can guard trades between mints of the given decimals
+    pub fn validate(&self, base_decimals: u8, quote_decimals: u8) -> Result<()> {
+        require!(
+            self.source != OracleSource::Mock || cfg!(feature = "test-oracle"),
+            RouterError::MockOracleDisabled
+        );
+        require!(
+            self.base_decimals == base_decimals && self.quote_decimals == quote_decimals,
+            RouterError::OracleDecimalsMismatch
+        );
+        Ok(())
+    }
+
+    /// Load a fresh and confident price from `price_account`
+    pub fn load_price(&self, price_account: &AccountInfo) -> Result<OraclePrice> {
+        require_keys_eq!(price_account.key(), self.price_account, RouterError::InvalidOracleAccount);
+
+        let price = match self.source {
+            OracleSource::Pyth => PythOracle::load_price(price_account)?,
+            OracleSource::Mock => {
+                require!(cfg!(feature = "test-oracle"), RouterError::MockOracleDisabled);
+                MockOracle::load_price(price_account)?
+            }
+        };
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now.saturating_sub(price.publish_time) <= self.max_age,
+            RouterError::StaleOraclePrice
+        );
+        require!(price.price > 0, RouterError::InvalidOracleAccount);
+        require!(
+            u128::from(price.conf) * u128::from(BPS_PRECISION)
+                <= u128::from(self.max_confidence_bps) * price.price as u128,
+            RouterError::OracleConfidenceTooWide
+        );
+
+        Ok(price)
+    }
+
+    /// Fail if trading `base_amount` for `quote_amount` deviates from the oracle price
+    pub fn check_execution_price(
+        &self,
+        price_account: &AccountInfo,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> Result<()> {
+        if base_amount == 0 {
+            return Ok(());
+        }
+
+        let price = self.load_price(price_account)?;
+
+        // In whole tokens, quote / 10^quote_decimals should be base / 10^base_decimals
+        // times price * 10^expo. Move every power of ten to one side to stay in integers.
+        let exponent = i32::from(self.base_decimals) - i32::from(self.quote_decimals) - price.expo;
+        let scale = 10u128
+            .checked_pow(exponent.unsigned_abs())
+            .ok_or(RouterError::ArithmeticOverflow)?;
+        let quote = u128::from(quote_amount);
+        let base = u128::from(base_amount)
+            .checked_mul(price.price as u128)
+            .ok_or(RouterError::ArithmeticOverflow)?;
+        let (execution, oracle) = if exponent >= 0 {
+            (quote.checked_mul(scale), Some(base))
+        } else {
+            (Some(quote), base.checked_mul(scale))
+        };
+        let execution = execution.ok_or(RouterError::ArithmeticOverflow)?;
+        let oracle = oracle.ok_or(RouterError::ArithmeticOverflow)?;
+
+        let deviation = execution.abs_diff(oracle)
+            .checked_mul(u128::from(BPS_PRECISION))
+            .ok_or(RouterError::ArithmeticOverflow)?;
+        let tolerance = oracle
+            .checked_mul(u128::from(self.max_deviation_bps))
+            .ok_or(RouterError::ArithmeticOverflow)?;
+        require!(deviation <= tolerance, RouterError::OraclePriceDeviation);
+
+        Ok(())
+    }
+}
+
+/// A price account whose values are set by its authority, for local tests. Only
+/// created, updated or read with the `test-oracle` feature.
+#[account]
+pub struct MockPriceAccount {
+    pub authority: Pubkey,
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl MockPriceAccount {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // authority
+        8 + // price
+        8 + // conf
+        4 + // expo
+        8; // publish_time
+}
@@ -1,12 +1,188 @@
 use anchor_lang::prelude::*;
+use the_ark_program::InstructionContext;
 
+pub const MAX_PROVIDER_LEN: usize = 128;
+pub const MAX_MILESTONES: usize = 8;
+// How long the arbiter has to resolve a dispute before the escrow resumes on its own terms
+pub const DISPUTE_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
+
+pub const SEED_ESCROW: &[u8] = b"escrow";
+pub const SEED_ESCROW_VAULT: &[u8] = b"escrow_vault";
+
+/// What must be proven on-chain before an escrow can be released
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum EscrowCondition {
+    // The recipient submits a Reclaim proof of `provider`, verified against `epoch_config`
+    ReclaimProof {
+        epoch_config: Pubkey,
+        provider: String,
+    },
+    // `decision_account`, a `DecisionRecord` owned by `government_program`, holds an approval
+    // of the milestone's `Escrow::milestone_request`
+    GovernmentDecision {
+        government_program: Pubkey,
+        decision_account: Pubkey,
+    },
+    // The clock has reached `unlock_at`
+    Timestamp {
+        unlock_at: i64,
+    },
+    // `arbiter` signs the fulfillment
+    ArbiterSignature {
+        arbiter: Pubkey,
+    },
+}
+
+impl EscrowCondition {
+    // Size of the largest variant
+    pub const SIZE: usize = 1 + 32 + 4 + MAX_PROVIDER_LEN;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MilestoneStatus {
+    // Waiting for the condition
+    Pending,
+    // Condition met, releasable once `release_time` is reached
+    Fulfilled,
+    // Paid out to the recipient
+    Released,
+    // Returned to the sender after expiry
+    Refunded,
+}
+
+/// One tranche of an escrow, released independently of the others
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Milestone {
+    pub amount: u64,
+    pub condition: EscrowCondition,
+    // Earliest time the tranche can be released, even once fulfilled
+    pub release_time: i64,
+    pub status: MilestoneStatus,
+}
+
+impl Milestone {
+    pub const SIZE: usize = 8 + EscrowCondition::SIZE + 8 + 1;
+
+    /// Whether the tranche no longer holds funds in the vault
+    pub fn is_settled(&self) -> bool {
+        matches!(self.status, MilestoneStatus::Released | MilestoneStatus::Refunded)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MilestoneArgs {
+    pub amount: u64,
+    pub condition: EscrowCondition,
+    pub release_time: i64,
+}
+
+/// A government instance registered in a router that settles disputes.
+/// It acts through its router executor PDA, so every resolution passes its `make_decision`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct EscrowArbiter {
+    pub router_state: Pubkey,
+    pub government_state: Pubkey,
+}
+
+impl EscrowArbiter {
+    pub const SIZE: usize = 32 + 32;
+}
+
+/// A dispute over the pending milestones. An escrow gets at most one.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Dispute {
+    pub raised_by: Pubkey,
+    pub raised_at: i64,
+    // Set once the arbiter settles it
+    pub resolved: bool,
+}
+
+impl Dispute {
+    pub const SIZE: usize = 32 + 8 + 1;
+
+    /// Whether the arbiter can still settle it. Until then, pending milestones are frozen.
+    pub fn is_open(&self, now: i64) -> bool {
+        !self.resolved && now < self.raised_at.saturating_add(DISPUTE_PERIOD)
+    }
+}
+
+/// A PDA escrow that owns its token vault and pays out in milestones
 #[account]
 pub struct Escrow {
+    pub bump: u8,
+    pub escrow_id: u64,
     pub sender: Pubkey,
     pub recipient: Pubkey,
     pub mint: Pubkey,
+    // Sum of all milestone amounts
     pub amount: u64,
-    pub condition: String,
-    pub is_fulfilled: bool,
+    pub milestones: Vec<Milestone>,
+    // Conditions must be fulfilled by this time, after which pending tranches can be refunded
     pub expiry_time: i64,
-}
\ No newline at end of file
+    // Government that resolves disputes, if any
+    pub arbiter: Option<EscrowArbiter>,
+    // Dispute over the pending milestones; freezes their fulfillment and refund while open
+    pub dispute: Option<Dispute>,
+}
+
+impl Escrow {
+    pub fn size(milestones: usize) -> usize {
+        8 + // discriminator
+        1 + // bump
+        8 + // escrow_id
+        32 + // sender
+        32 + // recipient
+        32 + // mint
+        8 + // amount
+        4 + (milestones * Milestone::SIZE) + // milestones
+        8 + // expiry_time
+        1 + EscrowArbiter::SIZE + // arbiter
+        1 + Dispute::SIZE // dispute
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.milestones.iter().all(Milestone::is_settled)
+    }
+
+    pub fn is_disputed(&self, now: i64) -> bool {
+        self.dispute.as_ref().is_some_and(|d| d.is_open(now))
+    }
+
+    /// Last time conditions can be fulfilled. A dispute raised before `expiry_time`
+    /// pushes it back by the time it could keep the escrow frozen.
+    pub fn fulfillment_deadline(&self) -> i64 {
+        match &self.dispute {
+            Some(dispute) if dispute.raised_at <= self.expiry_time => {
+                self.expiry_time.saturating_add(DISPUTE_PERIOD)
+            }
+            _ => self.expiry_time,
+        }
+    }
+
+    /// Request a government approves, by calling its `make_decision` on this context alone,
+    /// to fulfill the `GovernmentDecision` milestone at `milestone_index` of the escrow at
+    /// `escrow`. It names the expiry too, so an approval can't fulfill a later escrow
+    /// re-created at the same address.
+    pub fn milestone_request(&self, escrow: Pubkey, milestone_index: u8) -> InstructionContext {
+        let mut instruction_data = vec![milestone_index];
+        instruction_data.extend_from_slice(&self.expiry_time.to_le_bytes());
+
+        InstructionContext {
+            program_id: crate::ID,
+            instruction_data,
+            signer: Pubkey::default(),
+            accounts: vec![escrow],
+            block_time: 0,
+            instruction_index: 0,
+        }
+    }
+
+    /// Total held in the vault for milestones whose condition is still unmet
+    pub fn pending_amount(&self) -> Result<u64> {
+        self.milestones
+            .iter()
+            .filter(|m| m.status == MilestoneStatus::Pending)
+            .try_fold(0u64, |total, m| total.checked_add(m.amount))
+            .ok_or_else(|| crate::errors::RouterError::ArithmeticOverflow.into())
+    }
+}
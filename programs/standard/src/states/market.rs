@@ -0,0 +1,287 @@
+use anchor_lang::prelude::*;
+use crate::errors::RouterError;
+use crate::oracle::OracleConfig;
+
+pub const MAX_ORDERS_PER_SIDE: usize = 64;
+pub const MAX_EVENTS: usize = 64;
+
+pub const SEED_BIDS: &[u8] = b"bids";
+pub const SEED_ASKS: &[u8] = b"asks";
+pub const SEED_EVENT_QUEUE: &[u8] = b"event_queue";
+pub const SEED_MARKET_AUTHORITY: &[u8] = b"market_authority";
+pub const SEED_BASE_VAULT: &[u8] = b"base_vault";
+pub const SEED_QUOTE_VAULT: &[u8] = b"quote_vault";
+pub const SEED_OPEN_ORDERS: &[u8] = b"open_orders";
+
+#[account]
+pub struct Market {
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    // Vaults holding all escrowed funds, owned by the market authority PDA
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    // Bump of the market authority PDA
+    pub authority_bump: u8,
+    // Order amounts must be a multiple of this
+    pub base_lot_size: u64,
+    // Order prices must be a multiple of this
+    pub tick_size: u64,
+    // Bid and ask slabs
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    // Queue of fills waiting to be settled
+    pub event_queue: Pubkey,
+    // Sequence number of the next order, used for time priority
+    pub next_order_id: u64,
+    // Optional guard against market orders deviating from an oracle price
+    pub oracle: Option<OracleConfig>,
+}
+
+impl Market {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // base_mint
+        32 + // quote_mint
+        32 + // base_vault
+        32 + // quote_vault
+        1 + // authority_bump
+        8 + // base_lot_size
+        8 + // tick_size
+        32 + // bids
+        32 + // asks
+        32 + // event_queue
+        8 + // next_order_id
+        1 + OracleConfig::SIZE; // oracle
+
+    /// The mint of the funds orders on `side` escrow
+    pub fn mint_for(&self, side: OrderSide) -> Pubkey {
+        match side {
+            OrderSide::Bid => self.quote_mint,
+            OrderSide::Ask => self.base_mint,
+        }
+    }
+
+    /// The vault that escrows funds for orders on `side`
+    pub fn vault_for(&self, side: OrderSide) -> Pubkey {
+        match side {
+            OrderSide::Bid => self.quote_vault,
+            OrderSide::Ask => self.base_vault,
+        }
+    }
+}
+
+/// A user's balances on a market. Locked funds back resting orders;
+/// free funds can be settled back to the user's token accounts.
+#[account]
+pub struct OpenOrders {
+    pub bump: u8,
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub base_free: u64,
+    pub base_locked: u64,
+    pub quote_free: u64,
+    pub quote_locked: u64,
+}
+
+impl OpenOrders {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // market
+        32 + // owner
+        8 + // base_free
+        8 + // base_locked
+        8 + // quote_free
+        8; // quote_locked
+
+    pub fn lock(&mut self, side: OrderSide, amount: u64) -> Result<()> {
+        let locked = match side {
+            OrderSide::Bid => &mut self.quote_locked,
+            OrderSide::Ask => &mut self.base_locked,
+        };
+        *locked = locked.checked_add(amount).ok_or(RouterError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Move `amount` of locked funds on `side` back to free
+    pub fn unlock(&mut self, side: OrderSide, amount: u64) -> Result<()> {
+        let (locked, free) = match side {
+            OrderSide::Bid => (&mut self.quote_locked, &mut self.quote_free),
+            OrderSide::Ask => (&mut self.base_locked, &mut self.base_free),
+        };
+        *locked = locked.checked_sub(amount).ok_or(RouterError::ArithmeticOverflow)?;
+        *free = free.checked_add(amount).ok_or(RouterError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Apply this user's half of a fill of `amount` at `price`.
+    /// `limit_price` is the price the user's own order was placed at.
+    pub fn apply_fill(&mut self, side: OrderSide, amount: u64, price: u64, limit_price: u64) -> Result<()> {
+        let quote_amount = amount.checked_mul(price).ok_or(RouterError::ArithmeticOverflow)?;
+        match side {
+            OrderSide::Bid => {
+                let quote_reserved = amount.checked_mul(limit_price).ok_or(RouterError::ArithmeticOverflow)?;
+                self.quote_locked = self.quote_locked.checked_sub(quote_reserved).ok_or(RouterError::ArithmeticOverflow)?;
+                // Refund any price improvement
+                self.quote_free = self.quote_free
+                    .checked_add(quote_reserved - quote_amount)
+                    .ok_or(RouterError::ArithmeticOverflow)?;
+                self.base_free = self.base_free.checked_add(amount).ok_or(RouterError::ArithmeticOverflow)?;
+            }
+            OrderSide::Ask => {
+                self.base_locked = self.base_locked.checked_sub(amount).ok_or(RouterError::ArithmeticOverflow)?;
+                self.quote_free = self.quote_free.checked_add(quote_amount).ok_or(RouterError::ArithmeticOverflow)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum OrderSide {
+    Bid,
+    Ask,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum OrderType {
+    // Crosses what it can, rests the remainder
+    Limit,
+    // Crosses what it can, cancels the remainder
+    ImmediateOrCancel,
+    // Fills completely on placement or fails
+    FillOrKill,
+    // Rests without crossing, fails if it would cross
+    PostOnly,
+    // Crosses at any resting price; bids still escrow `amount * price`, capping their cost
+    Market,
+}
+
+/// What happens when an order would match a resting order of the same owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum SelfTradeBehavior {
+    // Shrink both orders by the overlapping amount without trading
+    DecrementTake,
+    // Cancel the resting order and keep matching
+    CancelProvide,
+    // Fail the transaction
+    AbortTransaction,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Order {
+    // Sequence number; lower ids were placed earlier
+    pub id: u64,
+    pub owner: Pubkey,
+    pub side: OrderSide,
+    // Remaining unfilled amount
+    pub amount: u64,
+    pub price: u64,
+}
+
+impl Order {
+    pub const SIZE: usize = 8 + 32 + 1 + 8 + 8;
+}
+
+/// One side of the book, kept sorted best price first and then by sequence number
+#[account]
+pub struct OrderSlab {
+    pub market: Pubkey,
+    pub side: OrderSide,
+    pub orders: Vec<Order>,
+}
+
+impl OrderSlab {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // market
+        1 + // side
+        4 + (MAX_ORDERS_PER_SIDE * Order::SIZE); // orders
+
+    /// Whether `a` has priority over `b` on this side of the book
+    fn has_priority(&self, a: &Order, b: &Order) -> bool {
+        match self.side {
+            OrderSide::Bid => a.price > b.price || (a.price == b.price && a.id < b.id),
+            OrderSide::Ask => a.price < b.price || (a.price == b.price && a.id < b.id),
+        }
+    }
+
+    pub fn insert(&mut self, order: Order) -> Result<()> {
+        require!(
+            self.orders.len() < MAX_ORDERS_PER_SIDE,
+            RouterError::OrderBookFull
+        );
+
+        let index = self.orders
+            .iter()
+            .position(|resting| self.has_priority(&order, resting))
+            .unwrap_or(self.orders.len());
+        self.orders.insert(index, order);
+
+        Ok(())
+    }
+
+    pub fn best(&self) -> Option<&Order> {
+        self.orders.first()
+    }
+
+    pub fn best_mut(&mut self) -> Option<&mut Order> {
+        self.orders.first_mut()
+    }
+
+    pub fn pop_best(&mut self) -> Option<Order> {
+        if self.orders.is_empty() {
+            None
+        } else {
+            Some(self.orders.remove(0))
+        }
+    }
+
+    pub fn remove(&mut self, order_id: u64) -> Option<Order> {
+        let index = self.orders.iter().position(|o| o.id == order_id)?;
+        Some(self.orders.remove(index))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum MarketEvent {
+    Fill {
+        maker: Pubkey,
+        taker: Pubkey,
+        maker_order_id: u64,
+        taker_order_id: u64,
+        taker_side: OrderSide,
+        // Execution price, set by the maker
+        price: u64,
+        // Limit price of the taker's order
+        taker_price: u64,
+        amount: u64,
+    },
+}
+
+impl MarketEvent {
+    pub const SIZE: usize = 1 + 32 + 32 + 8 + 8 + 1 + 8 + 8 + 8;
+}
+
+#[account]
+pub struct EventQueue {
+    pub market: Pubkey,
+    // Total number of events ever pushed
+    pub seq_num: u64,
+    pub events: Vec<MarketEvent>,
+}
+
+impl EventQueue {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // market
+        8 + // seq_num
+        4 + (MAX_EVENTS * MarketEvent::SIZE); // events
+
+    pub fn is_full(&self) -> bool {
+        self.events.len() >= MAX_EVENTS
+    }
+
+    pub fn push(&mut self, event: MarketEvent) -> Result<()> {
+        require!(self.events.len() < MAX_EVENTS, RouterError::EventQueueFull);
+        self.events.push(event);
+        self.seq_num += 1;
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 pub mod router;
 pub mod escrow;
 pub mod epoch;
+pub mod market;
+pub mod pool;
+pub mod stream;
 
 pub use router::*;
 pub use escrow::*;
-pub use epoch::*;
\ No newline at end of file
+pub use epoch::*;
+pub use market::*;
+pub use pool::*;
+pub use stream::*;
\ No newline at end of file
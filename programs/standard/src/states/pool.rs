@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::oracle::OracleConfig;
+
+pub const SEED_POOL: &[u8] = b"pool";
+pub const SEED_POOL_RESERVE: &[u8] = b"pool_reserve";
+pub const SEED_LP_MINT: &[u8] = b"lp_mint";
+pub const SEED_LOCKED_LP: &[u8] = b"locked_lp";
+
+pub const FEE_PRECISION: u64 = 10_000; // 4 decimal places
+pub const MAX_SWAP_FEE: u64 = 1_000; // 10%
+pub const LP_DECIMALS: u8 = 6;
+// LP tokens locked in the pool on the first deposit, so the supply never returns to zero
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// A constant-product (x * y = k) pool. The pool PDA owns both reserves
+/// and is the LP mint authority. Swap fees stay in the reserves, so they
+/// accrue to LP holders pro rata.
+///
+/// The fee and oracle are part of the pool address, so each configuration of
+/// a pair gets its own pool and no creator can claim the pair for all others.
+#[account]
+pub struct Pool {
+    pub bump: u8,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_reserve: Pubkey,
+    pub token_b_reserve: Pubkey,
+    pub lp_mint: Pubkey,
+    // Pool-owned LP account holding MINIMUM_LIQUIDITY, never withdrawn
+    pub locked_lp: Pubkey,
+    // Fee charged on the input amount, in FEE_PRECISION units
+    pub swap_fee: u64,
+    // Optional guard against swaps deviating from an oracle price of A in B
+    pub oracle: Option<OracleConfig>,
+}
+
+impl Pool {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // token_a_mint
+        32 + // token_b_mint
+        32 + // token_a_reserve
+        32 + // token_b_reserve
+        32 + // lp_mint
+        32 + // locked_lp
+        8 + // swap_fee
+        1 + OracleConfig::SIZE; // oracle
+}
+
+/// Pool address seed committing to its oracle configuration
+pub fn oracle_seed(oracle: &Option<OracleConfig>) -> [u8; 32] {
+    let mut data = Vec::with_capacity(1 + OracleConfig::SIZE);
+    // Writing into a Vec cannot fail
+    oracle.serialize(&mut data).unwrap();
+    anchor_lang::solana_program::hash::hash(&data).to_bytes()
+}
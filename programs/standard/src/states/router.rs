@@ -1,20 +1,99 @@
 use anchor_lang::prelude::*;
 use the_ark_program::interface::GovernmentTypes;
+
+pub const MAX_GOVERNMENTS: usize = 10;
+pub const MAX_ALLOWED_INSTRUCTIONS: usize = 16;
+pub const MAX_ACCOUNT_CONSTRAINTS: usize = 8;
+pub const MAX_ROUTED_INSTRUCTIONS: usize = 8;
+
+pub const SEED_EXECUTOR: &[u8] = b"executor";
+
 #[account]
 pub struct RouterState {
     pub authority: Pubkey,
     pub governments: Vec<GovernmentEntry>,
 }
 
+impl RouterState {
+    pub fn size(governments: &[GovernmentEntry]) -> usize {
+        8 + // Anchor discriminator
+        32 + // Authority
+        4 + // Vector discriminator
+        governments.iter().map(|g| GovernmentEntry::size(&g.allowlist)).sum::<usize>()
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct GovernmentEntry {
     pub government_type: GovernmentTypes,
     pub program_id: Pubkey,
+    // Government instance, e.g. a kingdom's monarch, whose decisions the entry routes
+    pub government_state: Pubkey,
+    // Bump of the executor PDA that signs approved instructions
+    pub executor_bump: u8,
+    // Instructions the government may approve for execution
+    pub allowlist: Vec<AllowedInstruction>,
+}
+
+impl GovernmentEntry {
+    pub fn size(allowlist: &[AllowedInstruction]) -> usize {
+        1 + // Government type
+        32 + // Program ID
+        32 + // Government state
+        1 + // Executor bump
+        4 + // Vector discriminator
+        allowlist.iter().map(AllowedInstruction::size).sum::<usize>()
+    }
+}
+
+/// The router-owned PDA that signs approved instructions for a government instance.
+/// It can hold treasuries and act as a mint authority, like a multisig vault.
+pub fn find_executor_address(router_state: &Pubkey, government_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_EXECUTOR, router_state.as_ref(), government_state.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// A target program and instruction discriminator the router is allowed to execute
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowedInstruction {
+    pub program_id: Pubkey,
+    pub discriminator: [u8; 8],
+    pub account_constraints: Vec<AccountConstraint>,
+}
+
+impl AllowedInstruction {
+    pub fn size(&self) -> usize {
+        32 + // Program ID
+        8 + // Discriminator
+        4 + // Vector discriminator
+        self.account_constraints.len() * AccountConstraint::SIZE
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub enum GovernmentInstruction {
-    MakeDecision,
+/// Pins the account at `index` of the executed instruction to `key`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AccountConstraint {
+    pub index: u8,
+    pub key: Pubkey,
+    // Whether the account may be passed as writable
+    pub is_writable: bool,
+}
+
+impl AccountConstraint {
+    pub const SIZE: usize = 1 + 32 + 1;
+}
+
+/// One instruction of a routed batch.
+///
+/// Its accounts are the next `accounts_len` entries of `remaining_accounts`,
+/// in batch order. Target program accounts may follow the last slice.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoutedInstruction {
+    pub program_id: Pubkey,
+    pub accounts_len: u8,
+    pub data: Vec<u8>,
 }
 
 #[error_code]
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::errors::RouterError;
+
+pub const SEED_STREAM: &[u8] = b"stream";
+pub const SEED_STREAM_VAULT: &[u8] = b"stream_vault";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamRate {
+    // Tokens unlocked every second since the start
+    PerSecond(u64),
+    // Tokens unlocked at every Solana epoch boundary since the start
+    PerEpoch(u64),
+}
+
+/// Tokens streamed to a recipient from a vault funded by a treasury.
+/// The payer's government (`authority`) can cancel, refunding what has not streamed yet.
+#[account]
+pub struct PaymentStream {
+    pub bump: u8,
+    pub stream_id: u64,
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    // Token account the stream was funded from; unstreamed funds return here on cancel
+    pub refund_account: Pubkey,
+    // Receives the rent when the stream is closed
+    pub rent_payer: Pubkey,
+    pub rate: StreamRate,
+    pub start_time: i64,
+    pub start_epoch: u64,
+    // Amount received by the vault, cut down to what had streamed on cancel
+    pub deposited: u64,
+    // Amount claimed by the recipient so far
+    pub withdrawn: u64,
+}
+
+impl PaymentStream {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // stream_id
+        32 + // authority
+        32 + // recipient
+        32 + // mint
+        32 + // refund_account
+        32 + // rent_payer
+        1 + 8 + // rate
+        8 + // start_time
+        8 + // start_epoch
+        8 + // deposited
+        8; // withdrawn
+
+    /// Total unlocked at `now` / `epoch`, capped at the deposit
+    pub fn streamed_amount(&self, now: i64, epoch: u64) -> Result<u64> {
+        let (periods, rate) = match self.rate {
+            StreamRate::PerSecond(rate) => (now.saturating_sub(self.start_time).max(0) as u64, rate),
+            StreamRate::PerEpoch(rate) => (epoch.saturating_sub(self.start_epoch), rate),
+        };
+        let streamed = u128::from(periods)
+            .checked_mul(u128::from(rate))
+            .ok_or(RouterError::ArithmeticOverflow)?;
+
+        Ok(streamed.min(u128::from(self.deposited)) as u64)
+    }
+
+    /// Unlocked but not yet claimed
+    pub fn claimable_amount(&self, now: i64, epoch: u64) -> Result<u64> {
+        self.streamed_amount(now, epoch)?
+            .checked_sub(self.withdrawn)
+            .ok_or_else(|| RouterError::ArithmeticOverflow.into())
+    }
+}
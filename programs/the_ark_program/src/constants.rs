@@ -3,3 +3,7 @@ pub const PUBLIC_KEY_LENGTH: usize = 32;
 pub const TIMESTAMP_LENGTH: usize = 8;
 pub const VECTOR_LENGTH_PREFIX: usize = 4;
 pub const BUMP_LENGTH: usize = 1;
+
+// The `standard` program, whose pools treasuries provide liquidity to
+pub const POOL_PROGRAM_ID: anchor_lang::prelude::Pubkey =
+    anchor_lang::pubkey!("7aQvq1fEiDXqK36H7mW8MSTGdnHn6XAHDd9pauZwZXGQ");
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hash, instruction::Instruction, program::invoke_signed};
 use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::constants::POOL_PROGRAM_ID;
 use crate::state::Treasury;
 use anchor_spl::associated_token::AssociatedToken;
 
@@ -50,6 +52,55 @@ pub struct AddTokenToTreasury<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Lets the treasury authority move liquidity between the treasury and a `standard`
+/// pool. The treasury signs for itself, so its tokens and LP tokens only ever move
+/// between accounts it owns and the pool, which validates its own accounts.
+#[derive(Accounts)]
+pub struct TreasuryLiquidity<'info> {
+    #[account(
+        seeds = [b"treasury", treasury.owner.as_ref(), treasury.name.as_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Validated by the pool program
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the pool program
+    #[account(mut)]
+    pub token_a_reserve: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the pool program
+    #[account(mut)]
+    pub token_b_reserve: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the pool program
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the pool program
+    #[account(mut)]
+    pub locked_lp: UncheckedAccount<'info>,
+
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = treasury)]
+    pub treasury_lp_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Checked against the pool program id
+    #[account(address = POOL_PROGRAM_ID)]
+    pub pool_program: UncheckedAccount<'info>,
+}
+
     pub fn create_treasury(ctx: Context<CreateTreasury>, name: String, authority: Pubkey) -> Result<()> {
         let treasury = &mut ctx.accounts.treasury;
         treasury.name = name;
@@ -62,4 +113,66 @@ pub struct AddTokenToTreasury<'info> {
         let treasury = &mut ctx.accounts.treasury;
         let token_account = &ctx.accounts.token_account;
         treasury.add_token_account(token_account.mint, token_account.key())
-    }
\ No newline at end of file
+    }
+
+    pub fn provide_treasury_liquidity(
+        ctx: Context<TreasuryLiquidity>,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        minimum_lp_out: u64,
+    ) -> Result<()> {
+        let mut data = hash(b"global:add_liquidity").to_bytes()[..8].to_vec();
+        (max_amount_a, max_amount_b, minimum_lp_out).serialize(&mut data)?;
+        invoke_pool(&ctx, data)
+    }
+
+    pub fn withdraw_treasury_liquidity(
+        ctx: Context<TreasuryLiquidity>,
+        lp_amount: u64,
+        minimum_amount_a: u64,
+        minimum_amount_b: u64,
+    ) -> Result<()> {
+        let mut data = hash(b"global:remove_liquidity").to_bytes()[..8].to_vec();
+        (lp_amount, minimum_amount_a, minimum_amount_b).serialize(&mut data)?;
+        invoke_pool(&ctx, data)
+    }
+
+    /// Call the pool's liquidity instruction `data` with the treasury as provider
+    fn invoke_pool(ctx: &Context<TreasuryLiquidity>, data: Vec<u8>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let treasury = &accounts.treasury;
+        let seeds = &[
+            b"treasury".as_ref(),
+            treasury.owner.as_ref(),
+            treasury.name.as_bytes(),
+            &[ctx.bumps.treasury],
+        ];
+
+        // In the order of the pool's `ModifyLiquidity` accounts
+        let account_infos = [
+            treasury.to_account_info(),
+            accounts.pool.to_account_info(),
+            accounts.token_a_reserve.to_account_info(),
+            accounts.token_b_reserve.to_account_info(),
+            accounts.lp_mint.to_account_info(),
+            accounts.locked_lp.to_account_info(),
+            accounts.treasury_token_a.to_account_info(),
+            accounts.treasury_token_b.to_account_info(),
+            accounts.treasury_lp_token.to_account_info(),
+            accounts.token_program.to_account_info(),
+        ];
+        let instruction = Instruction {
+            program_id: POOL_PROGRAM_ID,
+            accounts: account_infos.iter()
+                .map(|info| AccountMeta {
+                    pubkey: info.key(),
+                    is_signer: info.key() == treasury.key(),
+                    is_writable: info.is_writable,
+                })
+                .collect(),
+            data,
+        };
+
+        invoke_signed(&instruction, &account_infos, &[&seeds[..]])?;
+        Ok(())
+    }
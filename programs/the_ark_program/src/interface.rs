@@ -26,7 +26,7 @@ pub struct InstructionContext {
     pub signer: Pubkey,  // The account that signed the original transaction
     pub accounts: Vec<Pubkey>,  // List of accounts involved in the instruction
     pub block_time: i64,  // Timestamp of the block (useful for time-sensitive decisions)
-    pub instruction_index: u8,  // Index of this instruction in the transaction
+    pub instruction_index: u8,  // Index of this instruction within the routed batch
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -82,6 +82,40 @@ pub enum Decision {
     Reject,
 }
 
+/// Decision a government records for the router to read back.
+///
+/// Governments create it as a PDA they own; the router checks the owner and
+/// the discriminator before trusting it, and the hash before acting on it.
+#[account]
+pub struct DecisionRecord {
+    pub decision: Decision,
+    // `instructions_hash` of the request the decision was made on
+    pub instructions_hash: [u8; 32],
+}
+
+impl DecisionRecord {
+    pub const SPACE: usize = 8 + 1 + 32;
+}
+
+/// Hash of what a batch of instruction contexts does: each instruction's program,
+/// accounts and data, in order. Who asked and when are left out.
+pub fn instructions_hash(contexts: &[InstructionContext]) -> Result<[u8; 32]> {
+    let mut data = Vec::new();
+    for context in contexts {
+        context.program_id.serialize(&mut data)?;
+        context.accounts.serialize(&mut data)?;
+        context.instruction_data.serialize(&mut data)?;
+    }
+    Ok(anchor_lang::solana_program::hash::hash(&data).to_bytes())
+}
+
+/// Instruction data of a government's `make_decision(contexts)` instruction.
+pub fn make_decision_data(contexts: &[InstructionContext]) -> Result<Vec<u8>> {
+    let mut data = anchor_lang::solana_program::hash::hash(b"global:make_decision").to_bytes()[..8].to_vec();
+    contexts.serialize(&mut data)?;
+    Ok(data)
+}
+
 #[error_code]
 pub enum GovernmentError {
     #[msg("Unauthorized action")]
@@ -67,6 +67,24 @@ pub mod the_ark_program {
     pub fn add_new_token_to_treasury(ctx: Context<AddTokenToTreasury>) -> Result<()> {
         add_token_to_treasury(ctx)
     }
+
+    pub fn add_treasury_liquidity(
+        ctx: Context<TreasuryLiquidity>,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        minimum_lp_out: u64,
+    ) -> Result<()> {
+        provide_treasury_liquidity(ctx, max_amount_a, max_amount_b, minimum_lp_out)
+    }
+
+    pub fn remove_treasury_liquidity(
+        ctx: Context<TreasuryLiquidity>,
+        lp_amount: u64,
+        minimum_amount_a: u64,
+        minimum_amount_b: u64,
+    ) -> Result<()> {
+        withdraw_treasury_liquidity(ctx, lp_amount, minimum_amount_a, minimum_amount_b)
+    }
 }
 
 #[derive(Accounts)]